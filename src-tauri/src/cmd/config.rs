@@ -9,7 +9,7 @@ use tokio::task;
 
 use crate::{
     cmd::SchedulerCmd,
-    config::{self, AppConfig, SharedConfig},
+    config::{self, AppConfig, ConfigUpdates, SharedConfig},
     platform::register_shortcuts,
     scheduler::Command,
 };
@@ -25,12 +25,16 @@ pub async fn save_config(
     app_handle: AppHandle,
     scheduler_cmd: State<'_, SchedulerCmd>,
     config_state: State<'_, SharedConfig>,
+    config_updates: State<'_, ConfigUpdates>,
 ) -> Result<(), String> {
     // Get the old config to compare shortcuts and preserve advanced settings
-    let (old_shortcut, advanced_config) = {
+    let (old_shortcuts, advanced_config) = {
         let config_guard = config_state.read().await;
         (
-            config_guard.postpone_shortcut.clone(),
+            (
+                config_guard.postpone_shortcut.clone(),
+                config_guard.pomodoro_shortcut.clone(),
+            ),
             config_guard.advanced.clone(),
         )
     };
@@ -61,11 +65,17 @@ pub async fn save_config(
         *config_guard = config.clone();
     }
 
+    // Let the monitors follow the new config
+    config_updates.send_replace(config.clone());
+
     // Re-register shortcuts if they changed
-    if old_shortcut != config.postpone_shortcut {
+    let new_shortcuts = (
+        config.postpone_shortcut.clone(),
+        config.pomodoro_shortcut.clone(),
+    );
+    if old_shortcuts != new_shortcuts {
         tracing::info!(
-            "Postpone shortcut changed from '{old_shortcut}' to '{}', re-registering shortcuts",
-            config.postpone_shortcut
+            "Shortcuts changed from {old_shortcuts:?} to {new_shortcuts:?}, re-registering shortcuts"
         );
 
         // Unregister all existing shortcuts
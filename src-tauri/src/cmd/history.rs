@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use tauri::State;
+
+use crate::core::history::{HistoryEntry, HistoryStore};
+
+/// Get break history entries recorded in `[from, to)`
+///
+/// Both bounds are RFC 3339 timestamps.
+#[tauri::command]
+pub async fn get_break_history(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    state: State<'_, HistoryStore>,
+) -> Result<Vec<HistoryEntry>, String> {
+    if from > to {
+        return Err(format!("Invalid history range: {from} is after {to}"));
+    }
+    Ok(state.query(from, to))
+}
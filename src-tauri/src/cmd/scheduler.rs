@@ -1,10 +1,21 @@
 use std::ops::Deref;
 
+use chrono::{DateTime, Days, Duration, Local, NaiveTime, Utc};
 use tauri::State;
 use tokio::sync::{mpsc::Sender, watch};
+use tokio::time::timeout;
 
-use crate::scheduler::models::{Command, PauseReason, SchedulerEvent};
-use crate::scheduler::shared_state::SharedState;
+use crate::config::{AppConfig, SharedConfig};
+use crate::scheduler::event::localize;
+use crate::scheduler::models::{
+    Command, CommandReply, PauseDuration, PauseReason, Responder, SchedulerEvent,
+    SchedulerEventInfo,
+};
+use crate::scheduler::progress::ProgressStore;
+use crate::scheduler::timeline::{BreakCycle, project_timeline};
+
+/// How long to wait for the scheduler to reply to a command
+const REPLY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
 pub struct SchedulerCmd(pub Sender<Command>);
 
@@ -36,117 +47,169 @@ impl SchedulerCmd {
             tracing::error!("Failed to send {command} to scheduler: {e}");
         });
     }
+
+    /// Send the command built around a [`Responder`] and wait for the reply
+    ///
+    /// Fails if the command cannot be sent, or the scheduler drops it or does
+    /// not reply in time.
+    pub async fn request(
+        &self,
+        command: impl FnOnce(Option<Responder>) -> Command,
+    ) -> Result<CommandReply, String> {
+        let (responder, reply_rx) = Responder::new();
+        let command = command(Some(responder));
+        let description = command.to_string();
+        self.0.send(command).await.map_err(|e| e.to_string())?;
+        match timeout(REPLY_TIMEOUT, reply_rx).await {
+            Ok(Ok(reply)) => Ok(reply),
+            Ok(Err(_)) => Err(format!("Scheduler dropped {description} without replying")),
+            Err(_) => Err(format!("Scheduler did not reply to {description} in time")),
+        }
+    }
 }
 
 /// Shutdown sender to keep the scheduler alive
 pub struct ShutdownTx(pub watch::Sender<()>);
 
 /// Request the scheduler to emit its current status
+///
+/// The status is also returned in the reply.
+#[tauri::command]
+pub async fn request_break_status(state: State<'_, SchedulerCmd>) -> Result<CommandReply, String> {
+    state.request(Command::RequestBreakStatus).await
+}
+
+/// Pause the scheduler manually
+///
+/// Pauses the scheduler with [`PauseReason::Manual`]. The reply carries the
+/// status afterwards.
 #[tauri::command]
-pub async fn request_break_status(state: State<'_, SchedulerCmd>) -> Result<(), String> {
+pub async fn pause_scheduler(state: State<'_, SchedulerCmd>) -> Result<CommandReply, String> {
     state
-        .send(Command::RequestBreakStatus)
+        .request(|responder| Command::Pause(PauseReason::Manual, responder))
         .await
-        .map_err(|e| e.to_string())
 }
 
-/// Pause the scheduler manually
+/// Pause the scheduler manually for a limited time
 ///
-/// Pauses the scheduler with [`PauseReason::Manual`].
+/// The scheduler resumes automatically when the duration ends.
 #[tauri::command]
-pub async fn pause_scheduler(state: State<'_, SchedulerCmd>) -> Result<(), String> {
+pub async fn pause_scheduler_for(
+    state: State<'_, SchedulerCmd>,
+    duration: PauseDuration,
+) -> Result<CommandReply, String> {
     state
-        .send(Command::Pause(PauseReason::Manual))
+        .request(|responder| Command::PauseFor(duration, responder))
         .await
-        .map_err(|e| e.to_string())
 }
 
-/// Resume the scheduler
+/// Resume the scheduler from a manual pause
+///
+/// The scheduler stays paused while other pause reasons remain, which the
+/// status in the reply shows.
 #[tauri::command]
-pub async fn resume_scheduler(state: State<'_, SchedulerCmd>) -> Result<(), String> {
+pub async fn resume_scheduler(state: State<'_, SchedulerCmd>) -> Result<CommandReply, String> {
     state
-        .send(Command::Resume(PauseReason::Manual))
+        .request(|responder| Command::Resume(PauseReason::Manual, responder))
         .await
-        .map_err(|e| e.to_string())
 }
 
-/// Postpone the current or next break
+/// Start a Pomodoro session on the first enabled Pomodoro schedule
+///
+/// The optional task label is shown in the session's break prompts.
 #[tauri::command]
-pub async fn postpone_break(
+pub async fn start_pomodoro(
     state: State<'_, SchedulerCmd>,
-    shared_state: State<'_, SharedState>,
-) -> Result<(), String> {
-    // Validate pause state before sending command
-    if shared_state.read().is_paused() {
-        let reasons = shared_state
-            .read()
-            .pause_reasons()
-            .iter()
-            .map(std::string::ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(", ");
-        return Err(format!(
-            "Cannot postpone break while scheduler is paused (reasons: {reasons})"
-        ));
-    }
+    task: Option<String>,
+) -> Result<CommandReply, String> {
+    let task = task.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
     state
-        .send(Command::PostponeBreak)
+        .request(|responder| Command::StartPomodoro(task, responder))
         .await
-        .map_err(|e| e.to_string())
+}
+
+/// Stop the running Pomodoro session
+#[tauri::command]
+pub async fn stop_pomodoro(state: State<'_, SchedulerCmd>) -> Result<CommandReply, String> {
+    state.request(Command::StopPomodoro).await
+}
+
+/// Postpone the current or next break
+///
+/// Rejected if the scheduler is paused or the break reached its postpone limit.
+#[tauri::command]
+pub async fn postpone_break(state: State<'_, SchedulerCmd>) -> Result<CommandReply, String> {
+    state.request(Command::PostponeBreak).await
 }
 
 /// Manually trigger a break for testing purposes
 ///
-/// Returns an error if the scheduler is currently paused.
+/// Rejected if the scheduler is currently paused.
 #[tauri::command]
 pub async fn trigger_event(
     scheduler_cmd: State<'_, SchedulerCmd>,
-    shared_state: State<'_, SharedState>,
     break_kind: SchedulerEvent,
-) -> Result<(), String> {
-    // Validate pause state before sending command
-    if shared_state.read().is_paused() {
-        let reasons = shared_state
-            .read()
-            .pause_reasons()
-            .iter()
-            .map(std::string::ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(", ");
-        return Err(format!(
-            "Cannot trigger event while scheduler is paused (reasons: {reasons})"
-        ));
-    }
-
+) -> Result<CommandReply, String> {
     scheduler_cmd
-        .send(Command::TriggerEvent(break_kind))
+        .request(|responder| Command::TriggerEvent(break_kind, responder))
         .await
-        .map_err(|e| e.to_string())
 }
 
 /// Skip the current break immediately
+///
+/// Rejected if the scheduler is currently paused.
 #[tauri::command]
-pub async fn skip_break(
-    state: State<'_, SchedulerCmd>,
-    shared_state: State<'_, SharedState>,
-) -> Result<(), String> {
-    // Validate pause state before sending command
-    if shared_state.read().is_paused() {
-        let reasons = shared_state
-            .read()
-            .pause_reasons()
-            .iter()
-            .map(std::string::ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(", ");
-        return Err(format!(
-            "Cannot skip break while scheduler is paused (reasons: {reasons})"
-        ));
-    }
-    state
-        .send(Command::SkipBreak)
-        .await
-        .map_err(|e| e.to_string())
+pub async fn skip_break(state: State<'_, SchedulerCmd>) -> Result<CommandReply, String> {
+    state.request(Command::SkipBreak).await
+}
+
+/// Get the upcoming breaks and attentions
+///
+/// Projects at most `limit` events over the next `horizon_s` seconds, or until the
+/// end of today if not given. Pass an unsaved `config` to preview it instead of the
+/// current configuration. The projection continues the current break cycle and
+/// assumes breaks are taken in full and the scheduler is not paused.
+#[tauri::command]
+pub async fn get_upcoming_events(
+    limit: usize,
+    horizon_s: Option<u32>,
+    config: Option<AppConfig>,
+    config_state: State<'_, SharedConfig>,
+    progress: State<'_, ProgressStore>,
+) -> Result<Vec<SchedulerEventInfo>, String> {
+    let now = Utc::now();
+    let until = match horizon_s {
+        Some(horizon_s) => now + Duration::seconds(i64::from(horizon_s)),
+        None => end_of_today(now)?,
+    };
+    let cycle = progress.load().map(BreakCycle::from).unwrap_or_default();
+
+    let events = match config {
+        Some(config) => project_timeline(&config, now, &Local, cycle, until, limit),
+        None => project_timeline(
+            &*config_state.read().await,
+            now,
+            &Local,
+            cycle,
+            until,
+            limit,
+        ),
+    };
+
+    Ok(events
+        .into_iter()
+        .map(|event| SchedulerEventInfo::new(event.kind, event.time, now))
+        .collect())
+}
+
+/// Next local midnight after `now`
+fn end_of_today(now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    now.with_timezone(&Local)
+        .date_naive()
+        .checked_add_days(Days::new(1))
+        .and_then(|date| localize(date.and_time(NaiveTime::MIN), &Local))
+        .map(|midnight| midnight.with_timezone(&Utc))
+        .ok_or_else(|| format!("Cannot determine the end of today from {now}"))
 }
 
 /// Notify that a break has finished normally
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, Utc};
+use tauri::State;
+
+use crate::core::history::HistoryStore;
+use crate::core::stats::{DailyStats, StatsSummary, compute_daily_stats, compute_summary};
+
+/// Get break statistics of a single local day
+#[tauri::command]
+pub async fn get_daily_stats(
+    date: NaiveDate,
+    state: State<'_, HistoryStore>,
+) -> Result<DailyStats, String> {
+    // Pad by a day on both sides so every UTC offset is covered
+    let entries = state.query(utc_midnight(date, -1)?, utc_midnight(date, 2)?);
+    Ok(compute_daily_stats(&entries, date, &Local))
+}
+
+/// Get break statistics of the week (Monday to Sunday) containing `date`
+#[tauri::command]
+pub async fn get_weekly_stats(
+    date: NaiveDate,
+    state: State<'_, HistoryStore>,
+) -> Result<StatsSummary, String> {
+    let from = date - Days::new(u64::from(date.weekday().num_days_from_monday()));
+    let to = from + Days::new(6);
+
+    // The longest streak may lie anywhere before the week, so streaks need the
+    // whole history up to its end; it is grouped by day in a single pass
+    let entries = state.query(DateTime::<Utc>::MIN_UTC, utc_midnight(to, 2)?);
+    Ok(compute_summary(&entries, from, to, &Local))
+}
+
+/// UTC midnight of `date` shifted by `offset_days`
+fn utc_midnight(date: NaiveDate, offset_days: i64) -> Result<DateTime<Utc>, String> {
+    date.checked_add_signed(chrono::Duration::days(offset_days))
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+        .ok_or_else(|| format!("Date out of range: {date}"))
+}
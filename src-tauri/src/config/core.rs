@@ -1,5 +1,5 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use figment::{
@@ -79,10 +79,20 @@ async fn try_load_or_create_config(app_handle: &AppHandle) -> Result<AppConfig>
         return Ok(config);
     }
 
+    let config = load_config_file(&config_path)?;
+
+    tracing::info!("Config loaded successfully from {}", config_path.display());
+    tracing::debug!("Advanced config: {:?}", config.advanced);
+
+    Ok(config)
+}
+
+/// Load a config file, filling missing fields with their defaults
+pub fn load_config_file(config_path: &Path) -> Result<AppConfig> {
     // Load main config (without advanced section)
     let mut config: AppConfig = Figment::new()
         .merge(Serialized::defaults(AppConfig::default()))
-        .merge(Toml::file(&config_path))
+        .merge(Toml::file(config_path))
         .extract()
         .map_err(|e| {
             tracing::error!(
@@ -97,16 +107,13 @@ async fn try_load_or_create_config(app_handle: &AppHandle) -> Result<AppConfig>
         })?;
 
     // Load advanced config from separate section
-    config.advanced = load_advanced_config(&config_path).await?;
-
-    tracing::info!("Config loaded successfully from {}", config_path.display());
-    tracing::debug!("Advanced config: {:?}", config.advanced);
+    config.advanced = load_advanced_config(config_path)?;
 
     Ok(config)
 }
 
 /// Load advanced configuration from the `[advanced]` section
-async fn load_advanced_config(config_path: &PathBuf) -> Result<AdvancedConfig> {
+fn load_advanced_config(config_path: &Path) -> Result<AdvancedConfig> {
     // Try to extract the advanced section, fall back to default if not present
     #[derive(Deserialize)]
     struct ConfigFile {
@@ -114,8 +121,7 @@ async fn load_advanced_config(config_path: &PathBuf) -> Result<AdvancedConfig> {
         advanced: AdvancedConfig,
     }
 
-    let file_content = a_fs::read_to_string(config_path)
-        .await
+    let file_content = fs::read_to_string(config_path)
         .context("Failed to read config file for advanced section")?;
 
     let config_file: ConfigFile = toml::from_str(&file_content)
@@ -154,6 +160,30 @@ mod tests {
         assert_eq!(config.schedules.len(), 1); // default schedule
     }
 
+    #[test]
+    fn test_legacy_single_time_range() {
+        // Schedules written before multiple ranges were supported use `timeRange`
+        let legacy_toml = r#"
+            [[schedules]]
+            name = "Work Hours"
+
+            [schedules.timeRange]
+            start = "09:00:00"
+            end = "17:00:00"
+        "#;
+
+        let config: AppConfig = Figment::new()
+            .merge(Serialized::defaults(AppConfig::default()))
+            .merge(Toml::string(legacy_toml))
+            .extract()
+            .expect("Failed to extract config");
+
+        let ranges = &config.schedules[0].time_ranges;
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].start.to_string(), "09:00:00");
+        assert_eq!(ranges[0].end.to_string(), "17:00:00");
+    }
+
     #[test]
     fn test_figment_error_handling() {
         let invalid_toml = r#"
@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, watch};
 use ts_rs::TS;
 
 use super::app_exclusion::AppExclusion;
 use crate::{
     core::schedule::{AttentionSettings, ScheduleSettings},
     platform::i18n::LANGUAGE_FALLBACK,
+    scheduler::models::PauseReason,
     utils::LogLevel,
 };
 
@@ -20,6 +21,81 @@ pub struct AdvancedConfig {
     /// Overrides the default log level (info in release, trace in debug)
     #[serde(default = "LogLevel::default_for_build")]
     pub log_level: LogLevel,
+    /// Maximum age in seconds of saved scheduler progress to restore on startup
+    /// Falls back to the idle threshold (`inactive_s`) when unset, 0 never restores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_progress_within_s: Option<u32>,
+    /// Whether a system suspend at least as long as a long break counts as a long break
+    pub credit_sleep_as_long_break: bool,
+}
+
+/// What happens to the break interval when the scheduler resumes after a pause
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum PausePolicy {
+    /// Start a fresh interval
+    #[default]
+    Reset,
+    /// Continue the interval where it was paused
+    Freeze,
+    /// Count the pause as a break if it was long enough, then start a fresh interval
+    CreditBreak,
+}
+
+/// How breaks are checked for the user actually stepping away
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum BreakVerification {
+    /// Breaks are not checked
+    #[default]
+    Off,
+    /// Breaks with input during them are recorded as interrupted
+    Record,
+    /// Input during a break adds the time to the break
+    Extend,
+    /// Input during a break starts its countdown over
+    Restart,
+}
+
+/// Pause policy for each pause reason
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct PausePolicies {
+    /// Paused because the user was inactive
+    pub user_idle: PausePolicy,
+    /// Paused because Do Not Disturb was on
+    pub dnd: PausePolicy,
+    /// Paused because an excluded application was running
+    pub app_exclusion: PausePolicy,
+    /// Paused by the user
+    pub manual: PausePolicy,
+}
+
+impl Default for PausePolicies {
+    fn default() -> Self {
+        Self {
+            user_idle: PausePolicy::CreditBreak,
+            dnd: PausePolicy::Reset,
+            app_exclusion: PausePolicy::Reset,
+            manual: PausePolicy::Reset,
+        }
+    }
+}
+
+impl PausePolicies {
+    /// The policy applied when resuming from a pause for `reason`
+    #[must_use]
+    pub fn for_reason(&self, reason: PauseReason) -> PausePolicy {
+        match reason {
+            PauseReason::UserIdle => self.user_idle,
+            PauseReason::Dnd => self.dnd,
+            PauseReason::AppExclusion => self.app_exclusion,
+            PauseReason::Manual => self.manual,
+        }
+    }
 }
 
 /// Application configuration structure
@@ -33,6 +109,12 @@ pub struct AppConfig {
     pub monitor_dnd: bool,
     /// Inactive time in seconds before pausing breaks
     pub inactive_s: u32,
+    /// How the break interval continues after each kind of pause
+    pub pause_policies: PausePolicies,
+    /// Seconds a due break may wait for a pause in typing, 0 opens it on time
+    pub break_grace_s: u32,
+    /// Whether input during a break is detected, and what happens then
+    pub break_verification: BreakVerification,
     /// If breaks should be shown on all screens
     pub all_screens: bool,
     /// Language code, e.g., "en-US"
@@ -42,6 +124,8 @@ pub struct AppConfig {
     pub theme_mode: String,
     /// Shortcut to postpone breaks, e.g., "Ctrl+Shift+X"
     pub postpone_shortcut: String,
+    /// Shortcut to start or stop a Pomodoro session, e.g., "Ctrl+Shift+F"
+    pub pomodoro_shortcut: String,
     /// Prompt window size percentage (0.1 to 1.0, where 1.0 is fullscreen)
     pub window_size: f32,
     /// List of schedules
@@ -62,10 +146,14 @@ impl Default for AppConfig {
             autostart: false,
             monitor_dnd: true,
             inactive_s: 300,
+            pause_policies: PausePolicies::default(),
+            break_grace_s: 0,
+            break_verification: BreakVerification::Off,
             all_screens: false,
             language: detect_system_language(),
             theme_mode: "system".to_string(),
             postpone_shortcut: String::new(),
+            pomodoro_shortcut: String::new(),
             window_size: 0.8, // Default 80% of screen size
             schedules: vec![ScheduleSettings::default()],
             attentions: vec![],
@@ -85,6 +173,9 @@ fn detect_system_language() -> String {
 /// Shared application configuration wrapped in a `RwLock` for thread-safe access
 pub type SharedConfig = RwLock<AppConfig>;
 
+/// Latest saved configuration, for background tasks that follow config changes
+pub type ConfigUpdates = watch::Sender<AppConfig>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,11 +223,31 @@ mod tests {
         assert!(!config.autostart);
         assert!(config.monitor_dnd);
         assert_eq!(config.inactive_s, 300);
+        assert_eq!(config.break_grace_s, 0);
+        assert_eq!(config.break_verification, BreakVerification::Off);
         assert!(!config.all_screens);
         assert!(!config.language.is_empty());
         assert_eq!(config.postpone_shortcut, "");
+        assert_eq!(config.pomodoro_shortcut, "");
         assert_eq!(config.schedules.len(), 1);
         assert_eq!(config.attentions.len(), 0);
+        assert_eq!(
+            config.pause_policies.for_reason(PauseReason::UserIdle),
+            PausePolicy::CreditBreak
+        );
+        assert_eq!(
+            config.pause_policies.for_reason(PauseReason::Manual),
+            PausePolicy::Reset
+        );
+    }
+
+    #[test]
+    fn test_partial_pause_policies() {
+        let config: AppConfig =
+            toml::from_str("[pausePolicies]\ndnd = \"freeze\"").expect("Failed to deserialize");
+
+        assert_eq!(config.pause_policies.dnd, PausePolicy::Freeze);
+        assert_eq!(config.pause_policies.user_idle, PausePolicy::CreditBreak);
     }
 
     #[test]
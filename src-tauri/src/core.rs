@@ -1,6 +1,8 @@
 pub mod audio;
+pub mod history;
 pub mod payload;
 pub mod schedule;
+pub mod stats;
 pub mod suggestions;
 pub mod theme;
 pub mod time;
@@ -0,0 +1,412 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use tauri::{AppHandle, Manager, Runtime};
+use ts_rs::TS;
+
+use crate::config::AppConfig;
+use crate::scheduler::SchedulerEvent;
+use crate::scheduler::host::SchedulerHost;
+
+const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+/// Lifecycle transition of a prompt (break or attention)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum PromptOutcome {
+    /// Prompt window was shown
+    Started,
+    /// Prompt ran until the end
+    Completed,
+    /// User skipped the prompt
+    Skipped,
+    /// User postponed the prompt
+    Postponed,
+    /// Prompt was closed because the scheduler got paused
+    Cancelled,
+    /// Break was taken away from the computer while idle, without a prompt
+    Credited,
+    /// Prompt ran until the end, but the user kept working during the break
+    Interrupted,
+}
+
+/// A single record in the break history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct HistoryEntry {
+    /// When the transition happened
+    pub timestamp: DateTime<Utc>,
+    pub event: SchedulerEvent,
+    pub outcome: PromptOutcome,
+    /// Name of the schedule (breaks) or attention the prompt belongs to
+    pub source_name: Option<String>,
+    /// Seconds the prompt had been shown when the transition happened
+    pub duration_s: u32,
+    /// Number of times the break had been postponed before this transition
+    pub postpone_count: u8,
+}
+
+impl HistoryEntry {
+    /// Create an entry for a transition happening at `timestamp`
+    #[must_use]
+    pub fn new(
+        timestamp: DateTime<Utc>,
+        event: SchedulerEvent,
+        outcome: PromptOutcome,
+        source_name: Option<String>,
+        duration_s: u32,
+        postpone_count: u8,
+    ) -> Self {
+        Self {
+            timestamp,
+            event,
+            outcome,
+            source_name,
+            duration_s,
+            postpone_count,
+        }
+    }
+}
+
+/// Append-only store for prompt lifecycle transitions
+///
+/// Entries are kept in memory for querying and appended to a JSON Lines file
+/// under the app data directory. A store without a file (see [`HistoryStore::in_memory`])
+/// is used when the data directory is unavailable and in tests.
+#[derive(Debug, Default)]
+pub struct HistoryStore {
+    path: Option<PathBuf>,
+    entries: RwLock<Vec<HistoryEntry>>,
+}
+
+impl HistoryStore {
+    /// Create a store that is not backed by a file
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open (or create) a store backed by the given file
+    ///
+    /// Lines that cannot be parsed are skipped, so a partially written
+    /// last line does not make the whole history unreadable.
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let mut entries = Vec::new();
+
+        if path.exists() {
+            let file = File::open(&path)
+                .with_context(|| format!("Failed to open history file: {}", path.display()))?;
+            for (index, line) in BufReader::new(file).lines().enumerate() {
+                let line = line.context("Failed to read history file")?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<HistoryEntry>(&line) {
+                    Ok(entry) => entries.push(entry),
+                    Err(e) => {
+                        tracing::warn!("Skipping malformed history line {}: {e}", index + 1);
+                    }
+                }
+            }
+        } else if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create history directory")?;
+        }
+
+        // Keep entries sorted so range queries stay correct even after clock changes
+        entries.sort_by_key(|entry| entry.timestamp);
+
+        Ok(Self {
+            path: Some(path),
+            entries: RwLock::new(entries),
+        })
+    }
+
+    /// Record a transition
+    ///
+    /// Write failures are logged; the entry is still kept in memory.
+    pub fn record(&self, entry: HistoryEntry) {
+        tracing::debug!(
+            "Recording history: {} {} ({}s)",
+            entry.event,
+            entry.outcome,
+            entry.duration_s
+        );
+
+        if let Some(path) = &self.path
+            && let Err(e) = append_line(path, &entry)
+        {
+            tracing::error!("Failed to write history entry: {e}");
+        }
+
+        let mut entries = self.entries.write();
+        let index = entries.partition_point(|e| e.timestamp <= entry.timestamp);
+        entries.insert(index, entry);
+    }
+
+    /// Get all entries with `from <= timestamp < to`, oldest first
+    #[must_use]
+    pub fn query(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<HistoryEntry> {
+        let entries = self.entries.read();
+        let start = entries.partition_point(|e| e.timestamp < from);
+        let end = entries.partition_point(|e| e.timestamp < to);
+        entries
+            .get(start..end.max(start))
+            .map(<[HistoryEntry]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    /// Number of recorded entries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Whether no entry has been recorded yet
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+}
+
+fn append_line(path: &PathBuf, entry: &HistoryEntry) -> Result<()> {
+    let line = serde_json::to_string(entry).context("Failed to serialize history entry")?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open history file: {}", path.display()))?;
+    writeln!(file, "{line}").context("Failed to append history entry")?;
+    Ok(())
+}
+
+/// Load the break history from the app data directory
+///
+/// Falls back to an in-memory store if the file cannot be opened.
+pub fn load_history<R: Runtime>(app_handle: &AppHandle<R>) -> HistoryStore {
+    let path = match get_history_path(app_handle) {
+        Ok(path) => path,
+        Err(e) => {
+            tracing::error!("Failed to resolve history path, history will not be persisted: {e}");
+            return HistoryStore::in_memory();
+        }
+    };
+
+    match HistoryStore::open(path) {
+        Ok(store) => {
+            tracing::info!("Loaded {} history entries", store.len());
+            store
+        }
+        Err(e) => {
+            tracing::error!("Failed to load history, history will not be persisted: {e}");
+            HistoryStore::in_memory()
+        }
+    }
+}
+
+/// Record a prompt lifecycle transition into the host's [`HistoryStore`]
+///
+/// `now` is the time of the transition, read from the scheduler's clock.
+/// `started_at` is when the prompt was shown, used to compute how long it has been
+/// on screen; pass `None` for transitions that happen before the prompt is shown.
+/// Returns the entry, which schedulers then publish as
+/// [`DomainEvent::PromptTransition`](crate::scheduler::event_bus::DomainEvent).
+pub async fn record_prompt_transition<H: SchedulerHost>(
+    host: &H,
+    event: SchedulerEvent,
+    outcome: PromptOutcome,
+    now: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    postpone_count: u8,
+) -> HistoryEntry {
+    let source_name = resolve_source_name(&*host.config().read().await, event);
+    let duration_s = started_at.map_or(0, |started_at| {
+        (now - started_at).num_seconds().max(0) as u32
+    });
+    let entry = HistoryEntry::new(now, event, outcome, source_name, duration_s, postpone_count);
+
+    if let Some(history) = host.history_store() {
+        history.record(entry.clone());
+    } else {
+        tracing::warn!("History store not available, dropping {outcome} entry for {event}");
+    }
+    entry
+}
+
+/// Resolve the name of the schedule or attention a prompt belongs to
+#[must_use]
+pub fn resolve_source_name(config: &AppConfig, event: SchedulerEvent) -> Option<String> {
+    match event {
+        SchedulerEvent::MiniBreak(id) => config
+            .schedules
+            .iter()
+            .find(|s| s.mini_breaks.base.id == id)
+            .map(|s| s.name.clone()),
+        SchedulerEvent::LongBreak(id) => config
+            .schedules
+            .iter()
+            .find(|s| s.long_breaks.base.id == id)
+            .map(|s| s.name.clone()),
+        SchedulerEvent::Attention(id) => config
+            .attentions
+            .iter()
+            .find(|a| a.id == id)
+            .map(|a| a.name.clone()),
+    }
+}
+
+/// Get the path to the history file
+fn get_history_path<R: Runtime>(app_handle: &AppHandle<R>) -> Result<PathBuf> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .context("Failed to get app data directory")?;
+    Ok(data_dir.join(HISTORY_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::schedule::{AttentionId, BreakId};
+    use chrono::{Duration, TimeZone};
+
+    fn entry_at(timestamp: DateTime<Utc>, outcome: PromptOutcome) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            event: SchedulerEvent::MiniBreak(BreakId::new()),
+            outcome,
+            source_name: Some("Default".to_string()),
+            duration_s: 20,
+            postpone_count: 0,
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, 3, 10, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn query_returns_entries_in_half_open_range() {
+        let store = HistoryStore::in_memory();
+        let t0 = base_time();
+        for minutes in [0, 10, 20, 30] {
+            store.record(entry_at(
+                t0 + Duration::minutes(minutes),
+                PromptOutcome::Completed,
+            ));
+        }
+
+        let result = store.query(t0 + Duration::minutes(10), t0 + Duration::minutes(30));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].timestamp, t0 + Duration::minutes(10));
+        assert_eq!(result[1].timestamp, t0 + Duration::minutes(20));
+    }
+
+    #[test]
+    fn query_with_inverted_range_is_empty() {
+        let store = HistoryStore::in_memory();
+        store.record(entry_at(base_time(), PromptOutcome::Started));
+
+        let result = store.query(base_time() + Duration::hours(1), base_time());
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn out_of_order_records_are_sorted() {
+        let store = HistoryStore::in_memory();
+        let t0 = base_time();
+        store.record(entry_at(
+            t0 + Duration::minutes(5),
+            PromptOutcome::Completed,
+        ));
+        store.record(entry_at(t0, PromptOutcome::Started));
+
+        let result = store.query(t0, t0 + Duration::hours(1));
+        assert_eq!(result[0].outcome, PromptOutcome::Started);
+        assert_eq!(result[1].outcome, PromptOutcome::Completed);
+    }
+
+    #[test]
+    fn persisted_entries_survive_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(HISTORY_FILE_NAME);
+
+        {
+            let store = HistoryStore::open(path.clone()).unwrap();
+            store.record(entry_at(base_time(), PromptOutcome::Started));
+            store.record(entry_at(
+                base_time() + Duration::seconds(20),
+                PromptOutcome::Skipped,
+            ));
+        }
+
+        let reopened = HistoryStore::open(path).unwrap();
+        assert_eq!(reopened.len(), 2);
+        let entries = reopened.query(base_time(), base_time() + Duration::hours(1));
+        assert_eq!(entries[1].outcome, PromptOutcome::Skipped);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(HISTORY_FILE_NAME);
+
+        let valid =
+            serde_json::to_string(&entry_at(base_time(), PromptOutcome::Postponed)).unwrap();
+        fs::write(&path, format!("{valid}\nnot json\n\n{{\"timestamp\":")).unwrap();
+
+        let store = HistoryStore::open(path).unwrap();
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn entry_serializes_in_camel_case() {
+        let entry = HistoryEntry {
+            timestamp: base_time(),
+            event: SchedulerEvent::Attention(AttentionId::new()),
+            outcome: PromptOutcome::Completed,
+            source_name: None,
+            duration_s: 5,
+            postpone_count: 1,
+        };
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["outcome"], "completed");
+        assert_eq!(json["durationS"], 5);
+        assert_eq!(json["postponeCount"], 1);
+        assert_eq!(json["event"]["type"], "attention");
+    }
+
+    #[test]
+    fn resolves_source_name_for_breaks_and_attentions() {
+        let config = AppConfig::default();
+        let schedule = &config.schedules[0];
+
+        assert_eq!(
+            resolve_source_name(
+                &config,
+                SchedulerEvent::MiniBreak(schedule.mini_breaks.base.id)
+            ),
+            Some(schedule.name.clone())
+        );
+        assert_eq!(
+            resolve_source_name(
+                &config,
+                SchedulerEvent::LongBreak(schedule.long_breaks.base.id)
+            ),
+            Some(schedule.name.clone())
+        );
+        if let Some(attention) = config.attentions.first() {
+            assert_eq!(
+                resolve_source_name(&config, SchedulerEvent::Attention(attention.id)),
+                Some(attention.name.clone())
+            );
+        }
+    }
+}
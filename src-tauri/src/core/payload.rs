@@ -6,6 +6,7 @@ use tokio::sync::RwLock;
 use ts_rs::TS;
 
 use crate::core::audio::AudioSettings;
+use crate::core::schedule::SkipEscalation;
 use crate::core::theme::{ResolvedBackground, ThemeSettings};
 
 /// Break kind type
@@ -56,6 +57,8 @@ pub struct PromptPayload {
     pub message_key: String,
     pub message: Option<String>,
     pub schedule_name: Option<String>,
+    /// Task of the Pomodoro session the break belongs to
+    pub task: Option<String>,
     pub duration: i32,
     pub strict_mode: bool,
     pub theme: ThemeSettings,
@@ -70,6 +73,10 @@ pub struct PromptPayload {
     pub postpone_count: u8,
     /// Maximum number of times this break can be postponed
     pub max_postpone_count: u8,
+    /// Number of breaks skipped in a row before this one, if it was escalated
+    pub skip_count: u8,
+    /// How this break was escalated after the skips
+    pub escalation: Option<SkipEscalation>,
 }
 
 /// Shared state for storing active prompt payloads
@@ -116,6 +123,13 @@ impl PromptPayloadStore {
         payloads.remove(payload_id);
     }
 
+    /// Remove all payloads whose identifier starts with `prefix`
+    pub async fn remove_with_prefix(&self, prefix: &str) {
+        let mut payloads = self.write().await;
+        payloads.retain(|payload_id, _| !payload_id.starts_with(prefix));
+        tracing::debug!("Removed prompt payloads with prefix: {prefix}");
+    }
+
     /// Clear all stored payloads
     pub async fn clear(&self) {
         let mut payloads = self.write().await;
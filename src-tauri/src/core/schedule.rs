@@ -4,9 +4,9 @@ use crate::core::{
     audio::AudioSettings,
     suggestions::SuggestionsSettings,
     theme::ThemeSettings,
-    time::{ShortTimes, TimeRange},
+    time::{ShortTimes, TimeRange, deserialize_time_ranges},
 };
-use chrono::Weekday;
+use chrono::{NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
@@ -62,6 +62,21 @@ pub struct BaseBreakSettings {
     pub max_postpone_count: u8,
     /// If the break should be strictly followed
     pub strict_mode: bool,
+    /// What happens once `escalate_after_skips` of these breaks were skipped in a row
+    pub skip_escalation: SkipEscalation,
+    /// Number of breaks skipped in a row before `skip_escalation` applies
+    pub escalate_after_skips: u8,
+}
+
+impl BaseBreakSettings {
+    /// Whether these breaks are scheduled like breaks with `other` settings
+    fn same_timing(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.enabled == other.enabled
+            && self.duration_s == other.duration_s
+            && self.skip_escalation == other.skip_escalation
+            && self.escalate_after_skips == other.escalate_after_skips
+    }
 }
 
 impl Default for BaseBreakSettings {
@@ -76,10 +91,28 @@ impl Default for BaseBreakSettings {
             postponed_s: 300,      // Postpone default to 5 minutes
             max_postpone_count: 2, // Default: allow 2 postpones
             strict_mode: false,
+            skip_escalation: SkipEscalation::Off,
+            escalate_after_skips: 3, // Default: escalate after 3 skips in a row
         }
     }
 }
 
+/// How a break escalates after being skipped repeatedly
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum SkipEscalation {
+    /// Skipping has no consequence
+    #[default]
+    Off,
+    /// The next break is strict and cannot be skipped or postponed
+    Strict,
+    /// The next break comes after half the usual interval
+    ShortenInterval,
+    /// The next break is a long break (mini breaks only)
+    LongBreak,
+}
+
 /// Settings for mini breaks
 #[derive(Serialize, Deserialize, Debug, Clone, TS)]
 #[serde(default, rename_all = "camelCase")]
@@ -113,6 +146,12 @@ pub struct LongBreakSettings {
 
     /// Number of mini breaks after which to trigger a long break
     pub after_mini_breaks: u8,
+
+    /// Work time between long breaks in seconds, 0 counts mini breaks instead
+    pub interval_s: u32,
+
+    /// How a long break due by `interval_s` is fitted in with mini breaks
+    pub collision: LongBreakCollision,
 }
 
 impl Default for LongBreakSettings {
@@ -123,10 +162,38 @@ impl Default for LongBreakSettings {
                 ..BaseBreakSettings::default()
             },
             after_mini_breaks: 4, // Default to have a long break after 4 mini breaks
+            interval_s: 0,        // Default to counting mini breaks
+            collision: LongBreakCollision::Merge,
         }
     }
 }
 
+/// What happens when a time-based long break falls due around a mini break
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum LongBreakCollision {
+    /// Take the long break in place of the mini break closest to its due time
+    #[default]
+    Merge,
+    /// Take the long break on time, dropping the mini break it overtakes
+    Replace,
+    /// Take the long break in place of the first mini break after its due time
+    Defer,
+}
+
+/// How a schedule decides when breaks are due
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum ScheduleKind {
+    /// A mini break every `interval_s` while one of the time ranges is active
+    #[default]
+    Interval,
+    /// Focus blocks of `focus_s` followed by rests, in sessions started by the user
+    Pomodoro,
+}
+
 /// Settings for a break schedule
 #[derive(Serialize, Deserialize, Debug, Clone, TS)]
 #[serde(default, rename_all = "camelCase")]
@@ -136,8 +203,18 @@ pub struct ScheduleSettings {
     pub name: String,
     /// If the break is enabled
     pub enabled: bool,
-    /// Time range during which the schedule is active
-    pub time_range: TimeRange,
+    /// How breaks are scheduled
+    pub kind: ScheduleKind,
+    /// Length of a focus block in seconds (Pomodoro schedules)
+    pub focus_s: u32,
+    /// Count only active time towards break intervals (interval schedules)
+    ///
+    /// Idle gaps shorter than the inactivity threshold do not count as work.
+    /// Falls back to wall-clock time if idle detection is unavailable.
+    pub count_active_time: bool,
+    /// Time ranges during which the schedule is active
+    #[serde(alias = "timeRange", deserialize_with = "deserialize_time_ranges")]
+    pub time_ranges: Vec<TimeRange>,
     /// Days of the week when the schedule is active
     pub days_of_week: Vec<Weekday>,
     /// Notification time before breaks in seconds
@@ -153,7 +230,10 @@ impl Default for ScheduleSettings {
         ScheduleSettings {
             name: "Default Schedule".to_string(),
             enabled: true,
-            time_range: TimeRange::default(),
+            kind: ScheduleKind::Interval,
+            focus_s: 1500, // Default to 25 minutes of focus
+            count_active_time: false,
+            time_ranges: vec![TimeRange::default()],
             days_of_week: vec![
                 Weekday::Mon,
                 Weekday::Tue,
@@ -175,6 +255,56 @@ impl ScheduleSettings {
     pub fn has_notification(&self) -> bool {
         self.notification_before_s > 0
     }
+
+    /// Whether breaks fall due at the same times with `other` settings
+    ///
+    /// Looks, sounds, suggestions and postpone settings are read when a break
+    /// starts or is postponed, so they may differ.
+    #[must_use]
+    pub fn same_timing(&self, other: &Self) -> bool {
+        self.enabled == other.enabled
+            && self.kind == other.kind
+            && self.focus_s == other.focus_s
+            && self.count_active_time == other.count_active_time
+            && self.time_ranges == other.time_ranges
+            && self.days_of_week == other.days_of_week
+            && self.notification_before_s == other.notification_before_s
+            && self.mini_breaks.interval_s == other.mini_breaks.interval_s
+            && self.mini_breaks.base.same_timing(&other.mini_breaks.base)
+            && self.long_breaks.after_mini_breaks == other.long_breaks.after_mini_breaks
+            && self.long_breaks.interval_s == other.long_breaks.interval_s
+            && self.long_breaks.collision == other.long_breaks.collision
+            && self.long_breaks.base.same_timing(&other.long_breaks.base)
+    }
+
+    /// Seconds of work between two breaks
+    #[must_use]
+    pub fn work_interval_s(&self) -> u32 {
+        match self.kind {
+            ScheduleKind::Interval => self.mini_breaks.interval_s,
+            ScheduleKind::Pomodoro => self.focus_s,
+        }
+    }
+
+    /// Check if the schedule is active at `time` on `day`
+    ///
+    /// The part of an overnight range after midnight is attributed to the
+    /// previous day, so a Friday 22:00-06:00 range covers Saturday 03:00.
+    /// Pomodoro schedules are never active by time of day; they only run in
+    /// sessions started by the user.
+    #[must_use]
+    pub fn is_active_at(&self, time: NaiveTime, day: Weekday) -> bool {
+        self.enabled
+            && self.kind == ScheduleKind::Interval
+            && self.time_ranges.iter().any(|range| {
+                let range_day = if range.is_after_midnight(&time) {
+                    day.pred()
+                } else {
+                    day
+                };
+                range.contains(&time) && self.days_of_week.contains(&range_day)
+            })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, TS)]
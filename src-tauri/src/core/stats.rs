@@ -0,0 +1,484 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::core::history::{HistoryEntry, PromptOutcome};
+
+/// Minimum ratio of taken to due breaks for a day to count as compliant
+pub const COMPLIANT_DAY_RATIO: f64 = 0.8;
+
+/// Aggregated break statistics over a set of history entries
+///
+/// A break is *due* once it reaches a final outcome, counted in one of:
+///
+/// - `taken`: completed, or credited from idle time
+/// - `skipped`: skipped by the user
+/// - `cancelled`: closed by a pause
+/// - `interrupted`: completed while the user kept working
+///
+/// Postponing is not final, since the break is rescheduled.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct BreakStats {
+    /// Breaks that reached a final outcome
+    pub due: u32,
+    /// Breaks that ran until the end or were taken while idle
+    pub taken: u32,
+    /// Breaks skipped by the user
+    pub skipped: u32,
+    /// Breaks closed because the scheduler got paused
+    pub cancelled: u32,
+    /// Breaks during which the user kept working
+    pub interrupted: u32,
+    /// Number of postpone actions
+    pub postpones: u32,
+    /// Due breaks that were postponed at least once
+    pub postponed_breaks: u32,
+    /// `taken / due`, 0 when nothing was due
+    pub compliance_rate: f64,
+    /// `skipped / due`, 0 when nothing was due
+    pub skip_rate: f64,
+    /// `postponed_breaks / due`, 0 when nothing was due
+    pub postpone_rate: f64,
+    /// Longest time in seconds without a completed break within a single day
+    pub longest_stretch_s: u32,
+}
+
+/// Break statistics of a single schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct ScheduleStats {
+    pub schedule_name: String,
+    pub stats: BreakStats,
+}
+
+/// Break statistics of a single (local) day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct DailyStats {
+    pub date: NaiveDate,
+    pub overall: BreakStats,
+    pub schedules: Vec<ScheduleStats>,
+    /// Whether enough due breaks were taken (see [`COMPLIANT_DAY_RATIO`])
+    pub compliant: bool,
+}
+
+/// Break statistics over a range of days
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct StatsSummary {
+    /// First day of the range (inclusive)
+    pub from: NaiveDate,
+    /// Last day of the range (inclusive)
+    pub to: NaiveDate,
+    pub overall: BreakStats,
+    pub schedules: Vec<ScheduleStats>,
+    pub days: Vec<DailyStats>,
+    /// Compliant days in a row ending at `to`; days without due breaks are ignored
+    pub current_streak: u32,
+    /// Longest run of compliant days up to `to`
+    pub longest_streak: u32,
+}
+
+/// Compute statistics for a single day
+#[must_use]
+pub fn compute_daily_stats<Tz: TimeZone>(
+    entries: &[HistoryEntry],
+    date: NaiveDate,
+    tz: &Tz,
+) -> DailyStats {
+    let day_entries: Vec<&HistoryEntry> = entries
+        .iter()
+        .filter(|e| local_date(e, tz) == date)
+        .collect();
+
+    daily_stats(date, &day_entries, tz)
+}
+
+/// Compute statistics over the days `from..=to`
+///
+/// `entries` should contain the whole history up to `to`, as streaks look
+/// further back than `from`.
+#[must_use]
+pub fn compute_summary<Tz: TimeZone>(
+    entries: &[HistoryEntry],
+    from: NaiveDate,
+    to: NaiveDate,
+    tz: &Tz,
+) -> StatsSummary {
+    let by_day = group_by_day(entries, to, tz);
+
+    let days = from
+        .iter_days()
+        .take_while(|date| *date <= to)
+        .map(|date| daily_stats(date, by_day.get(&date).map_or(&[], Vec::as_slice), tz))
+        .collect();
+
+    let range_entries: Vec<&HistoryEntry> = by_day
+        .range(from..=to)
+        .flat_map(|(_, day_entries)| day_entries.iter().copied())
+        .collect();
+
+    let (current_streak, longest_streak) = compute_streaks(&by_day, tz);
+
+    StatsSummary {
+        from,
+        to,
+        overall: aggregate(&range_entries, tz),
+        schedules: per_schedule(&range_entries, tz),
+        days,
+        current_streak,
+        longest_streak,
+    }
+}
+
+/// Statistics of `date` from the entries of that day
+fn daily_stats<Tz: TimeZone>(
+    date: NaiveDate,
+    day_entries: &[&HistoryEntry],
+    tz: &Tz,
+) -> DailyStats {
+    let overall = aggregate(day_entries, tz);
+    let compliant = is_compliant(&overall);
+
+    DailyStats {
+        date,
+        overall,
+        schedules: per_schedule(day_entries, tz),
+        compliant,
+    }
+}
+
+/// Group the entries up to `to` by their local date
+fn group_by_day<'a, Tz: TimeZone>(
+    entries: &'a [HistoryEntry],
+    to: NaiveDate,
+    tz: &Tz,
+) -> BTreeMap<NaiveDate, Vec<&'a HistoryEntry>> {
+    let mut by_day: BTreeMap<NaiveDate, Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in entries {
+        let date = local_date(entry, tz);
+        if date <= to {
+            by_day.entry(date).or_default().push(entry);
+        }
+    }
+    by_day
+}
+
+/// Compute `(current, longest)` streaks of compliant days in `by_day`
+fn compute_streaks<Tz: TimeZone>(
+    by_day: &BTreeMap<NaiveDate, Vec<&HistoryEntry>>,
+    tz: &Tz,
+) -> (u32, u32) {
+    let mut current = 0;
+    let mut longest = 0;
+    for day_entries in by_day.values() {
+        let stats = aggregate(day_entries, tz);
+        if stats.due == 0 {
+            continue;
+        }
+        if is_compliant(&stats) {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+
+    (current, longest)
+}
+
+fn is_compliant(stats: &BreakStats) -> bool {
+    stats.due > 0 && stats.compliance_rate >= COMPLIANT_DAY_RATIO
+}
+
+/// Aggregate break entries (attentions are ignored)
+fn aggregate<Tz: TimeZone>(entries: &[&HistoryEntry], tz: &Tz) -> BreakStats {
+    let breaks: Vec<&HistoryEntry> = entries
+        .iter()
+        .copied()
+        .filter(|e| e.event.is_break())
+        .collect();
+
+    let mut stats = BreakStats::default();
+    for entry in &breaks {
+        match entry.outcome {
+            PromptOutcome::Started => continue,
+            PromptOutcome::Postponed => {
+                stats.postpones += 1;
+                continue;
+            }
+            PromptOutcome::Completed | PromptOutcome::Credited => stats.taken += 1,
+            PromptOutcome::Skipped => stats.skipped += 1,
+            PromptOutcome::Cancelled => stats.cancelled += 1,
+            PromptOutcome::Interrupted => stats.interrupted += 1,
+        }
+        stats.due += 1;
+        if entry.postpone_count > 0 {
+            stats.postponed_breaks += 1;
+        }
+    }
+
+    if stats.due > 0 {
+        let due = f64::from(stats.due);
+        stats.compliance_rate = f64::from(stats.taken) / due;
+        stats.skip_rate = f64::from(stats.skipped) / due;
+        stats.postpone_rate = f64::from(stats.postponed_breaks) / due;
+    }
+    stats.longest_stretch_s = longest_stretch_s(&breaks, tz);
+
+    stats
+}
+
+/// Longest gap between completed breaks, bounded by the first and last
+/// recorded break activity of each day
+fn longest_stretch_s<Tz: TimeZone>(breaks: &[&HistoryEntry], tz: &Tz) -> u32 {
+    let mut by_day: BTreeMap<NaiveDate, Vec<DateTime<Utc>>> = BTreeMap::new();
+    let mut completed_by_day: BTreeMap<NaiveDate, Vec<DateTime<Utc>>> = BTreeMap::new();
+    for entry in breaks {
+        let date = local_date(entry, tz);
+        by_day.entry(date).or_default().push(entry.timestamp);
+        if matches!(
+            entry.outcome,
+            PromptOutcome::Completed | PromptOutcome::Credited
+        ) {
+            completed_by_day
+                .entry(date)
+                .or_default()
+                .push(entry.timestamp);
+        }
+    }
+
+    let mut longest = Duration::zero();
+    for (date, timestamps) in &by_day {
+        let (Some(first), Some(last)) = (timestamps.iter().min(), timestamps.iter().max()) else {
+            continue;
+        };
+
+        let mut points = vec![*first];
+        if let Some(completed) = completed_by_day.get(date) {
+            points.extend(completed.iter().copied());
+        }
+        points.push(*last);
+        points.sort();
+
+        for pair in points.windows(2) {
+            longest = longest.max(pair[1] - pair[0]);
+        }
+    }
+
+    u32::try_from(longest.num_seconds()).unwrap_or(u32::MAX)
+}
+
+fn per_schedule<Tz: TimeZone>(entries: &[&HistoryEntry], tz: &Tz) -> Vec<ScheduleStats> {
+    let mut by_schedule: BTreeMap<String, Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in entries.iter().copied().filter(|e| e.event.is_break()) {
+        let name = entry.source_name.clone().unwrap_or_default();
+        by_schedule.entry(name).or_default().push(entry);
+    }
+
+    by_schedule
+        .into_iter()
+        .map(|(schedule_name, entries)| ScheduleStats {
+            schedule_name,
+            stats: aggregate(&entries, tz),
+        })
+        .collect()
+}
+
+fn local_date<Tz: TimeZone>(entry: &HistoryEntry, tz: &Tz) -> NaiveDate {
+    entry.timestamp.with_timezone(tz).date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::schedule::{AttentionId, BreakId};
+    use crate::scheduler::SchedulerEvent;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, day, hour, minute, 0).unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    fn entry(
+        timestamp: DateTime<Utc>,
+        outcome: PromptOutcome,
+        schedule: &str,
+        postpone_count: u8,
+    ) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            event: SchedulerEvent::MiniBreak(BreakId::new()),
+            outcome,
+            source_name: Some(schedule.to_string()),
+            duration_s: 0,
+            postpone_count,
+        }
+    }
+
+    /// A day with `taken` completed and `skipped` skipped breaks
+    fn day_with(day: u32, taken: u32, skipped: u32) -> Vec<HistoryEntry> {
+        let mut entries = vec![];
+        for i in 0..taken {
+            entries.push(entry(at(day, 9, i), PromptOutcome::Completed, "Work", 0));
+        }
+        for i in 0..skipped {
+            entries.push(entry(at(day, 10, i), PromptOutcome::Skipped, "Work", 0));
+        }
+        entries
+    }
+
+    #[test]
+    fn counts_final_outcomes_as_due() {
+        let entries = vec![
+            entry(at(3, 9, 0), PromptOutcome::Started, "Work", 0),
+            entry(at(3, 9, 1), PromptOutcome::Postponed, "Work", 0),
+            entry(at(3, 9, 6), PromptOutcome::Started, "Work", 1),
+            entry(at(3, 9, 7), PromptOutcome::Completed, "Work", 1),
+            entry(at(3, 10, 0), PromptOutcome::Skipped, "Work", 0),
+            entry(at(3, 11, 0), PromptOutcome::Cancelled, "Work", 0),
+        ];
+
+        let stats = compute_daily_stats(&entries, date(3), &Utc).overall;
+        assert_eq!(stats.due, 3);
+        assert_eq!(stats.taken, 1);
+        assert_eq!(stats.skipped, 1);
+        assert_eq!(stats.cancelled, 1);
+        assert_eq!(stats.postpones, 1);
+        assert_eq!(stats.postponed_breaks, 1);
+        assert!((stats.compliance_rate - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert!((stats.postpone_rate - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn counts_credited_breaks_as_taken() {
+        let entries = vec![
+            entry(at(3, 9, 0), PromptOutcome::Skipped, "Work", 0),
+            entry(at(3, 12, 0), PromptOutcome::Credited, "Work", 0),
+        ];
+
+        let stats = compute_daily_stats(&entries, date(3), &Utc).overall;
+        assert_eq!(stats.due, 2);
+        assert_eq!(stats.taken, 1);
+    }
+
+    #[test]
+    fn counts_interrupted_breaks_as_not_taken() {
+        let entries = vec![
+            entry(at(3, 9, 0), PromptOutcome::Completed, "Work", 0),
+            entry(at(3, 10, 0), PromptOutcome::Interrupted, "Work", 0),
+        ];
+
+        let stats = compute_daily_stats(&entries, date(3), &Utc).overall;
+        assert_eq!(stats.due, 2);
+        assert_eq!(stats.taken, 1);
+        assert_eq!(stats.interrupted, 1);
+    }
+
+    #[test]
+    fn empty_day_has_zero_rates() {
+        let stats = compute_daily_stats(&[], date(3), &Utc);
+        assert_eq!(stats.overall, BreakStats::default());
+        assert!(!stats.compliant);
+    }
+
+    #[test]
+    fn ignores_attentions() {
+        let mut attention = entry(at(3, 9, 0), PromptOutcome::Completed, "Water", 0);
+        attention.event = SchedulerEvent::Attention(AttentionId::new());
+
+        let stats = compute_daily_stats(&[attention], date(3), &Utc);
+        assert_eq!(stats.overall.due, 0);
+        assert!(stats.schedules.is_empty());
+    }
+
+    #[test]
+    fn splits_stats_per_schedule() {
+        let entries = vec![
+            entry(at(3, 9, 0), PromptOutcome::Completed, "Work", 0),
+            entry(at(3, 20, 0), PromptOutcome::Skipped, "Evening", 0),
+        ];
+
+        let stats = compute_daily_stats(&entries, date(3), &Utc);
+        assert_eq!(stats.schedules.len(), 2);
+        assert_eq!(stats.schedules[0].schedule_name, "Evening");
+        assert!((stats.schedules[0].stats.skip_rate - 1.0).abs() < f64::EPSILON);
+        assert_eq!(stats.schedules[1].schedule_name, "Work");
+        assert_eq!(stats.schedules[1].stats.taken, 1);
+    }
+
+    #[test]
+    fn longest_stretch_includes_time_before_first_completed_break() {
+        let entries = vec![
+            entry(at(3, 9, 0), PromptOutcome::Skipped, "Work", 0),
+            entry(at(3, 11, 0), PromptOutcome::Completed, "Work", 0),
+            entry(at(3, 11, 30), PromptOutcome::Completed, "Work", 0),
+            entry(at(3, 12, 0), PromptOutcome::Skipped, "Work", 0),
+        ];
+
+        let stats = compute_daily_stats(&entries, date(3), &Utc);
+        assert_eq!(stats.overall.longest_stretch_s, 2 * 3600);
+    }
+
+    #[test]
+    fn longest_stretch_does_not_span_days() {
+        let entries = vec![
+            entry(at(3, 22, 0), PromptOutcome::Completed, "Work", 0),
+            entry(at(4, 8, 0), PromptOutcome::Completed, "Work", 0),
+            entry(at(4, 8, 20), PromptOutcome::Completed, "Work", 0),
+        ];
+
+        let summary = compute_summary(&entries, date(3), date(4), &Utc);
+        assert_eq!(summary.overall.longest_stretch_s, 20 * 60);
+    }
+
+    #[test]
+    fn summary_contains_every_day_of_range() {
+        let entries = day_with(3, 4, 0);
+        let summary = compute_summary(&entries, date(1), date(7), &Utc);
+
+        assert_eq!(summary.days.len(), 7);
+        assert_eq!(summary.days[0].date, date(1));
+        assert_eq!(summary.days[2].overall.taken, 4);
+        assert_eq!(summary.overall.due, 4);
+    }
+
+    #[test]
+    fn streaks_skip_days_without_breaks() {
+        let mut entries = vec![];
+        entries.extend(day_with(1, 4, 0)); // compliant
+        entries.extend(day_with(2, 1, 3)); // not compliant
+        entries.extend(day_with(3, 5, 1)); // compliant (5/6)
+        entries.extend(day_with(4, 4, 0)); // compliant
+        // Day 5 has no breaks (weekend), streak continues
+        entries.extend(day_with(6, 4, 1)); // compliant (4/5)
+
+        let summary = compute_summary(&entries, date(6), date(6), &Utc);
+        assert_eq!(summary.current_streak, 3);
+        assert_eq!(summary.longest_streak, 3);
+
+        let summary = compute_summary(&entries, date(1), date(2), &Utc);
+        assert_eq!(summary.current_streak, 0);
+        assert_eq!(summary.longest_streak, 1);
+    }
+
+    #[test]
+    fn days_follow_the_given_timezone() {
+        let tz = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        // 20:00 UTC on the 3rd is 05:00 on the 4th in UTC+9
+        let entries = vec![entry(at(3, 20, 0), PromptOutcome::Completed, "Work", 0)];
+
+        assert_eq!(compute_daily_stats(&entries, date(3), &tz).overall.due, 0);
+        assert_eq!(compute_daily_stats(&entries, date(4), &tz).overall.due, 1);
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::Deref;
 
-use chrono::NaiveTime;
-use serde::{Deserialize, Serialize};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Deserializer, Serialize};
 use ts_rs::TS;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, TS)]
@@ -34,7 +34,7 @@ impl Deref for ShortTimes {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, TS)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(rename_all = "camelCase")]
 pub struct TimeRange {
@@ -52,15 +52,76 @@ impl Default for TimeRange {
 }
 
 impl TimeRange {
+    #[must_use]
+    pub fn is_full_day(&self) -> bool {
+        // if both are 00:00, treat as full day
+        (self.start == NaiveTime::MIN) && (self.end == NaiveTime::MIN)
+    }
+
+    /// Whether the range crosses midnight, e.g. 22:00-06:00
+    #[must_use]
+    pub fn is_overnight(&self) -> bool {
+        self.start > self.end
+    }
+
     #[must_use]
     pub fn contains(&self, time: &NaiveTime) -> bool {
-        if (self.start == NaiveTime::MIN) && (self.end == NaiveTime::MIN) {
-            // if both are 00:00, treat as full day
+        if self.is_full_day() {
             true
+        } else if self.is_overnight() {
+            self.start <= *time || *time <= self.end
         } else {
             self.start <= *time && *time <= self.end
         }
     }
+
+    /// Whether `time` falls in the part of an overnight range after midnight
+    ///
+    /// That part belongs to the day on which the range started.
+    #[must_use]
+    pub fn is_after_midnight(&self, time: &NaiveTime) -> bool {
+        self.is_overnight() && *time <= self.end
+    }
+
+    /// Instants at which this range, starting on `date`, starts and stops containing the time
+    ///
+    /// The end is inclusive, so the range stops one second after `end`.
+    /// Full-day and overnight ranges stop on the following day.
+    #[must_use]
+    pub fn boundaries_on(&self, date: NaiveDate) -> [NaiveDateTime; 2] {
+        let start = date.and_time(self.start);
+        if self.is_full_day() {
+            [start, start + Duration::days(1)]
+        } else if self.is_overnight() {
+            [
+                start,
+                (date + Duration::days(1)).and_time(self.end) + Duration::seconds(1),
+            ]
+        } else {
+            [start, date.and_time(self.end) + Duration::seconds(1)]
+        }
+    }
+}
+
+/// Deserialize either a single time range or a list of them
+///
+/// Configurations written before schedules supported several ranges hold a
+/// single `timeRange` table.
+pub fn deserialize_time_ranges<'de, D>(deserializer: D) -> Result<Vec<TimeRange>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(TimeRange),
+        Many(Vec<TimeRange>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(range) => vec![range],
+        OneOrMany::Many(ranges) => ranges,
+    })
 }
 
 #[cfg(test)]
@@ -193,6 +254,91 @@ mod tests {
         assert!(!range.contains(&time));
     }
 
+    #[test]
+    fn test_time_range_boundaries_on() {
+        let range = TimeRange {
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        };
+        let date = NaiveDate::from_ymd_opt(2025, 9, 3).unwrap();
+
+        let [start, end] = range.boundaries_on(date);
+        assert_eq!(start, date.and_hms_opt(9, 0, 0).unwrap());
+        assert_eq!(end, date.and_hms_opt(17, 0, 1).unwrap());
+        assert!(!range.contains(&end.time()));
+    }
+
+    #[test]
+    fn test_time_range_boundaries_on_full_day() {
+        let range = TimeRange::default();
+        let date = NaiveDate::from_ymd_opt(2025, 9, 3).unwrap();
+
+        let [start, end] = range.boundaries_on(date);
+        assert_eq!(start, date.and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(end, date.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_time_range_overnight() {
+        let range = TimeRange {
+            start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        };
+        assert!(range.is_overnight());
+
+        let late = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+        let early = NaiveTime::from_hms_opt(5, 0, 0).unwrap();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        assert!(range.contains(&late));
+        assert!(range.contains(&early));
+        assert!(!range.contains(&noon));
+
+        assert!(!range.is_after_midnight(&late));
+        assert!(range.is_after_midnight(&early));
+        assert!(!range.is_after_midnight(&noon));
+    }
+
+    #[test]
+    fn test_time_range_overnight_boundaries_on() {
+        let range = TimeRange {
+            start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        };
+        let date = NaiveDate::from_ymd_opt(2025, 9, 3).unwrap();
+
+        let [start, end] = range.boundaries_on(date);
+        assert_eq!(start, date.and_hms_opt(22, 0, 0).unwrap());
+        assert_eq!(end, date.succ_opt().unwrap().and_hms_opt(6, 0, 1).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_time_ranges_accepts_single_or_list() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(deserialize_with = "deserialize_time_ranges")]
+            ranges: Vec<TimeRange>,
+        }
+
+        let single: Wrapper =
+            toml::from_str("[ranges]\nstart = \"09:00:00\"\nend = \"17:00:00\"").unwrap();
+        assert_eq!(single.ranges.len(), 1);
+        assert_eq!(
+            single.ranges[0].start,
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap()
+        );
+
+        let many: Wrapper = toml::from_str(
+            "[[ranges]]\nstart = \"09:00:00\"\nend = \"12:00:00\"\n\n\
+             [[ranges]]\nstart = \"13:30:00\"\nend = \"18:00:00\"",
+        )
+        .unwrap();
+        assert_eq!(many.ranges.len(), 2);
+        assert_eq!(
+            many.ranges[1].start,
+            NaiveTime::from_hms_opt(13, 30, 0).unwrap()
+        );
+    }
+
     #[test]
     fn test_time_range_default() {
         let range = TimeRange::default();
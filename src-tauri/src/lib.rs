@@ -9,11 +9,12 @@
 
 use tauri::Manager;
 use tauri_plugin_autostart::ManagerExt;
+use tokio::sync::watch;
 
 use crate::{
     cmd::{SchedulerCmd, ShutdownTx},
     core::payload::PromptPayloadStore,
-    scheduler::manager::SchedulerManager,
+    scheduler::{event_bus::EventBus, manager::SchedulerManager},
 };
 
 pub mod cmd;
@@ -115,9 +116,17 @@ pub fn run() {
                 // Initialize prompt payload store
                 handle.manage(PromptPayloadStore::new());
 
+                // Load break history
+                handle.manage(core::history::load_history(&handle));
+                handle.manage(scheduler::progress::load_progress(&handle));
+
                 let shared_config = config::SharedConfig::new(app_config.clone());
                 handle.manage(shared_config);
 
+                // Saved configs are also published to tasks following config changes
+                let (config_updates, monitor_config_rx) = watch::channel(app_config.clone());
+                handle.manage::<config::ConfigUpdates>(config_updates);
+
                 // Sync system autostart with config preference
                 if app_config.autostart
                     && let Err(e) = handle.autolaunch().enable()
@@ -125,6 +134,11 @@ pub fn run() {
                     tracing::warn!("Failed to enable autostart on startup: {e}");
                 }
 
+                // Scheduler events reach the frontend and the tray through the bus
+                let events = EventBus::new();
+                scheduler::event_bus::spawn_frontend_bridge(handle.clone(), &events);
+                handle.manage(events.clone());
+
                 // Setup system tray after config is loaded
                 platform::setup_tray(&handle).await.unwrap_or_else(|e| {
                     tracing::error!("Failed to setup system tray: {e}");
@@ -141,12 +155,13 @@ pub fn run() {
                     core::suggestions::SharedSuggestions::new(suggestions_config);
                 handle.manage(shared_suggestions);
 
-                let (cmd_tx, shutdown_tx, shared_state) = SchedulerManager::init(&handle);
+                let (cmd_tx, shutdown_tx, shared_state) = SchedulerManager::init(&handle, &events);
 
                 // Register shared state for Tauri commands to access
                 handle.manage(shared_state.clone());
 
-                // Spawn monitors based on configuration
+                // Spawn the monitors that always run, the orchestrator adds the ones
+                // enabled by the config (DND, app exclusions) and follows its changes
                 let mut monitors: Vec<Box<dyn monitors::Monitor>> = vec![];
 
                 // Always add idle monitor (it will self-disable if detection fails)
@@ -155,40 +170,21 @@ pub fn run() {
                     app_config.inactive_s,
                 )));
 
-                // Add DND monitor if enabled
-                if app_config.monitor_dnd {
-                    tracing::info!("DND monitoring enabled");
-                    let dnd_monitor = monitors::DndMonitor::new();
-                    monitors.push(Box::new(dnd_monitor));
-                } else {
-                    tracing::info!("DND monitoring disabled");
-                }
+                // Always sample activity for schedules counting active time (it will
+                // self-disable if detection fails)
+                monitors.push(Box::new(monitors::ActivityMonitor::new(
+                    shared_state.clone(),
+                )));
 
-                // Add app whitelist monitor if there are exclusion rules
-                if !app_config.app_exclusions.is_empty() {
-                    let active_count = app_config
-                        .app_exclusions
-                        .iter()
-                        .filter(|e| e.active)
-                        .count();
-                    tracing::info!(
-                        "App whitelist monitoring enabled ({active_count} active rule(s))"
-                    );
-                    monitors.push(Box::new(monitors::AppWhitelistMonitor::new(
-                        app_config.app_exclusions.clone(),
-                    )));
-                }
+                // Always add sleep monitor to recompute timers after suspend or clock jumps
+                monitors.push(Box::new(monitors::SleepMonitor::new()));
 
-                if monitors.is_empty() {
-                    tracing::info!("No monitors enabled");
-                } else {
-                    monitors::spawn_monitor_tasks(
-                        monitors,
-                        cmd_tx.clone(),
-                        handle.clone(),
-                        shared_state.clone(),
-                    );
-                }
+                monitors::spawn_monitor_tasks(
+                    monitors,
+                    cmd_tx.clone(),
+                    monitor_config_rx,
+                    shared_state.clone(),
+                );
 
                 handle.manage(SchedulerCmd(cmd_tx)); // keep alive
                 handle.manage(ShutdownTx(shutdown_tx));
@@ -205,16 +201,23 @@ pub fn run() {
             cmd::config::get_config,
             cmd::config::pick_background_image,
             cmd::config::save_config,
+            cmd::history::get_break_history,
             cmd::payload::get_prompt_payload,
             cmd::payload::remove_prompt_payload,
             cmd::payload::store_prompt_payload,
+            cmd::scheduler::get_upcoming_events,
             cmd::scheduler::prompt_finished,
             cmd::scheduler::pause_scheduler,
+            cmd::scheduler::pause_scheduler_for,
             cmd::scheduler::postpone_break,
             cmd::scheduler::request_break_status,
             cmd::scheduler::resume_scheduler,
             cmd::scheduler::skip_break,
+            cmd::scheduler::start_pomodoro,
+            cmd::scheduler::stop_pomodoro,
             cmd::scheduler::trigger_event,
+            cmd::stats::get_daily_stats,
+            cmd::stats::get_weekly_stats,
             cmd::suggestions::get_suggestions,
             cmd::suggestions::get_suggestions_for_language,
             cmd::suggestions::save_suggestions,
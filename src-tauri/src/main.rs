@@ -1,5 +1,13 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 fn main() {
+    #[cfg(feature = "simulator")]
+    {
+        let args: Vec<String> = std::env::args().collect();
+        if let Some(code) = focust_lib::scheduler::simulator::run_cli(&args) {
+            std::process::exit(code);
+        }
+    }
+
     focust_lib::run();
 }
@@ -8,7 +8,7 @@
 //! The monitoring system consists of:
 //!
 //! - **`Monitor` trait**: Interface that all monitors must implement
-//! - **Concrete monitors**: `IdleMonitor`, `DndMonitor`, `AppWhitelistMonitor`
+//! - **Concrete monitors**: `ActivityMonitor`, `IdleMonitor`, `DndMonitor`, `AppWhitelistMonitor`, `SleepMonitor`
 //! - **Orchestrator**: Runs all monitors in a single task, checking at configured intervals
 //! - **Action conversion**: Converts `MonitorAction` to `Command` for the scheduler
 //! - **Session protection**: Unified session checking to prevent self-interference
@@ -18,7 +18,8 @@
 //! 1. **Creation**: Monitors are created with their specific configuration
 //! 2. **Initialization**: `on_start()` is called when the monitor begins
 //! 3. **Monitoring Loop**: `check()` is called periodically at the monitor's interval
-//! 4. **Shutdown**: `on_stop()` is called when the monitor stops (currently unused)
+//! 4. **Reconfiguration**: `reconfigure()` is called when the config is saved
+//! 5. **Shutdown**: `on_stop()` is called when a config change disables the monitor
 //!
 //! # Session Protection
 //!
@@ -31,7 +32,8 @@
 //!
 //! **Implementation**: The orchestrator checks `SharedState::in_any_session()` before
 //! calling each monitor's `check()` method. Monitors with `skip_during_session() == true`
-//! (the default) are automatically skipped during sessions.
+//! (the default) are automatically skipped during sessions. `SleepMonitor` and
+//! `ActivityMonitor` opt out, since suspends and input during a break still matter.
 //!
 //! # Design Patterns
 //!
@@ -72,20 +74,25 @@
 //! }
 //! ```
 
+mod activity;
 mod app_whitelist;
 mod dnd;
 mod idle;
 mod orchestrator;
+mod sleep;
 
+pub use activity::ActivityMonitor;
 pub use app_whitelist::AppWhitelistMonitor;
 pub use dnd::DndMonitor;
 pub use idle::IdleMonitor;
 pub use orchestrator::spawn_monitor_tasks;
+pub use sleep::SleepMonitor;
 
 use std::fmt::Display;
 use std::future::Future;
 use std::pin::Pin;
 
+use crate::config::AppConfig;
 use crate::scheduler::models::{Command, PauseReason};
 
 /// Result type for monitor check operations
@@ -125,6 +132,11 @@ pub enum MonitorAction {
     /// The monitor detected that a previous pause condition has cleared.
     /// The reason must match the one used in the corresponding `Pause`.
     Resume(PauseReason),
+    /// Notify that the system resumed from suspend or the wall clock jumped
+    ///
+    /// Carries the seconds spent asleep (0 if only the clock changed).
+    /// Schedulers recompute their timers instead of firing stale events.
+    SystemResumed(u64),
 }
 
 impl Display for MonitorAction {
@@ -133,6 +145,7 @@ impl Display for MonitorAction {
             MonitorAction::None => write!(f, "None"),
             MonitorAction::Pause(reason) => write!(f, "Pause({reason})"),
             MonitorAction::Resume(reason) => write!(f, "Resume({reason})"),
+            MonitorAction::SystemResumed(slept_s) => write!(f, "SystemResumed({slept_s}s)"),
         }
     }
 }
@@ -302,12 +315,33 @@ pub trait Monitor: Send + Sync {
     ///
     /// # Note
     ///
-    /// Currently, monitors run until the application exits and `on_stop()`
-    /// is never called. This method is provided for future use.
+    /// Only called when a config change disables the monitor. Monitors
+    /// still running when the application exits are not stopped.
     fn on_stop(&mut self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
         Box::pin(async {})
     }
 
+    /// Apply a saved config to the running monitor
+    ///
+    /// Returns `false` if `config` disables the monitor, which is then
+    /// stopped and removed by the orchestrator.
+    ///
+    /// # Default Implementation
+    ///
+    /// Keeps the monitor running unchanged. Monitors that do not depend
+    /// on the config don't need to override this.
+    fn reconfigure(&mut self, _config: &AppConfig) -> bool {
+        true
+    }
+
+    /// Pause reason this monitor pauses the scheduler with, if any
+    ///
+    /// When the monitor is removed, the orchestrator resumes this reason so
+    /// the scheduler is not left paused by a monitor that no longer runs.
+    fn pause_reason(&self) -> Option<PauseReason> {
+        None
+    }
+
     /// Whether to skip this monitor's checks during active sessions
     ///
     /// # Session Protection
@@ -334,7 +368,8 @@ pub trait Monitor: Send + Sync {
     ///
     /// Only override to return `false` if the monitor needs to detect
     /// critical system conditions that should interrupt sessions
-    /// (e.g., low battery, system shutdown).
+    /// (e.g., low battery, system shutdown), or only observes and never
+    /// pauses (e.g., activity sampling for break verification).
     ///
     /// # Implementation Note
     ///
@@ -369,6 +404,7 @@ pub trait Monitor: Send + Sync {
 ///
 /// - `Some(Command::Pause(reason))` for `MonitorAction::Pause`
 /// - `Some(Command::Resume(reason))` for `MonitorAction::Resume`
+/// - `Some(Command::SystemResumed(slept_s))` for `MonitorAction::SystemResumed`
 /// - `None` for `MonitorAction::None`
 ///
 /// # Example
@@ -381,7 +417,8 @@ pub trait Monitor: Send + Sync {
 pub fn action_to_command(action: MonitorAction) -> Option<Command> {
     match action {
         MonitorAction::None => None,
-        MonitorAction::Pause(reason) => Some(Command::Pause(reason)),
-        MonitorAction::Resume(reason) => Some(Command::Resume(reason)),
+        MonitorAction::Pause(reason) => Some(Command::Pause(reason, None)),
+        MonitorAction::Resume(reason) => Some(Command::Resume(reason, None)),
+        MonitorAction::SystemResumed(slept_s) => Some(Command::SystemResumed(slept_s)),
     }
 }
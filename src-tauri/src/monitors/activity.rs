@@ -0,0 +1,127 @@
+//! User activity sampling
+//!
+//! Samples the user's idle time frequently and feeds it to the activity tracker
+//! in [`SharedState`], which schedules with `count_active_time` use to measure
+//! their intervals in active time (see [`crate::scheduler::activity`]).
+//!
+//! # Behavior
+//!
+//! - Never pauses or resumes the scheduler, that is left to `IdleMonitor`
+//! - Keeps sampling during breaks, break verification watches these samples
+//! - **Self-disables** after 3 consecutive detection failures and stops the
+//!   tracker, so active-time schedules fall back to wall-clock time
+
+use std::future::Future;
+use std::pin::Pin;
+
+use chrono::Duration;
+use user_idle2::UserIdle;
+
+use super::{Monitor, MonitorAction, MonitorError, MonitorResult};
+use crate::scheduler::activity::ACTIVITY_SAMPLE_INTERVAL_S;
+use crate::scheduler::clock::{Clock, SystemClock};
+use crate::scheduler::shared_state::SharedState;
+
+/// Maximum consecutive failures before self-disabling
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Reads the seconds since the user's last input
+type IdleSource = fn() -> Result<u64, String>;
+
+/// Seconds since the last input, as reported by the system
+fn system_idle_s() -> Result<u64, String> {
+    UserIdle::get_time()
+        .map(|idle| idle.as_seconds())
+        .map_err(|e| e.to_string())
+}
+
+/// Monitor that samples user idle time into the activity tracker
+pub struct ActivityMonitor<C: Clock = SystemClock> {
+    /// Source of the time samples are taken at, shared with the schedulers
+    clock: C,
+    /// Shared scheduler state holding the activity tracker
+    shared_state: SharedState,
+    /// Where idle time is read from
+    idle_source: IdleSource,
+    /// Number of consecutive check failures
+    consecutive_failures: u32,
+    /// Whether the monitor has been disabled due to repeated failures
+    disabled: bool,
+}
+
+impl ActivityMonitor {
+    /// Create a new activity monitor feeding `shared_state`
+    #[must_use]
+    pub fn new(shared_state: SharedState) -> Self {
+        Self::with_clock(shared_state, SystemClock)
+    }
+}
+
+impl<C: Clock> ActivityMonitor<C> {
+    /// Create an activity monitor stamping samples with `clock`
+    #[must_use]
+    pub fn with_clock(shared_state: SharedState, clock: C) -> Self {
+        Self {
+            clock,
+            shared_state,
+            idle_source: system_idle_s,
+            consecutive_failures: 0,
+            disabled: false,
+        }
+    }
+
+    /// Read idle time from `idle_source` instead of the system
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn with_idle_source(mut self, idle_source: IdleSource) -> Self {
+        self.idle_source = idle_source;
+        self
+    }
+}
+
+impl<C: Clock> Monitor for ActivityMonitor<C> {
+    fn name(&self) -> &'static str {
+        "ActivityMonitor"
+    }
+
+    fn interval(&self) -> u64 {
+        ACTIVITY_SAMPLE_INTERVAL_S
+    }
+
+    fn check(&mut self) -> Pin<Box<dyn Future<Output = MonitorResult> + Send + '_>> {
+        Box::pin(async move {
+            if self.disabled {
+                return Ok(MonitorAction::None);
+            }
+
+            let idle_s = (self.idle_source)().map_err(|e| {
+                self.consecutive_failures += 1;
+
+                if self.consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+                    tracing::warn!(
+                        "User idle detection failed {} times. Active time will not be tracked.",
+                        self.consecutive_failures
+                    );
+                    self.disabled = true;
+                    self.shared_state.write().stop_activity_sampling();
+                }
+
+                MonitorError::CheckFailed(e)
+            })?;
+
+            self.consecutive_failures = 0;
+
+            let idle = Duration::seconds(idle_s.min(u64::from(u32::MAX)) as i64);
+            self.shared_state
+                .write()
+                .record_activity(self.clock.now(), idle);
+
+            Ok(MonitorAction::None)
+        })
+    }
+
+    fn skip_during_session(&self) -> bool {
+        // Break verification needs to see input during the break
+        false
+    }
+}
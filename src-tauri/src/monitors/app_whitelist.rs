@@ -8,7 +8,7 @@ use std::pin::Pin;
 use sysinfo::{ProcessRefreshKind, System};
 
 use super::{Monitor, MonitorAction, MonitorResult};
-use crate::config::AppExclusion;
+use crate::config::{AppConfig, AppExclusion};
 use crate::scheduler::models::PauseReason;
 
 const INTERVAL_SECS: u64 = 10;
@@ -135,6 +135,15 @@ impl Monitor for AppWhitelistMonitor {
             );
         })
     }
+
+    fn reconfigure(&mut self, config: &AppConfig) -> bool {
+        self.update_exclusions(config.app_exclusions.clone());
+        !self.exclusions.is_empty()
+    }
+
+    fn pause_reason(&self) -> Option<PauseReason> {
+        Some(PauseReason::AppExclusion)
+    }
 }
 
 #[cfg(test)]
@@ -10,11 +10,12 @@
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Instant;
 
 use tokio::sync::{Mutex as AsyncMutex, mpsc};
+use tokio::time::Instant;
 
 use super::{Monitor, MonitorAction, MonitorError, MonitorResult};
+use crate::config::AppConfig;
 use crate::platform::dnd::{DndEvent, DndMonitor as PlatformDndMonitor, INTERVAL_SECS};
 use crate::scheduler::models::PauseReason;
 
@@ -188,4 +189,24 @@ impl Monitor for DndMonitor {
             }
         })
     }
+
+    fn on_stop(&mut self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async {
+            if let Some(mut monitor) = self.platform_monitor.take()
+                && let Err(e) = monitor.stop().await
+            {
+                tracing::warn!("Failed to stop DND monitor: {e}");
+            }
+            *self.event_rx.lock().await = None;
+            tracing::info!("DndMonitor stopped");
+        })
+    }
+
+    fn reconfigure(&mut self, config: &AppConfig) -> bool {
+        config.monitor_dnd
+    }
+
+    fn pause_reason(&self) -> Option<PauseReason> {
+        Some(PauseReason::Dnd)
+    }
 }
@@ -32,6 +32,7 @@ use std::pin::Pin;
 use user_idle2::UserIdle;
 
 use super::{Monitor, MonitorAction, MonitorError, MonitorResult};
+use crate::config::AppConfig;
 use crate::scheduler::models::PauseReason;
 
 /// How often to check for idle state (seconds)
@@ -198,4 +199,16 @@ impl Monitor for IdleMonitor {
             );
         })
     }
+
+    fn reconfigure(&mut self, config: &AppConfig) -> bool {
+        if config.inactive_s != self.inactive_threshold_s {
+            tracing::info!("Idle threshold changed to {}s", config.inactive_s);
+            self.update_threshold(config.inactive_s);
+        }
+        true
+    }
+
+    fn pause_reason(&self) -> Option<PauseReason> {
+        Some(PauseReason::UserIdle)
+    }
 }
@@ -20,11 +20,12 @@
 //! if their interval hasn't elapsed yet.
 //!
 //! For example, if you have:
-//! - `IdleMonitor` (interval: 5s)
-//! - `DndMonitor` (interval: 1s)
+//! - `ActivityMonitor` (interval: 2s)
+//! - `IdleMonitor` (interval: 10s)
 //! - `AppWhitelistMonitor` (interval: 10s)
 //!
-//! The orchestrator will wake up every 1 second and check all monitors.
+//! The orchestrator will wake up every 2 seconds, check `ActivityMonitor` on
+//! every tick and the other monitors on every fifth tick.
 //!
 //! # Error Handling
 //!
@@ -32,61 +33,67 @@
 //! - `Unavailable` errors are logged once (monitors can self-disable)
 //! - If the command channel closes, the orchestrator exits gracefully
 //!
+//! # Reconfiguration
+//!
+//! The orchestrator follows the saved config through a `watch` channel:
+//!
+//! - Running monitors are reconfigured in place (idle threshold, exclusion rules)
+//! - Monitors the config disables are stopped and removed, and their pause
+//!   reason is resumed if the scheduler is paused by it
+//! - Monitors the config enables are started (`DndMonitor`, `AppWhitelistMonitor`)
+//! - The check interval is recomputed for the new set of monitors
+//!
 //! # Future Improvements
 //!
-//! - Per-monitor interval tracking (check each monitor only when its interval elapsed)
-//! - Dynamic monitor addition/removal
 //! - Monitor health monitoring and automatic restart
 
-use tauri::AppHandle;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
+use tokio::time::{Duration, Interval};
 
-use super::{Monitor, action_to_command};
-use crate::scheduler::models::Command;
+use super::{AppWhitelistMonitor, DndMonitor, Monitor, action_to_command};
+use crate::config::AppConfig;
+use crate::scheduler::models::{Command, PauseReason};
 use crate::scheduler::shared_state::SharedState;
 
 /// Spawn monitor tasks for the scheduler
 ///
 /// This creates a single background task that runs all provided monitors,
 /// checking each at its configured interval and sending commands as needed.
+/// Monitors that depend on the config (`DndMonitor`, `AppWhitelistMonitor`)
+/// are added by the orchestrator itself from the current value of `config_rx`.
 ///
 /// # Arguments
 ///
-/// * `monitors` - Vector of boxed monitors to run
+/// * `monitors` - Vector of boxed monitors that always run
 /// * `cmd_tx` - Channel sender for sending commands to the scheduler
-/// * `_app_handle` - Tauri app handle (reserved for future use)
+/// * `config_rx` - Saved config, followed to reconfigure monitors at runtime
 /// * `shared_state` - Shared scheduler state for session checking
 ///
 /// # Behavior
 ///
-/// - If no monitors are provided, returns immediately without spawning a task
 /// - Spawns a single async task that runs all monitors
 /// - Task runs until the command channel is closed
 /// - Automatically skips monitors during active sessions (if configured)
+/// - Adds, removes and reconfigures monitors when the config changes
 ///
 /// # Examples
 ///
 /// ```rust,ignore
 /// let monitors: Vec<Box<dyn Monitor>> = vec![
 ///     Box::new(IdleMonitor::new(120)),
-///     Box::new(DndMonitor::new()),
+///     Box::new(SleepMonitor::new()),
 /// ];
 ///
-/// spawn_monitor_tasks(monitors, cmd_tx, app_handle, shared_state);
+/// spawn_monitor_tasks(monitors, cmd_tx, config_updates.subscribe(), shared_state);
 /// ```
 pub fn spawn_monitor_tasks(
     monitors: Vec<Box<dyn Monitor>>,
     cmd_tx: mpsc::Sender<Command>,
-    _app_handle: AppHandle,
+    config_rx: watch::Receiver<AppConfig>,
     shared_state: SharedState,
 ) {
-    if monitors.is_empty() {
-        tracing::debug!("No monitors configured, skipping monitor task spawn");
-        return;
-    }
-
     tokio::spawn(async move {
-        run_monitors(monitors, cmd_tx, shared_state).await;
+        run_monitors(monitors, cmd_tx, config_rx, shared_state).await;
     });
 }
 
@@ -115,6 +122,7 @@ pub fn spawn_monitor_tasks(
 /// 1. **`MonitorAction` → Command Conversion**:
 ///    - `MonitorAction::Pause(reason)` → `Command::Pause(reason)`
 ///    - `MonitorAction::Resume(reason)` → `Command::Resume(reason)`
+///    - `MonitorAction::SystemResumed(slept_s)` → `Command::SystemResumed(slept_s)`
 ///    - `MonitorAction::None` → No command sent
 ///
 /// 2. **Unified Session Protection**:
@@ -132,25 +140,28 @@ pub fn spawn_monitor_tasks(
 ///
 /// * `monitors` - Mutable vector of monitors to run
 /// * `cmd_tx` - Channel sender for scheduler commands
+/// * `config_rx` - Saved config to follow
 /// * `shared_state` - Shared scheduler state for session checking
 ///
 /// # Lifecycle
 ///
 /// 1. **Initialization Phase**:
+///    - Add the monitors enabled by the current config
 ///    - Call `on_start()` on each monitor (async initialization)
 ///    - Calculate minimum interval across all monitors
 ///    - Create interval timer with that period
 ///
 /// 2. **Monitoring Loop**:
 ///    - Wait for timer tick
-///    - Check each monitor sequentially
+///    - Check each monitor sequentially whose interval has elapsed
 ///    - Convert actions to commands using `action_to_command()`
 ///    - Send commands to scheduler via `cmd_tx`
 ///    - Handle errors gracefully (log but continue)
+///    - On config change, apply it with [`apply_config()`]
 ///
 /// 3. **Shutdown**:
 ///    - Exits when command channel closes (scheduler shutdown)
-///    - Currently does NOT call `on_stop()` (future improvement)
+///    - Does NOT call `on_stop()` on the remaining monitors (future improvement)
 ///
 /// # Performance Considerations
 ///
@@ -196,15 +207,18 @@ pub fn spawn_monitor_tasks(
 ///
 /// # Future Improvements
 ///
-/// - Per-monitor interval tracking (only check when interval elapsed)
 /// - Call `on_stop()` during graceful shutdown
 /// - Monitor health metrics and auto-restart
 /// - Parallel monitor checking (if safe)
 async fn run_monitors(
     mut monitors: Vec<Box<dyn Monitor>>,
     cmd_tx: mpsc::Sender<Command>,
+    mut config_rx: watch::Receiver<AppConfig>,
     shared_state: SharedState,
 ) {
+    let config = config_rx.borrow_and_update().clone();
+    monitors.extend(missing_monitors(&monitors, &config));
+
     tracing::info!(
         "Starting monitor orchestrator with {} monitor(s)",
         monitors.len()
@@ -216,46 +230,279 @@ async fn run_monitors(
         monitor.on_start().await;
     }
 
-    // Find minimum interval for check duration
-    let check_interval = monitors.iter().map(|m| m.interval()).min().unwrap_or(1);
-
-    let mut interval_timer =
-        tokio::time::interval(tokio::time::Duration::from_secs(check_interval));
-
-    tracing::debug!("Monitor check interval: {check_interval}s");
+    let (mut check_interval, mut strides) = check_schedule(&monitors);
+    let mut interval_timer = check_timer(check_interval);
+    let mut follow_config = true;
 
+    let mut tick: u64 = 0;
     loop {
-        interval_timer.tick().await;
+        tokio::select! {
+            _ = interval_timer.tick() => {
+                let current_tick = tick;
+                tick = tick.wrapping_add(1);
 
-        let in_session = shared_state.read().in_any_session();
-
-        // Check each monitor if its interval has elapsed
-        for monitor in &mut monitors {
-            // Skip monitors during session if they request it
-            if monitor.skip_during_session() && in_session {
-                continue;
+                if !check_monitors(&mut monitors, &strides, current_tick, &cmd_tx, &shared_state)
+                    .await
+                {
+                    return;
+                }
             }
-
-            let action = match monitor.check().await {
-                Ok(a) => a,
-                Err(e) => {
-                    tracing::debug!("Monitor '{}' check error: {e}", monitor.name());
+            changed = config_rx.changed(), if follow_config => {
+                if changed.is_err() {
+                    tracing::debug!("Config updates closed, monitors keep their config");
+                    follow_config = false;
                     continue;
                 }
-            };
 
-            tracing::debug!("Monitor '{}' triggered action: {action}", monitor.name());
-            let Some(cmd) = action_to_command(action) else {
+                let config = config_rx.borrow_and_update().clone();
+                if !apply_config(&mut monitors, &config, &cmd_tx, &shared_state).await {
+                    return;
+                }
+
+                let previous_interval = check_interval;
+                (check_interval, strides) = check_schedule(&monitors);
+                if check_interval != previous_interval {
+                    interval_timer = check_timer(check_interval);
+                }
+            }
+        }
+    }
+}
+
+/// Check each monitor whose interval has elapsed at `tick`
+///
+/// Returns `false` if the command channel is closed.
+async fn check_monitors(
+    monitors: &mut [Box<dyn Monitor>],
+    strides: &[u64],
+    tick: u64,
+    cmd_tx: &mpsc::Sender<Command>,
+    shared_state: &SharedState,
+) -> bool {
+    let in_session = shared_state.read().in_any_session();
+
+    for (monitor, stride) in monitors.iter_mut().zip(strides) {
+        if !tick.is_multiple_of(*stride) {
+            continue;
+        }
+
+        // Skip monitors during session if they request it
+        if monitor.skip_during_session() && in_session {
+            continue;
+        }
+
+        let action = match monitor.check().await {
+            Ok(a) => a,
+            Err(e) => {
+                tracing::debug!("Monitor '{}' check error: {e}", monitor.name());
                 continue;
-            };
-
-            if let Err(e) = cmd_tx.send(cmd).await {
-                tracing::error!(
-                    "Failed to send command from monitor '{}': {e}",
-                    monitor.name()
-                );
-                return;
             }
+        };
+
+        tracing::debug!("Monitor '{}' triggered action: {action}", monitor.name());
+        let Some(cmd) = action_to_command(action) else {
+            continue;
+        };
+
+        if let Err(e) = cmd_tx.send(cmd).await {
+            tracing::error!(
+                "Failed to send command from monitor '{}': {e}",
+                monitor.name()
+            );
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Bring the running monitors in line with a saved config
+///
+/// Monitors disabled by `config` are stopped and removed. If the scheduler is
+/// paused for the reason of a removed monitor, that reason is resumed, as
+/// nothing would resume it anymore. Monitors enabled by `config` are started.
+///
+/// Returns `false` if the command channel is closed.
+async fn apply_config(
+    monitors: &mut Vec<Box<dyn Monitor>>,
+    config: &AppConfig,
+    cmd_tx: &mpsc::Sender<Command>,
+    shared_state: &SharedState,
+) -> bool {
+    let removed: Vec<_> = monitors
+        .extract_if(.., |monitor| !monitor.reconfigure(config))
+        .collect();
+
+    for mut monitor in removed {
+        tracing::info!("Stopping monitor disabled by config: {}", monitor.name());
+        monitor.on_stop().await;
+
+        let Some(reason) = monitor.pause_reason() else {
+            continue;
+        };
+        if !shared_state.read().pause_reasons().contains(&reason) {
+            continue;
+        }
+        tracing::info!("Clearing pause reason {reason} of removed monitor");
+        if let Err(e) = cmd_tx.send(Command::Resume(reason, None)).await {
+            tracing::error!("Failed to resume {reason} of removed monitor: {e}");
+            return false;
         }
     }
+
+    for mut monitor in missing_monitors(monitors, config) {
+        tracing::debug!("Initializing monitor: {}", monitor.name());
+        monitor.on_start().await;
+        monitors.push(monitor);
+    }
+
+    true
+}
+
+/// Create the monitors enabled by `config` that are not running yet
+fn missing_monitors(monitors: &[Box<dyn Monitor>], config: &AppConfig) -> Vec<Box<dyn Monitor>> {
+    let running = |reason| monitors.iter().any(|m| m.pause_reason() == Some(reason));
+    let mut missing: Vec<Box<dyn Monitor>> = vec![];
+
+    if config.monitor_dnd && !running(PauseReason::Dnd) {
+        tracing::info!("DND monitoring enabled");
+        missing.push(Box::new(DndMonitor::new()));
+    }
+
+    if !config.app_exclusions.is_empty() && !running(PauseReason::AppExclusion) {
+        let active_count = config.app_exclusions.iter().filter(|e| e.active).count();
+        tracing::info!("App whitelist monitoring enabled ({active_count} active rule(s))");
+        missing.push(Box::new(AppWhitelistMonitor::new(
+            config.app_exclusions.clone(),
+        )));
+    }
+
+    missing
+}
+
+/// Timer period in seconds and the number of ticks between checks of each monitor
+fn check_schedule(monitors: &[Box<dyn Monitor>]) -> (u64, Vec<u64>) {
+    // Find minimum interval for check duration
+    let check_interval = monitors.iter().map(|m| m.interval()).min().unwrap_or(1);
+
+    let strides = monitors
+        .iter()
+        .map(|m| (m.interval() / check_interval).max(1))
+        .collect();
+
+    tracing::debug!("Monitor check interval: {check_interval}s");
+    (check_interval, strides)
+}
+
+fn check_timer(check_interval: u64) -> Interval {
+    tokio::time::interval(Duration::from_secs(check_interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppExclusion;
+    use crate::monitors::ActivityMonitor;
+    use crate::scheduler::clock::FakeClock;
+    use crate::scheduler::shared_state::create_shared_state;
+
+    fn config_with(monitor_dnd: bool, app_exclusions: Vec<AppExclusion>) -> AppConfig {
+        AppConfig {
+            monitor_dnd,
+            app_exclusions,
+            ..AppConfig::default()
+        }
+    }
+
+    fn exclusions() -> Vec<AppExclusion> {
+        vec![AppExclusion::pause(vec!["focust-test-app".to_string()])]
+    }
+
+    #[test]
+    fn missing_monitors_follow_config() {
+        let missing = missing_monitors(&[], &config_with(true, exclusions()));
+        let names: Vec<_> = missing.iter().map(|m| m.name()).collect();
+        assert_eq!(names, ["DndMonitor", "AppWhitelistMonitor"]);
+
+        // Already running monitors are not added twice
+        assert!(missing_monitors(&missing, &config_with(true, exclusions())).is_empty());
+        assert!(missing_monitors(&[], &config_with(false, vec![])).is_empty());
+    }
+
+    #[tokio::test]
+    async fn apply_config_keeps_reconfigured_monitors() {
+        let shared_state = create_shared_state();
+        shared_state
+            .write()
+            .add_pause_reason(PauseReason::AppExclusion);
+        let (cmd_tx, mut cmd_rx) = mpsc::channel(8);
+        let mut monitors: Vec<Box<dyn Monitor>> =
+            vec![Box::new(AppWhitelistMonitor::new(exclusions()))];
+
+        let mut rules = exclusions();
+        rules.push(AppExclusion::pause(vec!["focust-other-app".to_string()]));
+        assert!(
+            apply_config(
+                &mut monitors,
+                &config_with(false, rules),
+                &cmd_tx,
+                &shared_state
+            )
+            .await
+        );
+
+        assert_eq!(monitors.len(), 1);
+        assert!(cmd_rx.try_recv().is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn removed_monitor_resumes_its_pause_reason() {
+        let shared_state = create_shared_state();
+        shared_state
+            .write()
+            .add_pause_reason(PauseReason::AppExclusion);
+        let (config_tx, config_rx) = watch::channel(config_with(false, exclusions()));
+        let (cmd_tx, mut cmd_rx) = mpsc::channel(8);
+        let orchestrator = tokio::spawn(run_monitors(
+            vec![],
+            cmd_tx,
+            config_rx,
+            shared_state.clone(),
+        ));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(cmd_rx.try_recv().is_err());
+
+        // Removing the last exclusion rule removes the monitor
+        config_tx.send_replace(config_with(false, vec![]));
+        assert!(matches!(
+            cmd_rx.recv().await,
+            Some(Command::Resume(PauseReason::AppExclusion, None))
+        ));
+
+        orchestrator.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn activity_is_sampled_during_break_session() {
+        let shared_state = create_shared_state();
+        shared_state.write().start_break_session();
+        let clock = FakeClock::new(chrono::Utc::now());
+        let monitor =
+            ActivityMonitor::with_clock(shared_state.clone(), clock).with_idle_source(|| Ok(0));
+        let (_config_tx, config_rx) = watch::channel(config_with(false, vec![]));
+        let (cmd_tx, _cmd_rx) = mpsc::channel(8);
+        let orchestrator = tokio::spawn(run_monitors(
+            vec![Box::new(monitor)],
+            cmd_tx,
+            config_rx,
+            shared_state.clone(),
+        ));
+        assert_eq!(shared_state.read().idle_s(), None);
+
+        // Break verification relies on the input seen during the break
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(shared_state.read().idle_s(), Some(0));
+
+        orchestrator.abort();
+    }
 }
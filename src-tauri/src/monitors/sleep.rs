@@ -0,0 +1,302 @@
+//! System suspend/resume and wall-clock jump detection
+//!
+//! Timers in the schedulers run on the monotonic clock while break and attention
+//! times are wall-clock instants. After a suspend or a manual clock change the two
+//! diverge, so the schedulers must be told to recompute their timers.
+//!
+//! # Detection
+//!
+//! - **Suspend notifications**: logind `PrepareForSleep` on Linux (see
+//!   [`crate::platform::sleep`]); the time asleep is measured between the
+//!   suspend and resume events
+//! - **Drift detector**: on every platform, the wall-clock time elapsed between two
+//!   checks is compared with the monotonic time elapsed. A forward jump is treated
+//!   as time asleep, a backward jump only requires recomputing timers
+//!
+//! # Behavior
+//!
+//! Returns `MonitorAction::SystemResumed(slept_s)` once per detected resume or
+//! clock jump. A suspend noticed from the drift before its resume notification
+//! arrived is not reported again by the notification. The monitor keeps running
+//! during break and attention sessions.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use super::{Monitor, MonitorAction, MonitorResult};
+use crate::platform::sleep::{SleepEvent, watch_sleep_events};
+use crate::scheduler::clock::{Clock, SystemClock};
+
+/// How often to check for resume events and clock drift (seconds)
+const INTERVAL_SECS: u64 = 10;
+
+/// Minimum difference between wall-clock and monotonic elapsed time treated as a jump
+///
+/// Must be well above scheduling jitter, NTP corrections and the check interval.
+const DRIFT_THRESHOLD_S: i64 = 30;
+
+/// Monitor that detects system suspend/resume and wall-clock jumps
+pub struct SleepMonitor<C: Clock = SystemClock> {
+    /// Source of the wall-clock time
+    clock: C,
+    /// Channel receiver for platform suspend/resume events
+    event_rx: Option<mpsc::Receiver<SleepEvent>>,
+    /// When the system reported it was about to suspend
+    suspended_at: Option<DateTime<Utc>>,
+    /// Wall-clock and monotonic time of the previous check
+    last_check: Option<(DateTime<Utc>, Instant)>,
+    /// When a clock jump was last reported
+    jump_reported_at: Option<DateTime<Utc>>,
+}
+
+impl Default for SleepMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SleepMonitor {
+    /// Create a new sleep monitor
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock> SleepMonitor<C> {
+    /// Create a sleep monitor reading the wall clock from `clock`
+    #[must_use]
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            event_rx: None,
+            suspended_at: None,
+            last_check: None,
+            jump_reported_at: None,
+        }
+    }
+
+    /// Process pending platform events, returning the seconds slept on resume
+    fn drain_events(&mut self) -> Option<u64> {
+        let rx = self.event_rx.as_mut()?;
+        let mut slept_s = None;
+
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                SleepEvent::Suspending(at) => {
+                    tracing::info!("System is suspending");
+                    self.suspended_at = Some(at);
+                }
+                SleepEvent::Resumed(at) => {
+                    let slept = self
+                        .suspended_at
+                        .take()
+                        .map_or(0, |suspended_at| (at - suspended_at).num_seconds());
+                    if self.jump_reported_at.is_some_and(|reported_at| {
+                        (at - reported_at).num_seconds().abs() <= DRIFT_THRESHOLD_S
+                    }) {
+                        tracing::debug!(
+                            "System resumed after {slept}s, already reported from clock drift"
+                        );
+                        continue;
+                    }
+                    tracing::info!("System resumed after {slept}s");
+                    slept_s = Some(u64::try_from(slept).unwrap_or(0));
+                }
+            }
+        }
+
+        slept_s
+    }
+}
+
+/// Classify the gap between two checks as a clock jump
+///
+/// Returns the seconds the wall clock ran ahead of the monotonic clock (0 for a
+/// backward jump), or `None` if both clocks agree within [`DRIFT_THRESHOLD_S`].
+fn detect_clock_jump(
+    wall_elapsed: chrono::Duration,
+    mono_elapsed: std::time::Duration,
+) -> Option<u64> {
+    let mono_elapsed = chrono::Duration::from_std(mono_elapsed).ok()?;
+    let drift_s = (wall_elapsed - mono_elapsed).num_seconds();
+
+    if drift_s >= DRIFT_THRESHOLD_S {
+        Some(drift_s.unsigned_abs())
+    } else if drift_s <= -DRIFT_THRESHOLD_S {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+impl<C: Clock> Monitor for SleepMonitor<C> {
+    fn name(&self) -> &'static str {
+        "SleepMonitor"
+    }
+
+    fn interval(&self) -> u64 {
+        INTERVAL_SECS
+    }
+
+    fn check(&mut self) -> Pin<Box<dyn Future<Output = MonitorResult> + Send + '_>> {
+        Box::pin(async move {
+            let now = self.clock.now();
+            let now_mono = Instant::now();
+
+            let resumed = self.drain_events();
+            let jump = self.last_check.and_then(|(last, last_mono)| {
+                detect_clock_jump(now - last, now_mono.duration_since(last_mono))
+            });
+            self.last_check = Some((now, now_mono));
+
+            // A resume reported by the system already accounts for the drift
+            let Some(slept_s) = resumed.or(jump) else {
+                return Ok(MonitorAction::None);
+            };
+
+            if resumed.is_none() {
+                tracing::info!("Wall clock jumped, treating {slept_s}s as time asleep");
+                self.jump_reported_at = Some(now);
+            }
+            Ok(MonitorAction::SystemResumed(slept_s))
+        })
+    }
+
+    fn on_start(&mut self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async {
+            let (tx, rx) = mpsc::channel(8);
+            self.event_rx = Some(rx);
+            self.last_check = Some((self.clock.now(), Instant::now()));
+
+            let clock = self.clock.clone();
+            tokio::spawn(async move {
+                if let Err(e) = watch_sleep_events(tx, clock).await {
+                    tracing::info!(
+                        "Suspend notifications unavailable ({e}), relying on clock drift detection"
+                    );
+                }
+            });
+        })
+    }
+
+    fn skip_during_session(&self) -> bool {
+        // Suspending during a break must still be noticed, the break scheduler
+        // decides what to do with it
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::clock::FakeClock;
+    use chrono::{Duration, TimeZone};
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, 3, 14, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn no_jump_when_clocks_agree() {
+        let jump = detect_clock_jump(Duration::seconds(12), std::time::Duration::from_secs(10));
+        assert_eq!(jump, None);
+    }
+
+    #[test]
+    fn forward_jump_reports_time_asleep() {
+        let jump = detect_clock_jump(
+            Duration::minutes(45) + Duration::seconds(10),
+            std::time::Duration::from_secs(10),
+        );
+        assert_eq!(jump, Some(45 * 60));
+    }
+
+    #[test]
+    fn backward_jump_reports_zero() {
+        let jump = detect_clock_jump(Duration::minutes(-5), std::time::Duration::from_secs(10));
+        assert_eq!(jump, Some(0));
+    }
+
+    #[test]
+    fn resume_event_reports_time_since_suspend() {
+        let (tx, rx) = mpsc::channel(8);
+        let mut monitor = SleepMonitor {
+            event_rx: Some(rx),
+            ..SleepMonitor::new()
+        };
+
+        tx.try_send(SleepEvent::Suspending(base_time())).unwrap();
+        assert_eq!(monitor.drain_events(), None);
+
+        tx.try_send(SleepEvent::Resumed(base_time() + Duration::minutes(30)))
+            .unwrap();
+        assert_eq!(monitor.drain_events(), Some(30 * 60));
+        assert!(monitor.suspended_at.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn check_detects_wall_clock_jump() {
+        let clock = FakeClock::new(base_time());
+        let mut monitor = SleepMonitor::with_clock(clock.clone());
+        monitor.last_check = Some((clock.now(), Instant::now()));
+
+        tokio::time::advance(std::time::Duration::from_secs(INTERVAL_SECS)).await;
+        assert!(matches!(monitor.check().await, Ok(MonitorAction::None)));
+
+        // Suspended for an hour: the wall clock moves, tokio time does not
+        clock.jump(Duration::hours(1));
+        tokio::time::advance(std::time::Duration::from_secs(INTERVAL_SECS)).await;
+        assert!(matches!(
+            monitor.check().await,
+            Ok(MonitorAction::SystemResumed(3600))
+        ));
+    }
+
+    #[test]
+    fn resume_without_suspend_reports_zero() {
+        let (tx, rx) = mpsc::channel(8);
+        let mut monitor = SleepMonitor {
+            event_rx: Some(rx),
+            ..SleepMonitor::new()
+        };
+
+        tx.try_send(SleepEvent::Resumed(base_time())).unwrap();
+        assert_eq!(monitor.drain_events(), Some(0));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn late_resume_event_after_clock_jump_is_not_reported_again() {
+        let clock = FakeClock::new(base_time());
+        let (tx, rx) = mpsc::channel(8);
+        let mut monitor = SleepMonitor {
+            event_rx: Some(rx),
+            ..SleepMonitor::with_clock(clock.clone())
+        };
+        monitor.last_check = Some((clock.now(), Instant::now()));
+
+        // The drift is noticed before logind reports the resume
+        clock.jump(Duration::hours(1));
+        tokio::time::advance(std::time::Duration::from_secs(INTERVAL_SECS)).await;
+        assert!(matches!(
+            monitor.check().await,
+            Ok(MonitorAction::SystemResumed(3600))
+        ));
+
+        tx.try_send(SleepEvent::Suspending(base_time())).unwrap();
+        tx.try_send(SleepEvent::Resumed(clock.now() - Duration::seconds(5)))
+            .unwrap();
+        tokio::time::advance(std::time::Duration::from_secs(INTERVAL_SECS)).await;
+        assert!(matches!(monitor.check().await, Ok(MonitorAction::None)));
+
+        // A later suspend is reported as usual
+        tx.try_send(SleepEvent::Suspending(clock.now())).unwrap();
+        tx.try_send(SleepEvent::Resumed(clock.now() + Duration::minutes(10)))
+            .unwrap();
+        assert_eq!(monitor.drain_events(), Some(10 * 60));
+    }
+}
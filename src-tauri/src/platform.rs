@@ -2,6 +2,7 @@ pub mod dnd;
 pub mod hotkey;
 pub mod i18n;
 pub mod notifications;
+pub mod sleep;
 pub mod tray;
 pub mod window;
 
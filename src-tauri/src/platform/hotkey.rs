@@ -3,17 +3,21 @@ use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut,
 
 use crate::cmd::SchedulerCmd;
 use crate::config::SharedConfig;
+use crate::scheduler::SharedState;
 use crate::scheduler::models::Command;
 
 /// Register global shortcuts for the application
 ///
-/// Reads the postpone shortcut from config and registers it if specified.
-/// If the shortcut string is empty, no shortcut will be registered.
+/// Reads the postpone and Pomodoro shortcuts from config and registers those
+/// specified. If a shortcut string is empty, that shortcut will not be registered.
 pub async fn register_shortcuts<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
-    let postpone_shortcut = {
+    let (postpone_shortcut, pomodoro_shortcut) = {
         let config_state = app.state::<SharedConfig>();
         let config = config_state.read().await;
-        config.postpone_shortcut.clone()
+        (
+            config.postpone_shortcut.clone(),
+            config.pomodoro_shortcut.clone(),
+        )
     };
 
     // Only register if shortcut is configured
@@ -24,9 +28,18 @@ pub async fn register_shortcuts<R: Runtime>(app: &AppHandle<R>) -> Result<(), St
             tracing::error!("Failed to register postpone shortcut '{postpone_shortcut}': {e}");
             e
         })?;
-        tracing::info!("Global shortcuts registered successfully");
     }
 
+    if pomodoro_shortcut.is_empty() {
+        tracing::info!("No Pomodoro shortcut configured, skipping registration");
+    } else {
+        register_pomodoro_shortcut(app, &pomodoro_shortcut).map_err(|e| {
+            tracing::error!("Failed to register Pomodoro shortcut '{pomodoro_shortcut}': {e}");
+            e
+        })?;
+    }
+
+    tracing::info!("Global shortcuts registered successfully");
     Ok(())
 }
 
@@ -46,7 +59,7 @@ fn register_postpone_shortcut<R: Runtime>(
 
                 // Send postpone command to scheduler
                 if let Some(scheduler_cmd) = app_handle.try_state::<SchedulerCmd>() {
-                    scheduler_cmd.try_send_command(&Command::PostponeBreak);
+                    scheduler_cmd.try_send_command(&Command::PostponeBreak(None));
                 } else {
                     tracing::warn!("SchedulerCmd state not found");
                 }
@@ -58,6 +71,41 @@ fn register_postpone_shortcut<R: Runtime>(
     Ok(())
 }
 
+/// Register the shortcut starting or stopping a Pomodoro session
+fn register_pomodoro_shortcut<R: Runtime>(
+    app: &AppHandle<R>,
+    shortcut_str: &str,
+) -> Result<(), String> {
+    let shortcut = parse_shortcut(shortcut_str)?;
+
+    let app_handle = app.clone();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                tracing::debug!("Pomodoro shortcut triggered");
+
+                let in_pomodoro = app_handle
+                    .try_state::<SharedState>()
+                    .is_some_and(|state| state.read().pomodoro().is_some());
+                let command = if in_pomodoro {
+                    Command::StopPomodoro(None)
+                } else {
+                    Command::StartPomodoro(None, None)
+                };
+
+                if let Some(scheduler_cmd) = app_handle.try_state::<SchedulerCmd>() {
+                    scheduler_cmd.try_send_command(&command);
+                } else {
+                    tracing::warn!("SchedulerCmd state not found");
+                }
+            }
+        })
+        .map_err(|e| format!("Failed to register shortcut: {e}"))?;
+
+    tracing::info!("Registered Pomodoro shortcut: {shortcut_str}");
+    Ok(())
+}
+
 /// Parse a shortcut string into a Shortcut struct
 /// Supported format: "Ctrl+Shift+X", etc.
 fn parse_shortcut(s: &str) -> Result<Shortcut, String> {
@@ -16,10 +16,19 @@ pub struct LanguageStrings {
 }
 
 #[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct TrayStrings {
     pub show: String,
     pub pause: String,
     pub resume: String,
+    pub pause_indefinitely: String,
+    pub pause_30_minutes: String,
+    pub pause_1_hour: String,
+    pub pause_until_schedule_end: String,
+    pub pause_until_tomorrow: String,
+    pub paused_until: String,
+    pub start_pomodoro: String,
+    pub stop_pomodoro: String,
     pub restart: String,
     pub quit: String,
     pub tooltip: String,
@@ -41,6 +50,14 @@ impl Default for TrayStrings {
             show: "Show Settings".to_string(),
             pause: "Pause Breaks".to_string(),
             resume: "Resume Breaks".to_string(),
+            pause_indefinitely: "Indefinitely".to_string(),
+            pause_30_minutes: "For 30 Minutes".to_string(),
+            pause_1_hour: "For 1 Hour".to_string(),
+            pause_until_schedule_end: "Until End of Schedule".to_string(),
+            pause_until_tomorrow: "Until Tomorrow".to_string(),
+            paused_until: "Paused until {time}".to_string(),
+            start_pomodoro: "Start Pomodoro".to_string(),
+            stop_pomodoro: "Stop Pomodoro".to_string(),
             restart: "Restart".to_string(),
             quit: "Quit".to_string(),
             tooltip: "Focust - Break Reminder".to_string(),
@@ -0,0 +1,92 @@
+//! System suspend/resume notifications
+//!
+//! - **Linux**: Event-driven via the logind `PrepareForSleep` D-Bus signal
+//! - **Windows/macOS**: Not available, suspend is detected from wall-clock drift
+//!   by `SleepMonitor` instead
+//!
+//! Events are stamped with the wall-clock time at which they were received, read
+//! from the monitor's [`Clock`], so the time spent asleep can be computed even though
+//! the process itself is frozen.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+
+use crate::scheduler::clock::Clock;
+
+/// Suspend/resume event reported by the operating system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SleepEvent {
+    /// The system is about to suspend
+    Suspending(DateTime<Utc>),
+    /// The system has resumed from suspend
+    Resumed(DateTime<Utc>),
+}
+
+/// Watch for system suspend/resume and forward events to `sender`
+///
+/// Events are stamped with `clock`. Runs until the event source or the channel
+/// closes. Returns an error if notifications are not available on this platform.
+pub async fn watch_sleep_events<C: Clock>(
+    sender: mpsc::Sender<SleepEvent>,
+    clock: C,
+) -> Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        watch_logind(sender, clock).await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        drop((sender, clock));
+        Err(anyhow::anyhow!(
+            "Suspend notifications are not supported on this platform"
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+async fn watch_logind<C: Clock>(sender: mpsc::Sender<SleepEvent>, clock: C) -> Result<()> {
+    use anyhow::Context;
+    use futures_util::StreamExt;
+    use zbus::{Connection, proxy};
+
+    #[proxy(
+        interface = "org.freedesktop.login1.Manager",
+        default_service = "org.freedesktop.login1",
+        default_path = "/org/freedesktop/login1"
+    )]
+    trait Login1Manager {
+        #[zbus(signal)]
+        fn prepare_for_sleep(&self, start: bool) -> zbus::Result<()>;
+    }
+
+    let connection = Connection::system()
+        .await
+        .context("Failed to connect to system D-Bus")?;
+    let manager = Login1ManagerProxy::new(&connection)
+        .await
+        .context("Failed to create logind proxy")?;
+    let mut signals = manager
+        .receive_prepare_for_sleep()
+        .await
+        .context("Failed to subscribe to PrepareForSleep")?;
+
+    tracing::info!("Listening for suspend/resume via logind");
+
+    while let Some(signal) = signals.next().await {
+        let now = clock.now();
+        let event = if signal.args()?.start {
+            SleepEvent::Suspending(now)
+        } else {
+            SleepEvent::Resumed(now)
+        };
+        tracing::debug!("logind sleep event: {event:?}");
+
+        if sender.send(event).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
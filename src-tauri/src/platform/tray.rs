@@ -1,8 +1,9 @@
 use std::sync::{Arc, Mutex};
 
+use chrono::{DateTime, Local};
 use tauri::{
-    AppHandle, Listener, Manager, Runtime,
-    menu::{Menu, MenuBuilder, MenuItemBuilder},
+    AppHandle, Manager, Runtime,
+    menu::{Menu, MenuBuilder, MenuItemBuilder, SubmenuBuilder},
     tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
 };
 use tokio::sync::mpsc;
@@ -11,10 +12,12 @@ use crate::platform::{
     get_strings,
     i18n::{LanguageStrings, TrayStrings},
 };
-use crate::scheduler::models::{Command, SchedulerStatus};
+use crate::scheduler::event::get_pomodoro_schedule;
+use crate::scheduler::event_bus::{DomainEvent, EventBus};
+use crate::scheduler::models::{Command, PauseDuration};
 use crate::{
     cmd::{SchedulerCmd, open_settings_window},
-    scheduler::PauseReason,
+    scheduler::{PauseReason, SharedState},
 };
 use crate::{config::SharedConfig, platform::i18n::LANGUAGE_FALLBACK};
 
@@ -27,7 +30,8 @@ pub struct TrayState {
 
 /// Messages for updating the tray menu
 pub enum TrayUpdate {
-    UpdateMenu(bool), // bool: paused state
+    /// Paused state, when a timed pause ends, and whether a Pomodoro session runs
+    UpdateMenu(bool, Option<DateTime<Local>>, bool),
 }
 
 /// Setup system tray icon with menu (should be called after config is loaded)
@@ -37,7 +41,8 @@ pub async fn setup_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
     let strings = get_localized_strings(app).await;
     let tray_text = &strings.tray;
 
-    let initial_menu = build_tray_menu(app, tray_text, false)?;
+    let has_pomodoro = has_pomodoro_schedule(app).await;
+    let initial_menu = build_tray_menu(app, tray_text, false, None, false, has_pomodoro)?;
 
     let icon = app
         .default_window_icon()
@@ -96,28 +101,86 @@ async fn get_localized_strings<R: Runtime>(app: &AppHandle<R>) -> LanguageString
     get_strings(&lang)
 }
 
+/// Timed pause options offered in the tray pause submenu
+const PAUSE_OPTIONS: [(&str, PauseDuration); 4] = [
+    ("pause_30_minutes", PauseDuration::Seconds(30 * 60)),
+    ("pause_1_hour", PauseDuration::Seconds(60 * 60)),
+    ("pause_until_schedule_end", PauseDuration::UntilScheduleEnd),
+    ("pause_until_tomorrow", PauseDuration::UntilTomorrow),
+];
+
 /// Build tray menu with localized text and current pause state
+///
+/// While running, pausing is offered as a submenu of durations. While paused,
+/// a resume item is shown along with when a timed pause ends. Pomodoro sessions
+/// can be started or stopped if a Pomodoro schedule is enabled.
 fn build_tray_menu<R: Runtime>(
     app: &AppHandle<R>,
     tray_text: &TrayStrings,
     paused: bool,
+    paused_until: Option<DateTime<Local>>,
+    in_pomodoro: bool,
+    has_pomodoro: bool,
 ) -> tauri::Result<Menu<R>> {
-    let pause_resume_text = if paused {
-        &tray_text.resume
-    } else {
-        &tray_text.pause
-    };
-
     let show_item = MenuItemBuilder::with_id("show", &tray_text.show).build(app)?;
-    let pause_item = MenuItemBuilder::with_id("pause_or_resume", pause_resume_text).build(app)?;
     let restart_item = MenuItemBuilder::with_id("restart", &tray_text.restart).build(app)?;
     let quit_item = MenuItemBuilder::with_id("quit", &tray_text.quit).build(app)?;
 
-    MenuBuilder::new(app)
-        .items(&[&show_item, &pause_item])
-        .separator()
-        .items(&[&restart_item, &quit_item])
-        .build()
+    let mut menu = MenuBuilder::new(app).item(&show_item);
+
+    if paused {
+        let resume_item =
+            MenuItemBuilder::with_id("pause_or_resume", &tray_text.resume).build(app)?;
+        menu = menu.item(&resume_item);
+
+        if let Some(until) = paused_until {
+            let time = until.format("%H:%M").to_string();
+            let until_item = MenuItemBuilder::with_id(
+                "paused_until",
+                tray_text.paused_until.replace("{time}", &time),
+            )
+            .enabled(false)
+            .build(app)?;
+            menu = menu.item(&until_item);
+        }
+    } else {
+        let pause_labels = [
+            &tray_text.pause_30_minutes,
+            &tray_text.pause_1_hour,
+            &tray_text.pause_until_schedule_end,
+            &tray_text.pause_until_tomorrow,
+        ];
+        let mut pause_menu = SubmenuBuilder::new(app, &tray_text.pause).item(
+            &MenuItemBuilder::with_id("pause_or_resume", &tray_text.pause_indefinitely)
+                .build(app)?,
+        );
+        for ((id, _), label) in PAUSE_OPTIONS.iter().zip(pause_labels) {
+            pause_menu = pause_menu.item(&MenuItemBuilder::with_id(*id, label).build(app)?);
+        }
+        menu = menu.item(&pause_menu.build()?);
+    }
+
+    if in_pomodoro || has_pomodoro {
+        let label = if in_pomodoro {
+            &tray_text.stop_pomodoro
+        } else {
+            &tray_text.start_pomodoro
+        };
+        let pomodoro_item = MenuItemBuilder::with_id("pomodoro", label)
+            .enabled(in_pomodoro || !paused)
+            .build(app)?;
+        menu = menu.item(&pomodoro_item);
+    }
+
+    menu.separator().items(&[&restart_item, &quit_item]).build()
+}
+
+/// Check if a Pomodoro schedule is enabled
+async fn has_pomodoro_schedule<R: Runtime>(app: &AppHandle<R>) -> bool {
+    let Some(config) = app.try_state::<SharedConfig>() else {
+        return false;
+    };
+    get_pomodoro_schedule(&*config.read().await).is_some()
 }
 
 /// Spawn a task to handle tray menu updates
@@ -131,8 +194,16 @@ fn spawn_tray_update_task<R: Runtime>(
         let tray_clone = tray.clone();
         while let Some(update) = tray_rx.recv().await {
             match update {
-                TrayUpdate::UpdateMenu(paused) => {
-                    if let Ok(menu) = build_tray_menu(&app_handle, &strings.tray, paused) {
+                TrayUpdate::UpdateMenu(paused, paused_until, in_pomodoro) => {
+                    let has_pomodoro = has_pomodoro_schedule(&app_handle).await;
+                    if let Ok(menu) = build_tray_menu(
+                        &app_handle,
+                        &strings.tray,
+                        paused,
+                        paused_until,
+                        in_pomodoro,
+                        has_pomodoro,
+                    ) {
                         let _ = tray_clone.set_menu(Some(menu));
                     } else {
                         tracing::error!("Failed to build tray menu for update.");
@@ -145,31 +216,51 @@ fn spawn_tray_update_task<R: Runtime>(
 
 /// Listen for scheduler status events to update tray menu
 fn listen_for_scheduler_status<R: Runtime>(app: &AppHandle<R>, tray_state: TrayState) {
-    app.listen("scheduler-status", move |event| {
-        if let Ok(status) = serde_json::from_str::<SchedulerStatus>(event.payload()) {
+    let mut subscription = app.state::<EventBus>().subscribe();
+    tokio::spawn(async move {
+        while let Some(event) = subscription.recv().await {
+            let DomainEvent::Status(status) = event else {
+                continue;
+            };
+
             // Update stored state
             if let Ok(mut paused) = tray_state.scheduler_paused.lock() {
                 *paused = status.paused;
             }
 
+            let paused_until = status
+                .pause_expiry
+                .as_ref()
+                .and_then(|expiry| DateTime::parse_from_rfc3339(&expiry.time).ok())
+                .map(|until| until.with_timezone(&Local));
+
             // Send update message to tray update task
             if let Ok(sender_option) = tray_state.tray_sender.lock()
                 && let Some(sender) = sender_option.as_ref()
             {
                 sender
-                    .send(TrayUpdate::UpdateMenu(status.paused))
+                    .send(TrayUpdate::UpdateMenu(
+                        status.paused,
+                        paused_until,
+                        status.pomodoro.is_some(),
+                    ))
                     .unwrap_or_else(|e| {
                         tracing::warn!("Failed to send tray update: {e}");
                     });
             }
-        } else {
-            tracing::warn!("Failed to parse 'scheduler-status' event payload.");
         }
     });
 }
 
 /// Handle tray menu item clicks
 fn handle_tray_menu_event<R: Runtime>(app: &AppHandle<R>, event_id: &str) {
+    if let Some((_, duration)) = PAUSE_OPTIONS.iter().find(|(id, _)| *id == event_id) {
+        pause_for(app, *duration).unwrap_or_else(|e| {
+            tracing::error!("Failed to pause for {duration}: {e}");
+        });
+        return;
+    }
+
     match event_id {
         "show" => {
             show_settings_window(app);
@@ -179,6 +270,11 @@ fn handle_tray_menu_event<R: Runtime>(app: &AppHandle<R>, event_id: &str) {
                 tracing::error!("Failed to toggle pause: {e}");
             });
         }
+        "pomodoro" => {
+            toggle_pomodoro(app).unwrap_or_else(|e| {
+                tracing::error!("Failed to toggle Pomodoro session: {e}");
+            });
+        }
         "restart" => {
             tracing::info!("Restart requested from tray menu");
             app.restart();
@@ -203,6 +299,15 @@ fn show_settings_window<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
+/// Pause the scheduler for a limited time
+fn pause_for<R: Runtime>(app: &AppHandle<R>, duration: PauseDuration) -> Result<(), String> {
+    app.state::<SchedulerCmd>()
+        .try_send(Command::PauseFor(duration, None))
+        .map_err(|e| format!("Failed to send pause command: {e}"))?;
+    tracing::info!("Pause for {duration} sent from tray menu");
+    Ok(())
+}
+
 /// Toggle scheduler pause state
 fn toggle_pause<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     let scheduler_cmd = app.state::<SchedulerCmd>();
@@ -219,16 +324,37 @@ fn toggle_pause<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     if is_paused {
         // Currently paused, send resume command
         scheduler_cmd
-            .try_send(Command::Resume(PauseReason::Manual))
+            .try_send(Command::Resume(PauseReason::Manual, None))
             .map_err(|e| format!("Failed to send resume command: {e}"))?;
         tracing::info!("Resume sent from tray menu");
     } else {
         // Currently running, send pause command
         scheduler_cmd
-            .try_send(Command::Pause(PauseReason::Manual))
+            .try_send(Command::Pause(PauseReason::Manual, None))
             .map_err(|e| format!("Failed to send pause command: {e}"))?;
         tracing::info!("Pause sent from tray menu");
     }
 
     Ok(())
 }
+
+/// Start a Pomodoro session, or stop the running one
+fn toggle_pomodoro<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let in_pomodoro = app
+        .try_state::<SharedState>()
+        .is_some_and(|state| state.read().pomodoro().is_some());
+    let command = if in_pomodoro {
+        Command::StopPomodoro(None)
+    } else {
+        Command::StartPomodoro(None, None)
+    };
+
+    app.state::<SchedulerCmd>()
+        .try_send(command)
+        .map_err(|e| format!("Failed to send Pomodoro command: {e}"))?;
+    tracing::info!(
+        "Pomodoro session {} from tray menu",
+        if in_pomodoro { "stop" } else { "start" }
+    );
+    Ok(())
+}
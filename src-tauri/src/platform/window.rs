@@ -8,6 +8,7 @@ use tokio::sync::Mutex;
 
 use crate::core::{
     payload::store_payload_internal,
+    schedule::SkipEscalation,
     suggestions::{SharedSuggestions, sample_suggestion},
     theme::BackgroundType,
 };
@@ -15,17 +16,22 @@ use crate::core::{
     payload::{EventKind, PromptPayload},
     theme::{BackgroundSource, ResolvedBackground},
 };
-use crate::scheduler::SchedulerEvent;
+use crate::scheduler::{Escalation, SchedulerEvent};
 use crate::{config::AppConfig, core::suggestions::SuggestionsConfig};
 use crate::{config::SharedConfig, core::payload::PromptPayloadStore};
 
 const ALLOWED_EXTENSIONS_LOWERCASE: &[&str] = &["jpg", "jpeg", "png", "webp", "bmp", "gif"];
 
 /// Create prompt windows for monitors based on configuration
+///
+/// `task` is the label of the running Pomodoro session, shown in break prompts.
+/// `escalation` explains a break made stricter after skips in a row.
 pub async fn create_prompt_windows<R: Runtime>(
     app: &AppHandle<R>,
     event: SchedulerEvent,
     postpone_count: u8,
+    escalation: Option<Escalation>,
+    task: Option<String>,
 ) -> Result<(), String> {
     tracing::debug!("Creating prompt windows for event: {event}");
 
@@ -37,8 +43,14 @@ pub async fn create_prompt_windows<R: Runtime>(
         let suggestions_guard = suggestions.read().await;
 
         // Build prompt payload
-        let payload =
-            build_prompt_payload(&config_guard, &suggestions_guard, event, postpone_count)?;
+        let payload = build_prompt_payload(
+            &config_guard,
+            &suggestions_guard,
+            event,
+            postpone_count,
+            escalation,
+            task,
+        )?;
 
         // Generate unique payload ID
         let payload_id = format!(
@@ -242,6 +254,8 @@ fn build_prompt_payload(
     suggestions: &SuggestionsConfig,
     event: SchedulerEvent,
     postpone_count: u8,
+    escalation: Option<Escalation>,
+    task: Option<String>,
 ) -> Result<PromptPayload, String> {
     let (break_settings, schedule_name, kind) = match event {
         SchedulerEvent::MiniBreak(id) => {
@@ -283,6 +297,7 @@ fn build_prompt_payload(
                 message_key: "break.attentionMessage".to_string(),
                 message: Some(attention.message.clone()),
                 schedule_name: None,
+                task: None,
                 duration: attention.duration_s as i32,
                 strict_mode: false,
                 theme: attention.theme.clone(),
@@ -298,6 +313,8 @@ fn build_prompt_payload(
                 language: config.language.clone(),
                 postpone_count: 0, // Attention reminders cannot be postponed
                 max_postpone_count: 0,
+                skip_count: 0,
+                escalation: None,
             });
         }
     };
@@ -320,8 +337,10 @@ fn build_prompt_payload(
         },
         message: None,
         schedule_name: Some(schedule_name),
+        task,
         duration: break_settings.duration_s as i32,
-        strict_mode: break_settings.strict_mode,
+        strict_mode: break_settings.strict_mode
+            || escalation.is_some_and(|e| e.policy == SkipEscalation::Strict),
         theme: break_settings.theme.clone(),
         background,
         suggestion,
@@ -335,6 +354,8 @@ fn build_prompt_payload(
         language: config.language.clone(),
         postpone_count,
         max_postpone_count: break_settings.max_postpone_count,
+        skip_count: escalation.map_or(0, |e| e.skip_count),
+        escalation: escalation.map(|e| e.policy),
     })
 }
 
@@ -1,11 +1,20 @@
+pub mod activity;
+pub mod clock;
 pub mod event;
+pub mod event_bus;
+pub mod host;
 pub mod manager;
 pub mod models;
+pub mod progress;
 pub mod shared_state;
+#[cfg(any(test, feature = "simulator"))]
+pub mod simulator;
+pub mod timeline;
 
 mod attention_timer;
 mod break_scheduler;
 mod event_emitter;
+mod supervisor;
 
 #[cfg(test)]
 mod attention_timer_tests;
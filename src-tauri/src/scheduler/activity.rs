@@ -0,0 +1,155 @@
+//! Accumulated active time of the user
+//!
+//! Schedules that count active time (`count_active_time`) measure their intervals
+//! in time the user actually spent at the keyboard instead of wall-clock time.
+//! The tracker is fed with idle time samples by the `ActivityMonitor` and reset
+//! by the break scheduler whenever a new interval starts.
+//!
+//! # Accounting
+//!
+//! A sample taken at `now` with `idle` seconds since the last input means the
+//! user was active from the previous sample until `now - idle`. Anything after
+//! the last input is an idle gap and is not counted, however short it is. Long
+//! gaps pause the scheduler through the `IdleMonitor` as usual.
+//!
+//! The idle time of the latest sample is kept as well, so a due break can wait
+//! for a pause in typing before it opens (see `AppConfig::break_grace_s`).
+
+use chrono::{DateTime, Duration, Utc};
+
+/// How often the user's idle time is sampled (seconds)
+pub const ACTIVITY_SAMPLE_INTERVAL_S: u64 = 2;
+
+/// Active time accumulated since the last reset
+#[derive(Debug, Clone, Default)]
+pub struct ActivityTracker {
+    /// Active time since the last reset
+    active: Duration,
+    /// End of the period accounted for so far
+    covered_until: Option<DateTime<Utc>>,
+    /// Idle time of the latest sample
+    last_idle: Duration,
+    /// Whether idle time samples are being received
+    sampling: bool,
+}
+
+impl ActivityTracker {
+    /// Account for an idle time sample taken at `now`
+    ///
+    /// The first sample only starts sampling, as nothing is known about the
+    /// time before it.
+    pub fn record(&mut self, now: DateTime<Utc>, idle: Duration) {
+        if let Some(from) = self.covered_until {
+            let last_input = (now - idle).min(now);
+            if last_input > from {
+                self.active += last_input - from;
+            }
+        }
+        self.covered_until = Some(now);
+        self.last_idle = idle.max(Duration::zero());
+        self.sampling = true;
+    }
+
+    /// Start counting from zero at `now`
+    pub fn reset(&mut self, now: DateTime<Utc>) {
+        self.active = Duration::zero();
+        self.covered_until = Some(now);
+    }
+
+    /// Continue counting from `active_s` at `now`, e.g. after a pause
+    pub fn resume(&mut self, now: DateTime<Utc>, active_s: u32) {
+        self.active = Duration::seconds(i64::from(active_s));
+        self.covered_until = Some(now);
+    }
+
+    /// Stop using the tracker, e.g. because idle detection is unavailable
+    pub fn stop_sampling(&mut self) {
+        self.sampling = false;
+    }
+
+    /// Active seconds since the last reset, `None` if not sampling
+    #[must_use]
+    pub fn active_s(&self) -> Option<u32> {
+        self.sampling
+            .then(|| u32::try_from(self.active.num_seconds()).unwrap_or(u32::MAX))
+    }
+
+    /// Seconds without input at the latest sample, `None` if not sampling
+    #[must_use]
+    pub fn idle_s(&self) -> Option<u64> {
+        self.sampling
+            .then(|| self.last_idle.num_seconds().unsigned_abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, 1, 10, 0, 0).unwrap() + Duration::seconds(seconds)
+    }
+
+    #[test]
+    fn counts_time_until_last_input() {
+        let mut tracker = ActivityTracker::default();
+        assert_eq!(tracker.active_s(), None);
+
+        tracker.record(at(0), Duration::zero());
+        assert_eq!(tracker.active_s(), Some(0));
+
+        tracker.record(at(2), Duration::zero());
+        tracker.record(at(4), Duration::seconds(1));
+        assert_eq!(tracker.active_s(), Some(3));
+    }
+
+    #[test]
+    fn short_idle_gaps_are_not_counted() {
+        let mut tracker = ActivityTracker::default();
+        tracker.record(at(0), Duration::zero());
+        tracker.record(at(10), Duration::zero());
+
+        // Reading for 20s without input
+        for t in (12..=30).step_by(2) {
+            tracker.record(at(t), Duration::seconds(t - 10));
+        }
+        assert_eq!(tracker.active_s(), Some(10));
+
+        tracker.record(at(32), Duration::zero());
+        assert_eq!(tracker.active_s(), Some(12));
+    }
+
+    #[test]
+    fn keeps_idle_time_of_latest_sample() {
+        let mut tracker = ActivityTracker::default();
+        assert_eq!(tracker.idle_s(), None);
+
+        tracker.record(at(0), Duration::seconds(5));
+        tracker.record(at(2), Duration::seconds(1));
+        assert_eq!(tracker.idle_s(), Some(1));
+
+        tracker.stop_sampling();
+        assert_eq!(tracker.idle_s(), None);
+    }
+
+    #[test]
+    fn reset_discards_time_before_it() {
+        let mut tracker = ActivityTracker::default();
+        tracker.record(at(0), Duration::zero());
+        tracker.record(at(60), Duration::zero());
+
+        // A break from 60 to 80 without samples
+        tracker.reset(at(80));
+        tracker.record(at(82), Duration::zero());
+        assert_eq!(tracker.active_s(), Some(2));
+    }
+
+    #[test]
+    fn stopped_tracker_reports_nothing() {
+        let mut tracker = ActivityTracker::default();
+        tracker.record(at(0), Duration::zero());
+        tracker.stop_sampling();
+        assert_eq!(tracker.active_s(), None);
+    }
+}
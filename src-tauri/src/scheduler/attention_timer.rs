@@ -1,52 +1,80 @@
 use chrono::offset::LocalResult;
-use chrono::{DateTime, Duration, Local, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use chrono::{Datelike, NaiveDate, NaiveTime};
-use tauri::{AppHandle, Manager, Runtime};
+use tauri::AppHandle;
 use tokio::sync::{mpsc, watch};
 use tokio::time::sleep;
 
+use super::clock::{Clock, SystemClock};
+use super::event_bus::DomainEvent;
 use super::event_emitter::EventEmitter;
-use super::models::{Command, SchedulerEvent};
+use super::host::SchedulerHost;
+use super::models::{Command, CommandReply, Rejection, SchedulerEvent};
 use super::shared_state::SharedState;
-use crate::core::schedule::AttentionSettings;
-use crate::platform::create_prompt_windows;
-use crate::{config::SharedConfig, core::schedule::AttentionId};
+use crate::core::history::{PromptOutcome, record_prompt_transition};
+use crate::core::schedule::{AttentionId, AttentionSettings};
+
+/// How late an attention may fire before it is considered stale and skipped
+///
+/// Timers run on the monotonic clock, so after a suspend they can fire long
+/// after the wall-clock time the attention was scheduled for.
+const STALE_ATTENTION_TOLERANCE_S: i64 = 60;
 
 /// A simple timer for attention reminders
 /// Attention timer can be paused/resumed like breaks
 #[allow(dead_code)]
-pub struct AttentionTimer<E, R = tauri::Wry>
+pub struct AttentionTimer<E, H = AppHandle, C = SystemClock>
 where
     E: EventEmitter,
-    R: Runtime,
+    H: SchedulerHost,
+    C: Clock,
 {
-    app_handle: AppHandle<R>,
+    host: H,
     event_emitter: E,
     shutdown_rx: watch::Receiver<()>,
     paused: bool,
+    /// When the current attention prompt was shown (for history)
+    attention_started_at: Option<DateTime<Utc>>,
     shared_state: SharedState,
+    /// Skip prompt windows (tests and simulations)
+    headless: bool,
+    /// Source of the current time and local time zone
+    clock: C,
 }
 
-impl<E, R> AttentionTimer<E, R>
+impl<E, H, C> AttentionTimer<E, H, C>
 where
     E: EventEmitter,
-    R: Runtime,
+    H: SchedulerHost,
+    C: Clock,
 {
     pub fn new(
-        app_handle: AppHandle<R>,
+        host: H,
         event_emitter: E,
         shutdown_rx: watch::Receiver<()>,
         shared_state: SharedState,
+        clock: C,
     ) -> Self {
         Self {
-            app_handle,
+            host,
             event_emitter,
             shutdown_rx,
             paused: false,
+            attention_started_at: None,
             shared_state,
+            headless: cfg!(test),
+            clock,
         }
     }
 
+    /// Run without prompt windows
+    #[cfg(any(test, feature = "simulator"))]
+    #[must_use]
+    pub(crate) fn headless(mut self) -> Self {
+        self.headless = true;
+        self
+    }
+
     /// Check if paused (for testing)
     #[cfg(test)]
     #[allow(dead_code)]
@@ -81,13 +109,17 @@ where
 
             // Calculate next attention time
             let next_attention = {
-                let config = self.app_handle.state::<SharedConfig>();
+                let config = self.host.config();
                 let config_guard = config.read().await;
-                calculate_next_attention(&config_guard.attentions)
+                calculate_next_attention(
+                    &config_guard.attentions,
+                    self.clock.now(),
+                    &self.clock.timezone(),
+                )
             };
 
             if let Some((attention_id, attention_time)) = next_attention {
-                let now = Utc::now();
+                let now = self.clock.now();
                 let duration_to_wait = attention_time - now;
 
                 if duration_to_wait <= Duration::zero() {
@@ -95,7 +127,7 @@ where
                     tracing::warn!(
                         "Attention time already passed, triggering immediately and recalculating"
                     );
-                    self.trigger_attention(attention_id);
+                    self.trigger_attention(attention_id).await;
                     continue;
                 }
 
@@ -111,8 +143,15 @@ where
                         break;
                     }
                     () = sleep(duration_to_wait.to_std().unwrap_or(std::time::Duration::ZERO)) => {
-                        if !self.paused {
-                            self.trigger_attention(attention_id);
+                        if self.paused {
+                            continue;
+                        }
+                        if is_stale_attention(attention_time, self.clock.now()) {
+                            tracing::warn!(
+                                "Skipping stale attention {attention_id} scheduled at {attention_time}"
+                            );
+                        } else {
+                            self.trigger_attention(attention_id).await;
                         }
                     }
                     Some(cmd) = cmd_rx.recv() => {
@@ -140,17 +179,30 @@ where
     }
 
     /// Trigger an attention reminder
-    fn trigger_attention(&self, attention_id: AttentionId) {
+    async fn trigger_attention(&mut self, attention_id: AttentionId) {
         tracing::info!("Triggering attention: {attention_id}");
 
         // Mark attention session as started
         self.shared_state.write().start_attention_session();
 
         let event = SchedulerEvent::Attention(attention_id);
+        let now = self.clock.now();
+        self.attention_started_at = Some(now);
+        let entry =
+            record_prompt_transition(&self.host, event, PromptOutcome::Started, now, None, 0).await;
+        self.event_emitter
+            .emit(DomainEvent::PromptTransition(entry));
+
+        self.event_emitter.emit(DomainEvent::PromptStarted(event));
+
+        if self.headless {
+            tracing::debug!("Headless: skipping window creation for event: {event}");
+            return;
+        }
 
-        let app_handle = self.app_handle.clone();
+        let host = self.host.clone();
         tokio::spawn(async move {
-            create_prompt_windows(&app_handle, event, 0)
+            host.show_prompt(event, 0, None, None)
                 .await
                 .unwrap_or_else(|e| {
                     tracing::error!("Failed to create attention windows: {e}");
@@ -163,11 +215,11 @@ where
         tracing::debug!("AttentionTimer handling command: {cmd}");
 
         match cmd {
-            Command::Pause(reason) => {
+            Command::Pause(reason, _) => {
                 tracing::info!("Pausing AttentionTimer: {reason}");
                 self.paused = true;
             }
-            Command::Resume(reason) => {
+            Command::Resume(reason, _) => {
                 tracing::info!("Resuming AttentionTimer: {reason}");
                 self.paused = false;
                 // Will recalculate next attention on next loop iteration
@@ -175,19 +227,43 @@ where
             Command::UpdateConfig(new_config) => {
                 tracing::debug!("Updating config in AttentionTimer");
                 {
-                    let config = self.app_handle.state::<SharedConfig>();
+                    let config = self.host.config();
                     let mut config_guard = config.write().await;
                     *config_guard = new_config;
                 }
                 // Config updated, will recalculate next attention in next loop iteration
             }
-            Command::TriggerEvent(SchedulerEvent::Attention(attention_id)) => {
-                tracing::info!("Manually triggering attention: {attention_id}");
-                self.trigger_attention(attention_id);
+            Command::TriggerEvent(SchedulerEvent::Attention(attention_id), responder) => {
+                let reply = if self.paused {
+                    tracing::warn!("Cannot trigger attention {attention_id} while paused");
+                    CommandReply::Rejected(Rejection::Paused)
+                } else {
+                    tracing::info!("Manually triggering attention: {attention_id}");
+                    self.trigger_attention(attention_id).await;
+                    CommandReply::Accepted(None)
+                };
+                if let Some(responder) = responder {
+                    responder.send(reply);
+                }
             }
-            Command::PromptFinished(SchedulerEvent::Attention(_)) => {
+            Command::PromptFinished(event @ SchedulerEvent::Attention(_)) => {
                 tracing::debug!("Attention prompt finished, ending session");
                 self.shared_state.write().end_attention_session();
+                let entry = record_prompt_transition(
+                    &self.host,
+                    event,
+                    PromptOutcome::Completed,
+                    self.clock.now(),
+                    self.attention_started_at.take(),
+                    0,
+                )
+                .await;
+                self.event_emitter
+                    .emit(DomainEvent::PromptTransition(entry));
+            }
+            Command::SystemResumed(slept_s) => {
+                tracing::info!("System resumed after {slept_s}s, recalculating next attention");
+                // Will recalculate from the current time in next loop iteration
             }
             // AttentionTimer ignores other commands (they're for BreakScheduler)
             _ => {}
@@ -195,25 +271,33 @@ where
     }
 }
 
+/// Whether an attention firing at `now` is too late to be shown
+fn is_stale_attention(attention_time: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    now - attention_time > Duration::seconds(STALE_ATTENTION_TOLERANCE_S)
+}
+
 /// Calculate the next attention time across all enabled attentions
-pub(crate) fn calculate_next_attention(
+///
+/// Attention times are local times in `timezone`.
+pub(crate) fn calculate_next_attention<Tz: TimeZone>(
     attentions: &[AttentionSettings],
+    now: DateTime<Utc>,
+    timezone: &Tz,
 ) -> Option<(AttentionId, DateTime<Utc>)> {
-    let now = Utc::now();
-    let now_local = now.with_timezone(&Local);
+    let now_local = now.with_timezone(timezone);
 
     attentions
         .iter()
         .filter_map(|attention| {
-            get_next_attention_time(attention, now_local).map(|time| (attention.id, time))
+            get_next_attention_time(attention, &now_local).map(|time| (attention.id, time))
         })
         .min_by_key(|(_, time)| *time)
 }
 
 /// Get the next occurrence time for a specific attention
-pub(crate) fn get_next_attention_time(
+pub(crate) fn get_next_attention_time<Tz: TimeZone>(
     attention: &AttentionSettings,
-    now: DateTime<Local>,
+    now: &DateTime<Tz>,
 ) -> Option<DateTime<Utc>> {
     if !attention.enabled || attention.times.is_empty() || attention.days_of_week.is_empty() {
         tracing::debug!(
@@ -225,18 +309,19 @@ pub(crate) fn get_next_attention_time(
 
     let now_date = now.date_naive();
     let now_time = now.time();
+    let timezone = now.timezone();
 
-    let to_utc = |dt_local: DateTime<Local>| -> Option<DateTime<Utc>> {
+    let to_utc = |dt_local: DateTime<Tz>| -> Option<DateTime<Utc>> {
         tracing::debug!(
             "Found potential attention '{}' time: {} (local)",
             attention.name,
-            dt_local.to_rfc2822()
+            dt_local.naive_local()
         );
         Some(dt_local.with_timezone(&Utc))
     };
 
-    let build_datetime = |date: NaiveDate, time: NaiveTime| -> Option<DateTime<Local>> {
-        match date.and_time(time).and_local_timezone(Local) {
+    let build_datetime = |date: NaiveDate, time: NaiveTime| -> Option<DateTime<Tz>> {
+        match timezone.from_local_datetime(&date.and_time(time)) {
             LocalResult::Single(dt) => Some(dt),
             LocalResult::Ambiguous(dt1, _) => {
                 tracing::warn!(
@@ -282,7 +367,8 @@ mod tests {
     use crate::core::schedule::AttentionSettings;
     use crate::core::time::ShortTimes;
     use crate::scheduler::test_helpers::*;
-    use chrono::{Timelike, Weekday};
+    use chrono::{Local, Timelike, Weekday};
+    use chrono_tz::Europe::Berlin;
 
     mod get_next_attention_time_tests {
         use super::*;
@@ -298,7 +384,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             assert_eq!(next_time.with_timezone(&Local).hour(), 10);
@@ -316,7 +402,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 15, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             assert_eq!(next_time.with_timezone(&Local).hour(), 10);
@@ -334,7 +420,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
             assert!(result.is_none());
         }
 
@@ -349,7 +435,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
             assert!(result.is_none());
         }
 
@@ -364,7 +450,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 2, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             assert_eq!(next_time.with_timezone(&Local).weekday(), Weekday::Wed);
@@ -385,7 +471,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 10, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             assert_eq!(next_time.with_timezone(&Local).hour(), 12);
@@ -415,14 +501,14 @@ mod tests {
 
             let attentions = vec![attention1, attention2];
 
-            let result = calculate_next_attention(&attentions);
+            let result = calculate_next_attention(&attentions, Utc::now(), &Local);
             assert!(result.is_none());
         }
 
         #[test]
         fn returns_none_when_empty_list() {
             let attentions = vec![];
-            let result = calculate_next_attention(&attentions);
+            let result = calculate_next_attention(&attentions, Utc::now(), &Local);
             assert!(result.is_none());
         }
 
@@ -455,7 +541,7 @@ mod tests {
                 no_days_attention,
             ];
 
-            let result = calculate_next_attention(&attentions);
+            let result = calculate_next_attention(&attentions, Utc::now(), &Local);
 
             if let Some((id, _)) = result {
                 assert_eq!(id, valid_attention.id);
@@ -492,7 +578,7 @@ mod tests {
                 attention_morning.clone(),
             ];
 
-            let result = calculate_next_attention(&attentions);
+            let result = calculate_next_attention(&attentions, Utc::now(), &Local);
 
             assert!(result.is_some(), "Should return an attention");
             let (_id, time) = result.unwrap();
@@ -523,7 +609,7 @@ mod tests {
 
             let attentions = vec![attention_disabled, attention_enabled.clone()];
 
-            let result = calculate_next_attention(&attentions);
+            let result = calculate_next_attention(&attentions, Utc::now(), &Local);
 
             let (_id, time) = result.unwrap();
             // Should return the enabled attention's time (14:00)
@@ -531,6 +617,30 @@ mod tests {
         }
     }
 
+    mod is_stale_attention_tests {
+        use super::*;
+
+        #[test]
+        fn fires_on_time_or_slightly_late() {
+            let attention_time = test_datetime_with_local(2025, 9, 3, 10, 0, 0);
+            assert!(!is_stale_attention(attention_time, attention_time));
+            assert!(!is_stale_attention(
+                attention_time,
+                attention_time + Duration::seconds(STALE_ATTENTION_TOLERANCE_S)
+            ));
+        }
+
+        #[test]
+        fn skips_when_fired_long_after_scheduled_time() {
+            let attention_time = test_datetime_with_local(2025, 9, 3, 10, 0, 0);
+            // Timer fired after the system slept through the attention
+            assert!(is_stale_attention(
+                attention_time,
+                attention_time + Duration::hours(2)
+            ));
+        }
+    }
+
     // Additional tests for get_next_attention_time edge cases
     mod get_next_attention_time_edge_cases {
         use super::*;
@@ -548,7 +658,7 @@ mod tests {
             // Current time is exactly 10:00
             let now = test_local_datetime(2025, 9, 3, 10, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should return 14:00 (next time after current)
@@ -568,7 +678,7 @@ mod tests {
             // Current time is 20:00 (all times passed)
             let now = test_local_datetime(2025, 9, 3, 20, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should return next day at 9:00
@@ -589,7 +699,7 @@ mod tests {
             // Wednesday 2025-09-03
             let now = test_local_datetime(2025, 9, 3, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should skip to Saturday (2025-09-06)
@@ -609,7 +719,7 @@ mod tests {
             // Monday morning
             let now = test_local_datetime(2025, 9, 1, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should skip to Friday (2025-09-05)
@@ -634,7 +744,7 @@ mod tests {
             // Test at 23:30 (after last time)
             let now = test_local_datetime(2025, 9, 3, 23, 30, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should return next day at 00:30
@@ -655,7 +765,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 9, 0, 0);
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
             assert!(result.is_none());
         }
 
@@ -671,7 +781,7 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 3, 9, 0, 0); // Wednesday
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should wrap to next Monday (2025-09-08)
@@ -691,12 +801,32 @@ mod tests {
 
             let now = test_local_datetime(2025, 9, 1, 10, 0, 0); // Monday
 
-            let result = get_next_attention_time(&attention, now);
+            let result = get_next_attention_time(&attention, &now);
 
             let next_time = result.unwrap();
             // Should wrap to next Monday (2025-09-08)
             assert_eq!(next_time.with_timezone(&Local).weekday(), Weekday::Mon);
             assert_eq!(next_time.with_timezone(&Local).day(), 8);
         }
+
+        /// Test: Days and times are evaluated in the time zone of `now`
+        #[test]
+        fn uses_timezone_of_now() {
+            let attention = AttentionSettings {
+                enabled: true,
+                times: ShortTimes::new(vec![naive_time(10, 0, 0)]),
+                days_of_week: vec![Weekday::Sun],
+                ..Default::default()
+            };
+
+            // Saturday 23:30 UTC is Sunday 01:30 in Berlin, before the clocks go
+            // back to CET (UTC+1) at 03:00
+            let now = test_datetime(2025, 10, 25, 23, 30, 0);
+
+            let result = calculate_next_attention(&[attention], now, &Berlin);
+
+            let (_, next_time) = result.unwrap();
+            assert_eq!(next_time, test_datetime(2025, 10, 26, 9, 0, 0));
+        }
     }
 }
@@ -139,7 +139,7 @@ async fn test_pause_stops_attention_timer() {
 
     // Pause scheduler
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -173,14 +173,14 @@ async fn test_resume_restarts_attention_timer() {
 
     // Pause
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Resume
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -211,15 +211,18 @@ async fn test_attention_multiple_pause_reasons() {
 
     // Add multiple pause reasons
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Remove one
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -229,7 +232,7 @@ async fn test_attention_multiple_pause_reasons() {
 
     // Remove last
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -625,7 +628,7 @@ async fn test_pause_affects_both_schedulers() {
 
     // Pause
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -634,7 +637,7 @@ async fn test_pause_affects_both_schedulers() {
 
     // Resume
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -1,23 +1,33 @@
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::future::Future;
 use std::pin::Pin;
 
-use chrono::{DateTime, Datelike, Duration, Local, Utc};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
 use futures::future::pending;
-use tauri::{AppHandle, Manager, Runtime};
+use tauri::AppHandle;
 use tokio::sync::{mpsc, watch};
-use tokio::time::sleep;
+use tokio::time::{MissedTickBehavior, interval, sleep};
 
+use super::activity::ACTIVITY_SAMPLE_INTERVAL_S;
+use super::clock::{Clock, SystemClock};
+use super::event_bus::DomainEvent;
 use super::event_emitter::EventEmitter;
+use super::host::SchedulerHost;
 use super::models::{
-    BreakInfo, Command, PauseReason, SchedulerEvent, SchedulerEventInfo, SchedulerStatus,
+    ActiveTimeProgress, BreakActivity, BreakCredit, BreakInfo, Command, CommandReply, Escalation,
+    PauseExpiry, PauseReason, PomodoroSession, Rejection, Responder, SchedulerEvent,
+    SchedulerEventInfo, SchedulerPhase, SchedulerStatus,
 };
+use super::progress::{PROGRESS_SAVE_INTERVAL, ProgressStore, SchedulerProgress};
 use super::shared_state::SharedState;
-use crate::config::{AppConfig, SharedConfig};
-#[cfg(not(test))]
-use crate::platform::create_prompt_windows;
-use crate::platform::send_break_notification;
-use crate::scheduler::event::get_active_schedule;
+use crate::config::{AppConfig, BreakVerification, PausePolicy};
+use crate::core::history::{PromptOutcome, record_prompt_transition};
+use crate::core::schedule::{
+    AttentionSettings, BreakId, LongBreakCollision, ScheduleSettings, SkipEscalation,
+};
+use crate::scheduler::attention_timer::calculate_next_attention;
+use crate::scheduler::event::{get_active_schedule, get_pomodoro_schedule, next_schedule_boundary};
 
 /// The state of the break scheduler
 #[derive(Debug, Clone)]
@@ -34,6 +44,59 @@ enum BreakSchedulerState {
     InBreak(BreakInfo),
 }
 
+/// Where the break interval stood when the scheduler got paused
+#[derive(Debug, Clone, Copy)]
+struct PauseStart {
+    /// When the pause began, or when the user went idle for idle pauses
+    at: DateTime<Utc>,
+    /// Active seconds of the interval, if the schedule counts active time
+    active_s: Option<u32>,
+}
+
+/// A due break waiting for a pause in the user's input
+#[derive(Debug, Clone, Copy)]
+struct LullWait {
+    /// Break time of the waiting break
+    break_time: DateTime<Utc>,
+    /// When the break opens regardless of input
+    until: DateTime<Utc>,
+}
+
+/// Verification of a break in progress
+#[derive(Debug, Clone, Copy)]
+struct BreakCheck {
+    /// What happens when input is detected
+    mode: BreakVerification,
+    /// Full duration of the break
+    duration: Duration,
+    /// Whether input was detected during the break
+    interrupted: bool,
+}
+
+/// Seconds after a break starts in which input is not held against the user
+const BREAK_SETTLE_S: i64 = 5;
+
+/// Seconds a break prompt may stay open past the end of the break before the
+/// session is considered lost
+const BREAK_WATCHDOG_SLACK_S: i64 = 60;
+
+/// Seconds without input that count as a pause in typing
+const INPUT_LULL_S: u64 = 2;
+
+/// How often a waiting break checks for a pause in typing (seconds)
+const LULL_POLL_INTERVAL_S: i64 = 1;
+
+/// What the scheduler timer is armed for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimerTarget {
+    /// The deadline of the current state (notification or break time)
+    State,
+    /// The next instant at which the active schedule changes
+    ScheduleBoundary,
+    /// The next attention reminder, which changes the status
+    Attention,
+}
+
 impl Display for BreakSchedulerState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let state_str = match self {
@@ -48,46 +111,114 @@ impl Display for BreakSchedulerState {
 }
 
 /// Main break scheduler responsible for managing mini and long breaks
-pub struct BreakScheduler<E, R = tauri::Wry>
+pub struct BreakScheduler<E, H = AppHandle, C = SystemClock>
 where
     E: EventEmitter,
-    R: Runtime,
+    H: SchedulerHost,
+    C: Clock,
 {
-    app_handle: AppHandle<R>,
+    host: H,
     event_emitter: E,
     shutdown_rx: watch::Receiver<()>,
     state: BreakSchedulerState,
 
     // Break cycle state
     mini_break_counter: u8,
+    work_since_long_break_s: u32,
     last_break_time: Option<DateTime<Utc>>,
 
+    // Active seconds between breaks if the current schedule counts active time
+    active_time_target_s: Option<u32>,
+
+    // Where the interval stood when the scheduler got paused
+    pause_start: Option<PauseStart>,
+
+    // Due break waiting for a pause in typing
+    lull_wait: Option<LullWait>,
+
+    // Breaks skipped in a row, per break settings
+    skip_counts: HashMap<BreakId, u8>,
+
+    // When the current break prompt was shown (for history)
+    break_started_at: Option<DateTime<Utc>>,
+
+    // When the current break should end, including extensions
+    break_ends_at: Option<DateTime<Utc>>,
+
+    // Verification of the break in progress
+    break_check: Option<BreakCheck>,
+
+    // Next instant at which the active schedule starts, ends or changes
+    next_schedule_boundary: Option<DateTime<Utc>>,
+
+    // Name of the active schedule, for the status
+    schedule_name: Option<String>,
+
+    // Schedules the current timers were calculated from
+    schedules: Vec<ScheduleSettings>,
+
+    // Attention reminders, for the upcoming one in the status
+    attentions: Vec<AttentionSettings>,
+
     // Shared state for session management
     shared_state: SharedState,
+
+    // Skip prompt windows and notifications (tests and simulations)
+    headless: bool,
+
+    // Source of the current time and local time zone
+    clock: C,
 }
 
-impl<E, R> BreakScheduler<E, R>
+impl<E, H, C> BreakScheduler<E, H, C>
 where
     E: EventEmitter,
-    R: Runtime,
+    H: SchedulerHost,
+    C: Clock,
 {
     pub fn new(
-        app_handle: AppHandle<R>,
+        host: H,
         event_emitter: E,
         shutdown_rx: watch::Receiver<()>,
         shared_state: SharedState,
+        clock: C,
     ) -> Self {
         Self {
-            app_handle,
+            host,
             event_emitter,
             shutdown_rx,
             state: BreakSchedulerState::Idle,
             mini_break_counter: 0,
+            work_since_long_break_s: 0,
             last_break_time: None,
+            active_time_target_s: None,
+            pause_start: None,
+            lull_wait: None,
+            skip_counts: HashMap::new(),
+            break_started_at: None,
+            break_ends_at: None,
+            break_check: None,
+            next_schedule_boundary: None,
+            schedule_name: None,
+            schedules: Vec::new(),
+            attentions: Vec::new(),
             shared_state,
+            headless: cfg!(test),
+            clock,
         }
     }
 
+    /// Run without prompt windows and notifications
+    ///
+    /// Breaks still start and end through the usual commands, which the caller
+    /// sends in place of the prompt windows.
+    #[cfg(any(test, feature = "simulator"))]
+    #[must_use]
+    pub(crate) fn headless(mut self) -> Self {
+        self.headless = true;
+        self
+    }
+
     /// Get current state as string (for testing)
     #[cfg(test)]
     #[allow(dead_code)]
@@ -102,6 +233,13 @@ where
         self.mini_break_counter
     }
 
+    /// Get work time since the last long break (for testing)
+    #[cfg(test)]
+    #[allow(dead_code)]
+    pub fn get_work_since_long_break_s(&self) -> u32 {
+        self.work_since_long_break_s
+    }
+
     /// Get last break time (for testing)
     #[cfg(test)]
     #[allow(dead_code)]
@@ -109,6 +247,60 @@ where
         self.last_break_time
     }
 
+    /// Restore the break cycle saved by a previous run
+    ///
+    /// Progress older than `restore_progress_within_s` (the idle threshold by
+    /// default) is discarded, as the user has been away long enough for a fresh cycle.
+    pub async fn restore_progress(&mut self) {
+        let Some(progress) = self.host.progress_store().and_then(ProgressStore::load) else {
+            return;
+        };
+
+        let max_age_s = {
+            let config = self.host.config();
+            let config_guard = config.read().await;
+            config_guard
+                .advanced
+                .restore_progress_within_s
+                .unwrap_or(config_guard.inactive_s)
+        };
+
+        if progress.is_stale(self.clock.now(), max_age_s) {
+            tracing::info!(
+                "Discarding scheduler progress saved at {} (older than {max_age_s}s)",
+                progress.saved_at
+            );
+            return;
+        }
+
+        tracing::info!(
+            "Restoring scheduler progress: mini break counter {}, {}s worked since the last long break, last break at {:?}",
+            progress.mini_break_counter,
+            progress.work_since_long_break_s,
+            progress.last_break_time
+        );
+        self.mini_break_counter = progress.mini_break_counter;
+        self.work_since_long_break_s = progress.work_since_long_break_s;
+        self.last_break_time = progress.last_break_time;
+    }
+
+    /// Save the current break cycle so it survives a restart
+    ///
+    /// Pomodoro sessions are not resumed after a restart, so their cycle is not saved.
+    fn save_progress(&self) {
+        if self.in_pomodoro() {
+            return;
+        }
+        if let Some(store) = self.host.progress_store() {
+            store.save(SchedulerProgress {
+                mini_break_counter: self.mini_break_counter,
+                work_since_long_break_s: self.work_since_long_break_s,
+                last_break_time: self.last_break_time,
+                saved_at: self.clock.now(),
+            });
+        }
+    }
+
     /// Main run loop
     pub async fn run(&mut self, mut cmd_rx: mpsc::Receiver<Command>) {
         tracing::info!("BreakScheduler started");
@@ -116,13 +308,20 @@ where
         // Only transition to calculating if not paused
         // If paused, wait for Resume command to start scheduling
         if !matches!(self.state, BreakSchedulerState::Paused(_)) {
+            // The first interval starts now unless restored from a previous run
+            if self.last_break_time.is_none() {
+                self.update_last_break_time();
+            }
             self.transition_to_calculating().await;
         }
 
+        let mut progress_heartbeat = interval(PROGRESS_SAVE_INTERVAL);
+        progress_heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
         loop {
-            let timer_duration = self.get_duration_for_current_state();
+            let timer = self.get_duration_for_current_state();
             let mut sleep_fut: Pin<Box<dyn Future<Output = ()> + Send>> =
-                if let Some(duration) = timer_duration {
+                if let Some((duration, _)) = timer {
                     let std_duration = duration.to_std().unwrap_or(std::time::Duration::ZERO);
                     Box::pin(sleep(std_duration))
                 } else {
@@ -135,57 +334,135 @@ where
                     tracing::info!("BreakScheduler received shutdown");
                     break;
                 }
-                Some(cmd) = cmd_rx.recv() => {
+                cmd = cmd_rx.recv() => {
+                    let Some(cmd) = cmd else {
+                        tracing::info!("Command channel closed, shutting down");
+                        break;
+                    };
                     self.handle_command(cmd).await;
                 }
                 () = &mut sleep_fut => {
-                    if timer_duration.is_some() {
-                        self.on_timer_fired().await;
+                    if let Some((_, target)) = timer {
+                        self.on_timer_fired(target).await;
                     }
                 }
-                else => {
-                    tracing::info!("Command channel closed, shutting down");
-                    break;
+                _ = progress_heartbeat.tick() => {
+                    self.save_progress();
                 }
             }
         }
+        self.save_progress();
         tracing::info!("BreakScheduler shutting down");
     }
 
     /// Get the duration of next timer based on current state
-    fn get_duration_for_current_state(&self) -> Option<Duration> {
-        let now = Utc::now();
-        match &self.state {
-            BreakSchedulerState::WaitingForNotification(info) => {
-                info.notification_time.map(|notif_time| notif_time - now)
+    ///
+    /// Waiting and idle states also wake up at the next schedule boundary or
+    /// attention reminder, whichever comes first.
+    fn get_duration_for_current_state(&self) -> Option<(Duration, TimerTarget)> {
+        let now = self.clock.now();
+        let state_deadline = match &self.state {
+            BreakSchedulerState::WaitingForNotification(info) => info.notification_time,
+            BreakSchedulerState::WaitingForBreak(info) => Some(
+                self.lull_wait
+                    .filter(|wait| wait.break_time == info.break_time)
+                    .map_or(info.break_time, |wait| {
+                        wait.until
+                            .min(now + Duration::seconds(LULL_POLL_INTERVAL_S))
+                    }),
+            ),
+            BreakSchedulerState::Idle => None,
+            // The watchdog gives up on the prompt some time after the break ends,
+            // a verified break also checks for input as often as it is sampled
+            BreakSchedulerState::InBreak(_) => {
+                let mut deadline = self.break_watchdog_deadline();
+                if self.break_check.is_some() {
+                    deadline =
+                        deadline.min(now + Duration::seconds(ACTIVITY_SAMPLE_INTERVAL_S as i64));
+                }
+                return Some((deadline - now, TimerTarget::State));
             }
-            BreakSchedulerState::WaitingForBreak(info) => Some(info.break_time - now),
-            BreakSchedulerState::Paused(_)
-            | BreakSchedulerState::Idle
-            | BreakSchedulerState::InBreak(_) => None,
-        }
+            BreakSchedulerState::Paused(_) => return None,
+        };
+
+        [
+            state_deadline.map(|deadline| (deadline, TimerTarget::State)),
+            self.next_schedule_boundary
+                .map(|boundary| (boundary, TimerTarget::ScheduleBoundary)),
+            self.next_attention()
+                .map(|(_, time)| (time, TimerTarget::Attention)),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by_key(|(deadline, _)| *deadline)
+        .map(|(deadline, target)| (deadline - now, target))
     }
 
     /// Handle timer fired event based on current state
-    async fn on_timer_fired(&mut self) {
+    async fn on_timer_fired(&mut self, target: TimerTarget) {
+        match target {
+            TimerTarget::ScheduleBoundary => {
+                self.on_schedule_boundary().await;
+                return;
+            }
+            TimerTarget::Attention => {
+                // The attention timer shows it, only the upcoming one changes
+                self.emit_current_status();
+                return;
+            }
+            TimerTarget::State => {}
+        }
+
+        if self.defer_for_inactivity().await {
+            return;
+        }
+
         match self.state.clone() {
             BreakSchedulerState::WaitingForNotification(info) => {
                 tracing::debug!("Timer fired: sending notification");
                 self.send_notification(&info.event).await;
                 self.state = BreakSchedulerState::WaitingForBreak(info);
+                self.emit_current_status();
             }
             BreakSchedulerState::WaitingForBreak(info) => {
+                if self.wait_for_input_lull(&info).await {
+                    return;
+                }
                 tracing::debug!("Timer fired: executing break");
                 self.execute_break(info).await;
             }
+            BreakSchedulerState::InBreak(info) => {
+                if self.clock.now() >= self.break_watchdog_deadline() {
+                    self.recover_stuck_break(info.event).await;
+                    return;
+                }
+                self.check_break_activity(info.event);
+            }
             _ => {
                 tracing::warn!("Timer fired in unexpected state: {}", self.state);
             }
         }
     }
 
+    /// Re-evaluate the active schedule when one starts, ends or hands over to another
+    async fn on_schedule_boundary(&mut self) {
+        if matches!(self.state, BreakSchedulerState::Idle) {
+            tracing::info!("Schedule boundary reached, leaving Idle");
+            // Start a fresh interval instead of catching up on time outside schedules
+            self.update_last_break_time();
+        } else {
+            tracing::info!("Schedule boundary reached, re-evaluating active schedule");
+        }
+        self.transition_to_calculating().await;
+    }
+
     /// Send a notification before a break
     async fn send_notification(&self, event: &SchedulerEvent) {
+        if self.headless {
+            tracing::debug!("Headless: skipping notification for event: {event}");
+            return;
+        }
+
         let break_type = match event {
             SchedulerEvent::MiniBreak(_) => "MiniBreak",
             SchedulerEvent::LongBreak(_) => "LongBreak",
@@ -193,15 +470,14 @@ where
         };
 
         let notification_before_s = {
-            let config = self.app_handle.state::<SharedConfig>();
+            let config = self.host.config();
             let config_guard = config.read().await;
-            let now_local = Utc::now().with_timezone(&Local);
-            let active_schedule =
-                get_active_schedule(&config_guard, now_local.time(), now_local.weekday());
-            active_schedule.map_or(0, |s| s.notification_before_s)
+            self.current_schedule(&config_guard)
+                .map_or(0, |s| s.notification_before_s)
         };
 
-        send_break_notification(&self.app_handle, break_type, notification_before_s)
+        self.host
+            .notify_break(break_type, notification_before_s)
             .await
             .unwrap_or_else(|e| {
                 tracing::warn!("Failed to send break notification: {e}");
@@ -209,20 +485,105 @@ where
     }
 
     /// Update state after a break has been executed
+    ///
+    /// The time since the previous break counts as work towards a time-based long break,
+    /// only the active part of it if the schedule counts active time.
     fn update_state_after_break(&mut self, event: SchedulerEvent) {
+        let worked_s = self.worked_s_until(self.clock.now());
         self.update_last_break_time();
+        self.count_break(event, worked_s);
+    }
 
+    /// Seconds worked between the last break and `until`
+    fn worked_s_until(&self, until: DateTime<Utc>) -> u32 {
+        match self.active_time_s() {
+            Some(active_s) => active_s,
+            None => self.last_break_time.map_or(0, |last| {
+                u32::try_from((until - last).num_seconds().max(0)).unwrap_or(u32::MAX)
+            }),
+        }
+    }
+
+    /// Advance the long break cycle for a break that was taken
+    fn count_break(&mut self, event: SchedulerEvent, worked_s: u32) {
         match event {
             SchedulerEvent::MiniBreak(_) => {
                 self.mini_break_counter += 1;
+                self.work_since_long_break_s =
+                    self.work_since_long_break_s.saturating_add(worked_s);
             }
             SchedulerEvent::LongBreak(_) => {
-                self.mini_break_counter = 0;
+                self.reset_long_break_cycle();
             }
             SchedulerEvent::Attention(_) => {}
         }
     }
 
+    /// Count the time since `since` as a break
+    ///
+    /// A pause at least a long break long counts as a long break, one at least a
+    /// mini break long as a mini break. The credited break is recorded in the
+    /// history and announced with a `break-credited` event.
+    async fn credit_pause_as_break(&mut self, since: DateTime<Utc>) {
+        let now = self.clock.now();
+        let paused_s = u32::try_from((now - since).num_seconds().max(0)).unwrap_or(u32::MAX);
+
+        let event = {
+            let config = self.host.config();
+            let config_guard = config.read().await;
+            let Some(schedule) = self.current_schedule(&config_guard) else {
+                return;
+            };
+            let long = &schedule.long_breaks.base;
+            let mini = &schedule.mini_breaks.base;
+            if long.enabled && paused_s >= long.duration_s {
+                SchedulerEvent::LongBreak(long.id)
+            } else if mini.enabled && paused_s >= mini.duration_s {
+                SchedulerEvent::MiniBreak(mini.id)
+            } else {
+                return;
+            }
+        };
+
+        tracing::info!("Away for {paused_s}s, counting it as {event}");
+        let worked_s = self.worked_s_until(since);
+        self.count_break(event, worked_s);
+        // Time away is a real break, the breaks skipped before it are forgiven
+        self.skip_counts.clear();
+
+        let entry = record_prompt_transition(
+            &self.host,
+            event,
+            PromptOutcome::Credited,
+            self.clock.now(),
+            Some(since),
+            0,
+        )
+        .await;
+        self.event_emitter
+            .emit(DomainEvent::PromptTransition(entry));
+
+        self.event_emitter
+            .emit(DomainEvent::BreakCredited(BreakCredit { event, paused_s }));
+    }
+
+    /// Continue the interval where it stood at `start`
+    ///
+    /// The interval is moved forward by the length of the pause, so the time left
+    /// until the next break is the same as when the pause began.
+    fn continue_interval(&mut self, start: PauseStart) {
+        let now = self.clock.now();
+        let paused = now - start.at;
+        tracing::info!(
+            "Continuing the interval paused {}s ago",
+            paused.num_seconds()
+        );
+        self.last_break_time = Some(self.last_break_time.map_or(now, |last| last + paused));
+        self.shared_state
+            .write()
+            .resume_activity(now, start.active_s.unwrap_or(0));
+    }
+
     /// Execute a break: create window and play audio, then wait for completion
     /// Close all break windows asynchronously to avoid deadlock
     ///
@@ -240,23 +601,19 @@ where
     /// Solution: Spawn async task to close windows after a small delay,
     /// allowing the command to return immediately.
     ///
-    /// # Testing Behavior
+    /// # Headless Behavior
     ///
-    /// In test environment (`#[cfg(test)]`), session cleanup is synchronous because:
-    /// - No real windows exist (window creation is skipped in tests)
+    /// When headless (tests and simulations), session cleanup is synchronous because:
+    /// - No real windows exist (window creation is skipped)
     /// - No deadlock risk since there's no `WebView` thread
     /// - Tests need immediate session cleanup for assertions
     fn close_break_windows(&self) {
-        #[cfg(test)]
-        {
-            // In tests: synchronous cleanup (no windows, no deadlock risk)
+        if self.headless {
+            // Headless: synchronous cleanup (no windows, no deadlock risk)
             self.shared_state.write().end_break_session();
-        }
-
-        #[cfg(not(test))]
-        {
+        } else {
             // In production: asynchronous cleanup (avoid deadlock)
-            let app_handle = self.app_handle.clone();
+            let host = self.host.clone();
             let shared_state = self.shared_state.clone();
 
             tracing::debug!("Scheduling asynchronous closure of break windows");
@@ -267,13 +624,7 @@ where
 
                 tracing::debug!("Closing break windows (async)");
 
-                let windows = app_handle.webview_windows();
-                for (label, window) in windows {
-                    if label.starts_with("break-") {
-                        tracing::debug!("Closing break window: {label}");
-                        let _ = window.close();
-                    }
-                }
+                host.close_prompts("break-");
 
                 // CRITICAL: Always clean up session state when closing break windows
                 shared_state.write().end_break_session();
@@ -286,12 +637,9 @@ where
 
     /// Get postpone duration based on current break type
     async fn get_postpone_duration_s(&self) -> u32 {
-        let config = self.app_handle.state::<SharedConfig>();
+        let config = self.host.config();
         let config_guard = config.read().await;
-        let now_local = Utc::now().with_timezone(&Local);
-        let active_schedule =
-            get_active_schedule(&config_guard, now_local.time(), now_local.weekday());
-        active_schedule.map_or(300, |s| {
+        self.current_schedule(&config_guard).map_or(300, |s| {
             match &self.state {
                 BreakSchedulerState::WaitingForBreak(info)
                 | BreakSchedulerState::WaitingForNotification(info)
@@ -307,12 +655,9 @@ where
 
     /// Get maximum postpone count based on current break type
     async fn get_max_postpone_count(&self) -> u8 {
-        let config = self.app_handle.state::<SharedConfig>();
+        let config = self.host.config();
         let config_guard = config.read().await;
-        let now_local = Utc::now().with_timezone(&Local);
-        let active_schedule =
-            get_active_schedule(&config_guard, now_local.time(), now_local.weekday());
-        active_schedule.map_or(2, |s| {
+        self.current_schedule(&config_guard).map_or(2, |s| {
             match &self.state {
                 BreakSchedulerState::WaitingForBreak(info)
                 | BreakSchedulerState::WaitingForNotification(info)
@@ -328,18 +673,49 @@ where
 
     /// Emit current status to frontend
     fn emit_current_status(&self) {
+        self.emit_scheduler_status(self.current_status());
+    }
+
+    /// Status of the scheduler in its current state
+    fn current_status(&self) -> SchedulerStatus {
         match &self.state {
-            BreakSchedulerState::Paused(_) => {
-                self.emit_paused_status(true);
-            }
-            BreakSchedulerState::Idle | BreakSchedulerState::InBreak(_) => {
-                self.emit_idle_status();
-            }
+            BreakSchedulerState::Paused(_) => self.paused_status(true),
+            BreakSchedulerState::Idle => self.paused_status(false),
+            BreakSchedulerState::InBreak(info) => SchedulerStatus {
+                postpone_count: info.postpone_count,
+                ..self.paused_status(false)
+            },
             BreakSchedulerState::WaitingForNotification(info)
-            | BreakSchedulerState::WaitingForBreak(info) => {
-                self.emit_status(info);
+            | BreakSchedulerState::WaitingForBreak(info) => self.break_status(info),
+        }
+    }
+
+    /// Phase of the state machine as shown in the status
+    fn phase(&self) -> SchedulerPhase {
+        match &self.state {
+            BreakSchedulerState::Paused(_) => SchedulerPhase::Paused,
+            BreakSchedulerState::Idle => SchedulerPhase::Idle,
+            BreakSchedulerState::WaitingForNotification(_) => {
+                SchedulerPhase::WaitingForNotification
             }
+            BreakSchedulerState::WaitingForBreak(_) => SchedulerPhase::WaitingForBreak,
+            BreakSchedulerState::InBreak(_) => {
+                let remaining_s = self.break_ends_at.map_or(0, |ends_at| {
+                    u32::try_from((ends_at - self.clock.now()).num_seconds().max(0))
+                        .unwrap_or(u32::MAX)
+                });
+                SchedulerPhase::InBreak(remaining_s)
+            }
+        }
+    }
+
+    /// The next attention reminder, unless paused
+    fn next_attention(&self) -> Option<(SchedulerEvent, DateTime<Utc>)> {
+        if matches!(self.state, BreakSchedulerState::Paused(_)) {
+            return None;
         }
+        calculate_next_attention(&self.attentions, self.clock.now(), &self.clock.timezone())
+            .map(|(id, time)| (SchedulerEvent::Attention(id), time))
     }
 
     /// Handle incoming commands
@@ -347,43 +723,89 @@ where
     async fn handle_command(&mut self, cmd: Command) {
         tracing::debug!("BreakScheduler handling command: {cmd}");
         match cmd {
-            Command::Pause(reason) => {
-                self.handle_pause_command(reason);
+            Command::Pause(reason, responder) => {
+                self.handle_pause_command(reason).await;
+                self.reply(responder, Ok(()));
             }
-            Command::Resume(_reason) => {
-                self.handle_resume_command().await;
+            // The manager turns it into `Pause(Manual)`, so it should never get here
+            Command::PauseFor(duration, responder) => {
+                tracing::warn!("PauseFor({duration}) reached BreakScheduler, ignoring it");
+                self.reply(responder, Err(Rejection::Unsupported));
             }
-            Command::PostponeBreak => {
-                self.handle_postpone_break_command().await;
+            Command::Resume(reason, responder) => {
+                self.handle_resume_command(reason).await;
+                self.reply(responder, Ok(()));
             }
-            Command::SkipBreak => {
-                self.handle_skip_break_command().await;
+            Command::PostponeBreak(responder) => {
+                let result = self.handle_postpone_break_command().await;
+                self.reply(responder, result);
+            }
+            Command::SkipBreak(responder) => {
+                let result = self.handle_skip_break_command().await;
+                self.reply(responder, result);
             }
             Command::PromptFinished(event) => {
                 self.handle_prompt_finished_command(event).await;
             }
-            Command::TriggerEvent(event) => {
-                self.handle_trigger_event_command(event).await;
+            Command::TriggerEvent(event, responder) => {
+                let result = self.handle_trigger_event_command(event).await;
+                self.reply(responder, result);
             }
             Command::UpdateConfig(new_config) => {
                 self.handle_update_config_command(new_config).await;
             }
-            Command::RequestBreakStatus => {
+            Command::RequestBreakStatus(responder) => {
                 self.handle_request_break_status_command();
+                self.reply(responder, Ok(()));
+            }
+            Command::SystemResumed(slept_s) => {
+                self.handle_system_resumed_command(slept_s).await;
+            }
+            Command::StartPomodoro(task, responder) => {
+                let result = self.handle_start_pomodoro_command(task).await;
+                self.reply(responder, result);
+            }
+            Command::StopPomodoro(responder) => {
+                let result = self.handle_stop_pomodoro_command().await;
+                self.reply(responder, result);
             }
         }
     }
 
+    /// Answer a command with the status it left the scheduler in, or why it was refused
+    fn reply(&self, responder: Option<Responder>, result: Result<(), Rejection>) {
+        let Some(responder) = responder else {
+            return;
+        };
+        let reply = match result {
+            Ok(()) => CommandReply::Accepted(Some(Box::new(self.current_status()))),
+            Err(rejection) => {
+                tracing::debug!("Command rejected: {rejection}");
+                CommandReply::Rejected(rejection)
+            }
+        };
+        responder.send(reply);
+    }
+
     /// Transition to calculating next break
     async fn transition_to_calculating(&mut self) {
         let break_info = {
-            let config = self.app_handle.state::<SharedConfig>();
+            let host = self.host.clone();
+            let config = host.config();
             let config_guard = config.read().await;
+            // Pomodoro sessions do not follow the time of day
+            self.next_schedule_boundary = if self.in_pomodoro() {
+                None
+            } else {
+                next_schedule_boundary(&config_guard, &self.clock.now_local())
+            };
+            self.take_config(&config_guard);
             self.calculate_next_break(&config_guard)
         };
+        self.save_progress();
 
         if let Some(break_info) = break_info {
-            let now = Utc::now();
+            let now = self.clock.now();
 
             if break_info.break_time <= now {
                 tracing::warn!("Break time already passed, executing immediately");
@@ -411,98 +833,532 @@ where
         }
     }
 
+    /// Keep what the status and config updates need from `config`
+    fn take_config(&mut self, config: &AppConfig) {
+        let schedule = self.current_schedule(config);
+        self.schedule_name = schedule.map(|s| s.name.clone());
+        self.active_time_target_s = schedule
+            .filter(|s| s.count_active_time && !self.in_pomodoro())
+            .map(ScheduleSettings::work_interval_s);
+        self.schedules.clone_from(&config.schedules);
+        self.attentions.clone_from(&config.attentions);
+    }
+
     /// Calculate the next break based on current state and configuration
     fn calculate_next_break(&self, config: &AppConfig) -> Option<BreakInfo> {
-        let now = Utc::now();
-        calculate_next_break_pure(config, now, self.mini_break_counter, self.last_break_time)
+        let now = self.clock.now();
+        if self.in_pomodoro() {
+            let schedule = get_pomodoro_schedule(config)?;
+            let info = calculate_break_for_schedule(
+                schedule,
+                now,
+                self.mini_break_counter,
+                self.work_since_long_break_s,
+                self.last_break_time,
+            )?;
+            return Some(self.escalate(schedule, info, self.last_break_time.unwrap_or(now)));
+        }
+        // Counting active time, the interval started as long ago as the user was active
+        let last_break_time = match self.active_time_s() {
+            Some(active_s) => Some(now - Duration::seconds(i64::from(active_s))),
+            None => self.last_break_time,
+        };
+        let info = calculate_next_break_pure(
+            config,
+            now,
+            &self.clock.timezone(),
+            self.mini_break_counter,
+            self.work_since_long_break_s,
+            last_break_time,
+        )?;
+        let schedule = self.current_schedule(config)?;
+        Some(self.escalate(schedule, info, last_break_time.unwrap_or(now)))
+    }
+
+    /// Escalate the upcoming break if breaks of its kind were skipped too often
+    ///
+    /// `interval_start` is where the interval leading up to the break started, a
+    /// shortened interval keeps its start and ends halfway.
+    fn escalate(
+        &self,
+        schedule: &ScheduleSettings,
+        mut info: BreakInfo,
+        interval_start: DateTime<Utc>,
+    ) -> BreakInfo {
+        let Some(skipped) = info.event.break_id() else {
+            return info;
+        };
+        let settings = if info.event.is_long() {
+            &schedule.long_breaks.base
+        } else {
+            &schedule.mini_breaks.base
+        };
+        let skip_count = self.skip_counts.get(&skipped).copied().unwrap_or(0);
+        if skip_count < settings.escalate_after_skips.max(1) {
+            return info;
+        }
+
+        let policy = settings.skip_escalation;
+        match policy {
+            SkipEscalation::Off => return info,
+            SkipEscalation::Strict => {}
+            SkipEscalation::ShortenInterval => {
+                let break_time = interval_start + (info.break_time - interval_start) / 2;
+                let brought_forward = info.break_time - break_time;
+                info.break_time = break_time;
+                info.notification_time = info
+                    .notification_time
+                    .map(|time| time - brought_forward)
+                    .filter(|time| *time > self.clock.now());
+            }
+            SkipEscalation::LongBreak => {
+                if !info.event.is_mini() || !schedule.long_breaks.base.enabled {
+                    return info;
+                }
+                info.event = SchedulerEvent::LongBreak(schedule.long_breaks.base.id);
+            }
+        }
+
+        tracing::info!(
+            "{skip_count} breaks skipped in a row, escalating {} ({policy:?})",
+            info.event
+        );
+        info.escalation = Some(Escalation {
+            skipped,
+            skip_count,
+            policy,
+        });
+        info
+    }
+
+    /// Count a skipped break towards the escalation of its break settings
+    fn count_skip(&mut self, event: SchedulerEvent, escalation: Option<Escalation>) {
+        if let Some(id) = escalation.map(|e| e.skipped).or(event.break_id()) {
+            let count = self.skip_counts.entry(id).or_default();
+            *count = count.saturating_add(1);
+        }
+    }
+
+    /// Forget the skips leading up to a break that was taken
+    fn forgive_skips(&mut self, event: SchedulerEvent, escalation: Option<Escalation>) {
+        for id in [event.break_id(), escalation.map(|e| e.skipped)]
+            .into_iter()
+            .flatten()
+        {
+            self.skip_counts.remove(&id);
+        }
+    }
+
+    /// Active seconds since the last break, if the current schedule counts active time
+    ///
+    /// `None` also when idle detection is unavailable, so wall-clock time is used.
+    fn active_time_s(&self) -> Option<u32> {
+        self.active_time_target_s?;
+        self.shared_state.read().active_s()
+    }
+
+    /// Move the upcoming break back by the time the user was not active
+    ///
+    /// Called when the break or notification timer fires. Returns `true` if the
+    /// break is not due yet, in which case the timer is rearmed instead. Postponed
+    /// breaks are left alone, their time was chosen by the user.
+    async fn defer_for_inactivity(&mut self) -> bool {
+        if self.active_time_s().is_none() {
+            return false;
+        }
+        let (BreakSchedulerState::WaitingForNotification(info)
+        | BreakSchedulerState::WaitingForBreak(info)) = &self.state
+        else {
+            return false;
+        };
+        if info.postpone_count > 0 {
+            return false;
+        }
+
+        let recalculated = {
+            let config = self.host.config();
+            let config_guard = config.read().await;
+            self.calculate_next_break(&config_guard)
+        };
+        let Some(mut new_info) = recalculated.filter(|new_info| new_info.event == info.event)
+        else {
+            return false;
+        };
+
+        // Active time lags behind by up to one sample
+        let tolerance = self.clock.now() + Duration::seconds(ACTIVITY_SAMPLE_INTERVAL_S as i64);
+        let due = match self.state {
+            BreakSchedulerState::WaitingForNotification(_) => {
+                new_info.notification_time.unwrap_or(new_info.break_time)
+            }
+            _ => new_info.break_time,
+        };
+        if due <= tolerance {
+            return false;
+        }
+
+        tracing::debug!(
+            "Break not due in active time yet, deferring to {}",
+            new_info.break_time
+        );
+        self.state = match self.state {
+            BreakSchedulerState::WaitingForNotification(_)
+                if new_info.notification_time.is_some() =>
+            {
+                BreakSchedulerState::WaitingForNotification(new_info.clone())
+            }
+            _ => {
+                new_info.notification_time = None;
+                BreakSchedulerState::WaitingForBreak(new_info.clone())
+            }
+        };
+        self.emit_status(&new_info);
+        true
+    }
+
+    /// Hold a due break back until the user pauses typing
+    ///
+    /// Called when the break timer fires. Returns `true` while the break should
+    /// keep waiting, for at most `break_grace_s` past its break time. Breaks open
+    /// on time if idle detection is unavailable.
+    async fn wait_for_input_lull(&mut self, info: &BreakInfo) -> bool {
+        let grace_s = {
+            let config = self.host.config();
+            config.read().await.break_grace_s
+        };
+        let Some(idle_s) = self.shared_state.read().idle_s() else {
+            return false;
+        };
+        if grace_s == 0 || idle_s >= INPUT_LULL_S {
+            self.lull_wait = None;
+            return false;
+        }
+
+        let until = match self.lull_wait {
+            Some(wait) if wait.break_time == info.break_time => wait.until,
+            _ => {
+                tracing::debug!("Break due, waiting up to {grace_s}s for a pause in typing");
+                info.break_time + Duration::seconds(i64::from(grace_s))
+            }
+        };
+        if self.clock.now() >= until {
+            tracing::debug!("No pause in typing within {grace_s}s, opening break");
+            self.lull_wait = None;
+            return false;
+        }
+
+        self.lull_wait = Some(LullWait {
+            break_time: info.break_time,
+            until,
+        });
+        true
+    }
+
+    /// Check if a Pomodoro session is running
+    fn in_pomodoro(&self) -> bool {
+        self.shared_state.read().pomodoro().is_some()
+    }
+
+    /// The schedule breaks are currently taken from
+    ///
+    /// A running Pomodoro session takes precedence over the time of day.
+    fn current_schedule<'a>(&self, config: &'a AppConfig) -> Option<&'a ScheduleSettings> {
+        if self.in_pomodoro() {
+            get_pomodoro_schedule(config)
+        } else {
+            let now = self.clock.now_local();
+            get_active_schedule(config, now.time(), now.weekday())
+        }
     }
 
     /// Execute a break: create window and play audio, then wait for completion
-    #[allow(clippy::unused_async)]
     async fn execute_break(&mut self, info: BreakInfo) {
         tracing::info!("Executing break: {}", info.event);
         let event = info.event;
-        #[cfg(not(test))]
         let postpone_count = info.postpone_count;
+        let escalation = info.escalation;
         self.state = BreakSchedulerState::InBreak(info);
+        self.break_started_at = Some(self.clock.now());
+        self.break_ends_at = self
+            .break_duration(event)
+            .await
+            .map(|d| self.clock.now() + d);
+        self.break_check = self.start_break_check(event).await;
 
         // CRITICAL: Mark break session start BEFORE creating windows
         // This prevents DND monitor from reacting to system DND triggered by the fullscreen window
         // Windows fullscreen can trigger system Focus Assist, causing unwanted scheduler pause
         self.shared_state.write().start_break_session();
         tracing::info!("Break session started, DND monitor will ignore DND changes during break");
+        self.emit_current_status();
+
+        let entry = record_prompt_transition(
+            &self.host,
+            event,
+            PromptOutcome::Started,
+            self.clock.now(),
+            None,
+            postpone_count,
+        )
+        .await;
+        self.event_emitter
+            .emit(DomainEvent::PromptTransition(entry));
 
         // Emit event to notify tests/frontend that break is starting
-        if let Err(e) = self.event_emitter.emit("scheduler-event", event) {
-            tracing::warn!("Failed to emit scheduler-event: {e}");
+        self.event_emitter.emit(DomainEvent::PromptStarted(event));
+
+        // With MockRuntime, skip window creation as it's not supported
+        // Tests and simulations can still observe the InBreak state via events
+        if self.headless {
+            tracing::debug!("Headless: skipping window creation for event: {event}");
+        } else if let Err(e) = self
+            .host
+            .show_prompt(event, postpone_count, escalation, self.current_task())
+            .await
+        {
+            tracing::error!("Failed to create break windows: {e}");
+            self.record_current_break(PromptOutcome::Cancelled).await;
+
+            // Clean up session state on error
+            self.shared_state.write().end_break_session();
+            tracing::info!("Break session ended (error cleanup)");
+
+            self.update_state_after_break(event);
+            Box::pin(self.transition_to_calculating()).await;
         }
+    }
 
-        // In tests with MockRuntime, skip window creation as it's not supported
-        // The test can still verify that we entered InBreak state via events
-        #[cfg(not(test))]
-        {
-            if let Err(e) = create_prompt_windows(&self.app_handle, event, postpone_count).await {
-                tracing::error!("Failed to create break windows: {e}");
+    /// Start verifying the break behind `event`, if enabled and idle time is sampled
+    async fn start_break_check(&self, event: SchedulerEvent) -> Option<BreakCheck> {
+        let (mode, duration_s) = {
+            let config = self.host.config();
+            let config_guard = config.read().await;
+            (
+                config_guard.break_verification,
+                break_duration_s(&config_guard, event)?,
+            )
+        };
+        if mode == BreakVerification::Off || self.shared_state.read().idle_s().is_none() {
+            return None;
+        }
 
-                // Clean up session state on error
-                self.shared_state.write().end_break_session();
-                tracing::info!("Break session ended (error cleanup)");
+        Some(BreakCheck {
+            mode,
+            duration: Duration::seconds(i64::from(duration_s)),
+            interrupted: false,
+        })
+    }
 
-                self.update_state_after_break(event);
-                Box::pin(self.transition_to_calculating()).await;
-            }
+    /// Full duration of the break behind `event`
+    async fn break_duration(&self, event: SchedulerEvent) -> Option<Duration> {
+        let config = self.host.config();
+        let config_guard = config.read().await;
+        break_duration_s(&config_guard, event).map(|s| Duration::seconds(i64::from(s)))
+    }
+
+    /// When the break in progress is given up on if its prompt never finishes
+    ///
+    /// A break whose length is unknown, e.g. because its schedule is gone from
+    /// the config, is given up on the same time after it started.
+    fn break_watchdog_deadline(&self) -> DateTime<Utc> {
+        let ends_at = self
+            .break_ends_at
+            .or(self.break_started_at)
+            .unwrap_or_else(|| self.clock.now());
+        ends_at + Duration::seconds(BREAK_WATCHDOG_SLACK_S)
+    }
+
+    /// Give up on a break whose prompt never reported back
+    ///
+    /// The prompt window may have crashed, been killed, or its `PromptFinished`
+    /// call may have been lost. Without this the scheduler would stay in the
+    /// break forever with monitors ignoring their triggers, so leftover windows
+    /// and payloads are cleaned up and scheduling resumes. The break was not
+    /// taken, so only the interval restarts and the long break cycle is kept.
+    async fn recover_stuck_break(&mut self, event: SchedulerEvent) {
+        let overdue_s = self
+            .break_ends_at
+            .map_or(0, |ends_at| (self.clock.now() - ends_at).num_seconds());
+        tracing::error!(
+            "Break prompt for {event} did not finish {overdue_s}s after the break ended, recovering"
+        );
+
+        self.break_check = None;
+        self.record_current_break(PromptOutcome::Cancelled).await;
+
+        self.close_break_windows();
+        self.shared_state.write().end_break_session();
+        if let Some(store) = self.host.payload_store() {
+            store.remove_with_prefix("break-").await;
         }
 
-        #[cfg(test)]
-        {
-            // In tests, just log that we would create windows
-            tracing::debug!("Test mode: skipping window creation for event: {event}");
+        self.update_last_break_time();
+        self.transition_to_calculating().await;
+    }
+
+    /// Look for input during the break in progress
+    ///
+    /// Input in the first seconds of the break is the user finishing what they
+    /// were doing and is ignored. The prompt is told how much of the break is left.
+    fn check_break_activity(&mut self, event: SchedulerEvent) {
+        let now = self.clock.now();
+        let settled = self
+            .break_started_at
+            .is_some_and(|started| now - started >= Duration::seconds(BREAK_SETTLE_S));
+        let Some(idle_s) = self.shared_state.read().idle_s() else {
+            return;
+        };
+        let Some(check) = self.break_check.as_mut() else {
+            return;
+        };
+        if !settled || idle_s >= ACTIVITY_SAMPLE_INTERVAL_S {
+            return;
+        }
+
+        let Some(ends_at) = self.break_ends_at.as_mut() else {
+            return;
+        };
+
+        check.interrupted = true;
+        match check.mode {
+            BreakVerification::Extend => {
+                *ends_at += Duration::seconds(ACTIVITY_SAMPLE_INTERVAL_S as i64);
+            }
+            BreakVerification::Restart => *ends_at = now + check.duration,
+            BreakVerification::Off | BreakVerification::Record => {}
         }
+        let remaining_s = u32::try_from((*ends_at - now).num_seconds().max(0)).unwrap_or(u32::MAX);
+        tracing::info!("Input during {event}, {remaining_s}s of the break left");
+
+        self.event_emitter
+            .emit(DomainEvent::BreakActivity(BreakActivity {
+                event,
+                remaining_s,
+            }));
+        self.emit_current_status();
+    }
+
+    /// Task label of the running Pomodoro session, shown in break prompts
+    fn current_task(&self) -> Option<String> {
+        self.shared_state
+            .read()
+            .pomodoro()
+            .and_then(|session| session.task.clone())
     }
 
-    /// Reset break timers
-    fn reset_last_break_time(&mut self) {
-        self.last_break_time = None;
+    /// Start a fresh long break cycle, as after a long break
+    fn reset_long_break_cycle(&mut self) {
+        self.mini_break_counter = 0;
+        self.work_since_long_break_s = 0;
     }
 
     /// Update break timers after a break completes
     fn update_last_break_time(&mut self) {
-        self.last_break_time = Some(Utc::now());
+        let now = self.clock.now();
+        self.last_break_time = Some(now);
+        self.shared_state.write().reset_activity(now);
     }
 
-    /// Emit current status to frontend
-    fn emit_status(&self, break_info: &BreakInfo) {
-        let duration_to_wait = break_info.break_time - Utc::now();
-        let status = SchedulerStatus {
-            paused: false,
-            next_event: Some(SchedulerEventInfo {
-                kind: break_info.event,
-                time: break_info.break_time.to_rfc3339(),
-                seconds_until: duration_to_wait.num_seconds() as i32,
-            }),
-            mini_break_counter: self.mini_break_counter,
+    /// Record a lifecycle transition of the break held by the current state
+    ///
+    /// Must be called before leaving the state, so the time spent in the
+    /// prompt can be computed for `InBreak`.
+    async fn record_current_break(&mut self, outcome: PromptOutcome) {
+        let (event, postpone_count, in_break) = match &self.state {
+            BreakSchedulerState::InBreak(info) => (info.event, info.postpone_count, true),
+            BreakSchedulerState::WaitingForNotification(info)
+            | BreakSchedulerState::WaitingForBreak(info) => {
+                (info.event, info.postpone_count, false)
+            }
+            BreakSchedulerState::Paused(_) | BreakSchedulerState::Idle => return,
+        };
+        let started_at = if in_break {
+            self.break_ends_at = None;
+            self.break_started_at.take()
+        } else {
+            None
         };
 
+        let entry = record_prompt_transition(
+            &self.host,
+            event,
+            outcome,
+            self.clock.now(),
+            started_at,
+            postpone_count,
+        )
+        .await;
         self.event_emitter
-            .emit("scheduler-status", &status)
-            .unwrap_or_else(|e| {
-                tracing::warn!("Failed to emit scheduler status: {e}");
-            });
+            .emit(DomainEvent::PromptTransition(entry));
+    }
+
+    /// Emit current status to frontend
+    fn emit_status(&self, break_info: &BreakInfo) {
+        self.emit_scheduler_status(self.break_status(break_info));
+    }
+
+    /// Status while waiting for `break_info`
+    fn break_status(&self, break_info: &BreakInfo) -> SchedulerStatus {
+        let active_time = self.active_time_target_s.and_then(|target_s| {
+            self.active_time_s()
+                .map(|active_s| ActiveTimeProgress { active_s, target_s })
+        });
+        SchedulerStatus {
+            next_event: Some(SchedulerEventInfo::new(
+                break_info.event,
+                break_info.break_time,
+                self.clock.now(),
+            )),
+            postpone_count: break_info.postpone_count,
+            active_time,
+            ..self.paused_status(false)
+        }
     }
 
     /// Emit paused status to frontend
     fn emit_paused_status(&self, paused: bool) {
-        let status = SchedulerStatus {
+        self.emit_scheduler_status(self.paused_status(paused));
+    }
+
+    /// Status without an upcoming break, either paused or idle
+    fn paused_status(&self, paused: bool) -> SchedulerStatus {
+        let now = self.clock.now();
+        let (pause_reasons, pause_expiry, pomodoro) = {
+            let state = self.shared_state.read();
+            let pause_expiry = if paused {
+                state
+                    .manual_pause_until()
+                    .map(|until| PauseExpiry::new(until, now))
+            } else {
+                None
+            };
+            (
+                state.pause_reasons(),
+                pause_expiry,
+                state.pomodoro().cloned(),
+            )
+        };
+        SchedulerStatus {
             paused,
+            pause_reasons,
+            phase: self.phase(),
+            schedule_name: self.schedule_name.clone(),
             next_event: None,
             mini_break_counter: self.mini_break_counter,
-        };
+            postpone_count: 0,
+            next_attention: self
+                .next_attention()
+                .map(|(event, time)| SchedulerEventInfo::new(event, time, now)),
+            pause_expiry,
+            pomodoro,
+            active_time: None,
+        }
+    }
 
+    /// Emit `status` to frontend
+    fn emit_scheduler_status(&self, status: SchedulerStatus) {
         self.event_emitter
-            .emit("scheduler-status", &status)
-            .unwrap_or_else(|e| {
-                tracing::warn!("Failed to emit scheduler status: {e}");
-            });
+            .emit(DomainEvent::Status(Box::new(status)));
     }
 
     /// Emit idle status to frontend
@@ -511,32 +1367,73 @@ where
     }
 
     /// Handle Pause command
-    fn handle_pause_command(&mut self, reason: PauseReason) {
+    ///
+    /// Where the interval stood is kept for the resume, which decides what to
+    /// do with it.
+    async fn handle_pause_command(&mut self, reason: PauseReason) {
         tracing::info!("Pausing BreakScheduler: {reason}");
+        if matches!(self.state, BreakSchedulerState::InBreak(_)) {
+            self.record_current_break(PromptOutcome::Cancelled).await;
+        }
+        let was_paused = matches!(self.state, BreakSchedulerState::Paused(_));
         self.state = BreakSchedulerState::Paused(reason);
 
-        // Reset timers for certain pause reasons
-        match reason {
-            PauseReason::UserIdle | PauseReason::Dnd | PauseReason::AppExclusion => {
-                self.reset_last_break_time();
-            }
-            PauseReason::Manual => {}
+        if !was_paused {
+            let inactive_s = {
+                let config = self.host.config();
+                config.read().await.inactive_s
+            };
+
+            let now = self.clock.now();
+            let at = if reason == PauseReason::UserIdle {
+                // The idle pause comes `inactive_s` after the last input, but
+                // idle time before the last break must not count twice
+                let idle_start = now - Duration::seconds(i64::from(inactive_s));
+                self.last_break_time
+                    .map_or(idle_start, |last| idle_start.max(last))
+            } else {
+                now
+            };
+            self.pause_start = Some(PauseStart {
+                at,
+                active_s: self.active_time_s(),
+            });
         }
         self.close_break_windows();
         self.emit_paused_status(true);
     }
 
     /// Handle Resume command
-    async fn handle_resume_command(&mut self) {
-        tracing::info!("Resuming BreakScheduler");
-        if let BreakSchedulerState::Paused(_) = self.state {
-            self.update_last_break_time();
+    ///
+    /// Applies the pause policy of `reason`, the last pause reason to be cleared.
+    /// With overlapping reasons, the one that ends the pause decides, e.g. a DND
+    /// pause followed by inactivity is credited as a break once the user returns.
+    async fn handle_resume_command(&mut self, reason: PauseReason) {
+        tracing::info!("Resuming BreakScheduler: {reason}");
+        if matches!(self.state, BreakSchedulerState::Paused(_)) {
+            let policy = {
+                let config = self.host.config();
+                config.read().await.pause_policies.for_reason(reason)
+            };
+
+            match (policy, self.pause_start.take()) {
+                (PausePolicy::Freeze, Some(start)) => {
+                    self.continue_interval(start);
+                }
+                (PausePolicy::CreditBreak, Some(start)) => {
+                    self.credit_pause_as_break(start.at).await;
+                    self.update_last_break_time();
+                }
+                _ => {
+                    self.update_last_break_time();
+                }
+            }
             Box::pin(self.transition_to_calculating()).await;
         }
     }
 
     /// Handle `PostponeBreak` command
-    async fn handle_postpone_break_command(&mut self) {
+    async fn handle_postpone_break_command(&mut self) -> Result<(), Rejection> {
         // Check postpone limit first
         let max_count = self.get_max_postpone_count().await;
 
@@ -544,23 +1441,29 @@ where
             BreakSchedulerState::WaitingForNotification(info)
             | BreakSchedulerState::WaitingForBreak(info)
             | BreakSchedulerState::InBreak(info) => info.postpone_count,
-            _ => {
+            BreakSchedulerState::Paused(_) => {
                 tracing::warn!("Cannot postpone in current state: {}", self.state);
-                return;
+                return Err(Rejection::Paused);
+            }
+            BreakSchedulerState::Idle => {
+                tracing::warn!("Cannot postpone in current state: {}", self.state);
+                return Err(Rejection::NoBreak);
             }
         };
 
         // Check if limit reached
         if current_count >= max_count {
             tracing::warn!("Max postpone count ({max_count}) reached, cannot postpone further");
-            // Emit event to notify frontend
-            let _ = self.event_emitter.emit("postpone-limit-reached", ());
-            return;
+            // Emit event to notify frontend (postpones from the hotkey have no reply)
+            self.event_emitter.emit(DomainEvent::PostponeLimitReached);
+            return Err(Rejection::PostponeLimitReached);
         }
 
         let postpone_s = self.get_postpone_duration_s().await;
         let postpone_duration = Duration::seconds(i64::from(postpone_s));
 
+        self.record_current_break(PromptOutcome::Postponed).await;
+
         match &self.state {
             BreakSchedulerState::WaitingForNotification(info)
             | BreakSchedulerState::WaitingForBreak(info) => {
@@ -589,7 +1492,7 @@ where
 
                 let mut new_info = info.clone();
                 new_info.postpone_count += 1;
-                new_info.break_time = Utc::now() + postpone_duration;
+                new_info.break_time = self.clock.now() + postpone_duration;
                 new_info.notification_time = None;
 
                 self.close_break_windows();
@@ -600,16 +1503,23 @@ where
 
             _ => unreachable!("Cannot postpone in {} state.", self.state),
         }
+        Ok(())
     }
 
     /// Handle `SkipBreak` command
-    async fn handle_skip_break_command(&mut self) {
+    async fn handle_skip_break_command(&mut self) -> Result<(), Rejection> {
+        if let BreakSchedulerState::Paused(reason) = self.state {
+            tracing::warn!("Cannot skip a break while paused ({reason})");
+            return Err(Rejection::Paused);
+        }
         tracing::info!("Skipping current break");
-        match &self.state {
+        self.record_current_break(PromptOutcome::Skipped).await;
+        match self.state.clone() {
             BreakSchedulerState::WaitingForNotification(info)
             | BreakSchedulerState::WaitingForBreak(info)
             | BreakSchedulerState::InBreak(info) => {
                 self.update_state_after_break(info.event);
+                self.count_skip(info.event, info.escalation);
             }
             _ => {
                 self.update_last_break_time();
@@ -617,6 +1527,7 @@ where
         }
         self.close_break_windows();
         self.transition_to_calculating().await;
+        Ok(())
     }
 
     /// Handle `PromptFinished` command
@@ -626,12 +1537,25 @@ where
         if let BreakSchedulerState::InBreak(info) = &self.state {
             if event == info.event {
                 tracing::info!("Break finished normally: {event}");
+                let escalation = info.escalation;
+
+                let interrupted = self
+                    .break_check
+                    .take()
+                    .is_some_and(|c| c.interrupted && c.mode == BreakVerification::Record);
+                let outcome = if interrupted {
+                    PromptOutcome::Interrupted
+                } else {
+                    PromptOutcome::Completed
+                };
+                self.record_current_break(outcome).await;
 
                 // Clean up session state
                 self.shared_state.write().end_break_session();
                 tracing::info!("Break session ended, DND monitor will resume monitoring");
 
                 self.update_state_after_break(event);
+                self.forgive_skips(event, escalation);
                 tracing::debug!("Break state updated after break: {event}");
 
                 self.transition_to_calculating().await;
@@ -648,27 +1572,63 @@ where
     }
 
     /// Handle `TriggerEvent` command
-    async fn handle_trigger_event_command(&mut self, event: SchedulerEvent) {
+    async fn handle_trigger_event_command(
+        &mut self,
+        event: SchedulerEvent,
+    ) -> Result<(), Rejection> {
+        if let BreakSchedulerState::Paused(reason) = self.state {
+            tracing::warn!("Cannot trigger {event} while paused ({reason})");
+            return Err(Rejection::Paused);
+        }
         tracing::info!("Manually triggering break: {event}");
         // Create a new BreakInfo for manual trigger
         let test_info = BreakInfo {
-            break_time: Utc::now(),
+            break_time: self.clock.now(),
             notification_time: None,
             event,
             postpone_count: 0,
+            escalation: None,
         };
         self.execute_break(test_info).await;
+        Ok(())
     }
 
     /// Handle `UpdateConfig` command
+    ///
+    /// The timers are only recalculated if the timing of the schedules changed,
+    /// so saving unrelated settings keeps the time of the last break, the
+    /// postpone count and a notification already sent. A paused scheduler or a
+    /// break in progress picks up the new timing once it ends.
     async fn handle_update_config_command(&mut self, new_config: AppConfig) {
         tracing::debug!("Updating config");
+        let mut timing_changed = schedules_timing_changed(&self.schedules, &new_config.schedules);
+        if self.in_pomodoro() && get_pomodoro_schedule(&new_config).is_none() {
+            tracing::info!("Pomodoro schedule removed or disabled, ending the session");
+            self.end_pomodoro_session();
+            timing_changed = true;
+        }
+        let reschedule = timing_changed
+            && matches!(
+                self.state,
+                BreakSchedulerState::Idle
+                    | BreakSchedulerState::WaitingForNotification(_)
+                    | BreakSchedulerState::WaitingForBreak(_)
+            );
+        if !reschedule {
+            self.take_config(&new_config);
+        }
         {
-            let config = self.app_handle.state::<SharedConfig>();
+            let config = self.host.config();
             let mut config_guard = config.write().await;
             *config_guard = new_config;
         }
-        self.transition_to_calculating().await;
+
+        if reschedule {
+            self.transition_to_calculating().await;
+        } else {
+            tracing::debug!("Keeping current timers in state: {}", self.state);
+            self.emit_current_status();
+        }
     }
 
     /// Handle `RequestBreakStatus` command
@@ -676,47 +1636,189 @@ where
         tracing::debug!("Status request received");
         self.emit_current_status();
     }
+
+    /// Handle `SystemResumed` command
+    ///
+    /// Time asleep counts as a break: at least a mini break long starts a fresh
+    /// interval, and at least a long break long also completes the long break
+    /// cycle if `credit_sleep_as_long_break` is set. Timers are always recomputed,
+    /// so breaks that fell due while asleep are dropped instead of firing at once.
+    async fn handle_system_resumed_command(&mut self, slept_s: u64) {
+        if let BreakSchedulerState::InBreak(_) = self.state {
+            // The prompt could not finish while asleep, give it that time back
+            if let Some(ends_at) = self.break_ends_at.as_mut() {
+                *ends_at += Duration::seconds(i64::try_from(slept_s).unwrap_or(i64::MAX));
+            }
+        }
+        if matches!(
+            self.state,
+            BreakSchedulerState::Paused(_) | BreakSchedulerState::InBreak(_)
+        ) {
+            tracing::debug!("Ignoring system resume in state: {}", self.state);
+            return;
+        }
+
+        let (durations, credit_long_break) = {
+            let config = self.host.config();
+            let config_guard = config.read().await;
+            let durations = self
+                .current_schedule(&config_guard)
+                .map(|s| (s.mini_breaks.base.duration_s, s.long_breaks.base.duration_s));
+            (durations, config_guard.advanced.credit_sleep_as_long_break)
+        };
+
+        match durations {
+            Some((mini_duration_s, long_duration_s)) if slept_s >= u64::from(mini_duration_s) => {
+                tracing::info!("System resumed after {slept_s}s, starting a fresh interval");
+                self.update_last_break_time();
+
+                if credit_long_break && slept_s >= u64::from(long_duration_s) {
+                    tracing::info!("Crediting {slept_s}s asleep as a long break");
+                    self.reset_long_break_cycle();
+                }
+            }
+            _ => {
+                tracing::info!("System resumed after {slept_s}s, recomputing timers");
+            }
+        }
+        self.transition_to_calculating().await;
+    }
+
+    /// Handle `StartPomodoro` command
+    ///
+    /// The first focus block starts now, with a fresh rest cycle. Starting while a
+    /// session runs restarts it with the new task; a break in progress is cancelled.
+    async fn handle_start_pomodoro_command(
+        &mut self,
+        task: Option<String>,
+    ) -> Result<(), Rejection> {
+        if let BreakSchedulerState::Paused(reason) = self.state {
+            tracing::warn!("Cannot start a Pomodoro session while paused ({reason})");
+            return Err(Rejection::Paused);
+        }
+
+        let schedule_name = {
+            let config = self.host.config();
+            let config_guard = config.read().await;
+            get_pomodoro_schedule(&config_guard).map(|s| s.name.clone())
+        };
+        let Some(schedule_name) = schedule_name else {
+            tracing::warn!("Cannot start a Pomodoro session: no enabled Pomodoro schedule");
+            return Err(Rejection::NoPomodoroSchedule);
+        };
+
+        self.cancel_current_break().await;
+        tracing::info!("Starting Pomodoro session on '{schedule_name}' (task: {task:?})");
+        self.shared_state
+            .write()
+            .set_pomodoro(Some(PomodoroSession {
+                schedule_name,
+                task,
+            }));
+        self.reset_long_break_cycle();
+        self.update_last_break_time();
+        self.transition_to_calculating().await;
+        Ok(())
+    }
+
+    /// Handle `StopPomodoro` command
+    ///
+    /// Interval schedules take over again with a fresh interval.
+    async fn handle_stop_pomodoro_command(&mut self) -> Result<(), Rejection> {
+        if !self.in_pomodoro() {
+            tracing::debug!("No Pomodoro session to stop");
+            return Err(Rejection::NoPomodoroSession);
+        }
+
+        tracing::info!("Stopping Pomodoro session");
+        self.cancel_current_break().await;
+        self.end_pomodoro_session();
+
+        if matches!(self.state, BreakSchedulerState::Paused(_)) {
+            self.emit_paused_status(true);
+        } else {
+            self.transition_to_calculating().await;
+        }
+        Ok(())
+    }
+
+    /// Cancel the break whose prompt is open, if any
+    async fn cancel_current_break(&mut self) {
+        if matches!(self.state, BreakSchedulerState::InBreak(_)) {
+            self.record_current_break(PromptOutcome::Cancelled).await;
+            self.close_break_windows();
+        }
+    }
+
+    /// End the Pomodoro session and start a fresh interval
+    fn end_pomodoro_session(&mut self) {
+        self.shared_state.write().set_pomodoro(None);
+        self.reset_long_break_cycle();
+        self.update_last_break_time();
+    }
 }
 
 /// Pure function version of `calculate_next_break` for testing
 ///
 /// This function has no side effects and can be tested independently.
-/// All inputs are explicit parameters.
-pub(crate) fn calculate_next_break_pure(
+/// All inputs are explicit parameters. Schedules are evaluated in `timezone`.
+pub(crate) fn calculate_next_break_pure<Tz: TimeZone>(
     config: &AppConfig,
     now: DateTime<Utc>,
+    timezone: &Tz,
     mini_break_counter: u8,
+    work_since_long_break_s: u32,
     last_break_time: Option<DateTime<Utc>>,
 ) -> Option<BreakInfo> {
-    let now_local = now.with_timezone(&Local);
+    let now_local = now.with_timezone(timezone);
 
     // Check if we're in an active schedule
     let active_schedule = get_active_schedule(config, now_local.time(), now_local.weekday())?;
 
-    // Determine if it's time for a long break
-    let is_long_break_due = active_schedule.long_breaks.base.enabled
-        && mini_break_counter >= active_schedule.long_breaks.after_mini_breaks;
+    calculate_break_for_schedule(
+        active_schedule,
+        now,
+        mini_break_counter,
+        work_since_long_break_s,
+        last_break_time,
+    )
+}
+
+/// Calculate the next break of `active_schedule`, regardless of the time of day
+///
+/// Used directly for Pomodoro sessions, where the schedule is chosen by the user.
+pub(crate) fn calculate_break_for_schedule(
+    active_schedule: &ScheduleSettings,
+    now: DateTime<Utc>,
+    mini_break_counter: u8,
+    work_since_long_break_s: u32,
+    last_break_time: Option<DateTime<Utc>>,
+) -> Option<BreakInfo> {
+    let interval = Duration::seconds(i64::from(active_schedule.work_interval_s()));
+    let base_time = last_break_time.unwrap_or(now);
+    let mini_break_time = base_time + interval;
+
+    let long_break_time = long_break_time(
+        active_schedule,
+        base_time,
+        mini_break_time,
+        mini_break_counter,
+        work_since_long_break_s,
+    );
 
-    let (event, break_settings) = if is_long_break_due {
+    let (event, break_time) = if let Some(long_break_time) = long_break_time {
         (
             SchedulerEvent::LongBreak(active_schedule.long_breaks.base.id),
-            &active_schedule.long_breaks.base,
+            long_break_time,
         )
-    } else {
+    } else if active_schedule.mini_breaks.base.enabled {
         (
             SchedulerEvent::MiniBreak(active_schedule.mini_breaks.base.id),
-            &active_schedule.mini_breaks.base,
+            mini_break_time,
         )
-    };
-
-    if !break_settings.enabled {
+    } else {
         return None;
-    }
-
-    // Calculate break time
-    let interval = Duration::seconds(i64::from(active_schedule.mini_breaks.interval_s));
-    let base_time = last_break_time.unwrap_or(now);
-    let break_time = base_time + interval;
+    };
 
     // Calculate notification time if enabled
     let notification_time = active_schedule
@@ -733,9 +1835,76 @@ pub(crate) fn calculate_next_break_pure(
         notification_time,
         event,
         postpone_count: 0,
+        escalation: None,
+    })
+}
+
+/// Duration in seconds of the break behind `event`
+fn break_duration_s(config: &AppConfig, event: SchedulerEvent) -> Option<u32> {
+    config.schedules.iter().find_map(|s| match event {
+        SchedulerEvent::MiniBreak(id) if s.mini_breaks.base.id == id => {
+            Some(s.mini_breaks.base.duration_s)
+        }
+        SchedulerEvent::LongBreak(id) if s.long_breaks.base.id == id => {
+            Some(s.long_breaks.base.duration_s)
+        }
+        _ => None,
     })
 }
 
+/// Check if the timing of `new` differs from `old`
+///
+/// Schedules are matched by their mini break id, so reordering them is not a
+/// change, while adding or removing one is.
+fn schedules_timing_changed(old: &[ScheduleSettings], new: &[ScheduleSettings]) -> bool {
+    old.len() != new.len()
+        || old.iter().any(|old| {
+            !new.iter()
+                .find(|new| new.mini_breaks.base.id == old.mini_breaks.base.id)
+                .is_some_and(|new| old.same_timing(new))
+        })
+}
+
+/// When the next break should be a long break, if it should
+///
+/// Without `interval_s`, the long break takes the place of the next mini break
+/// once `after_mini_breaks` have been taken. Otherwise it falls due after
+/// `interval_s` of work and is fitted in with the mini break due at
+/// `mini_break_time` according to `collision`; with mini breaks disabled it is
+/// simply taken on time.
+fn long_break_time(
+    schedule: &ScheduleSettings,
+    base_time: DateTime<Utc>,
+    mini_break_time: DateTime<Utc>,
+    mini_break_counter: u8,
+    work_since_long_break_s: u32,
+) -> Option<DateTime<Utc>> {
+    let long_breaks = &schedule.long_breaks;
+    if !long_breaks.base.enabled {
+        return None;
+    }
+    if long_breaks.interval_s == 0 {
+        return (mini_break_counter >= long_breaks.after_mini_breaks).then_some(mini_break_time);
+    }
+
+    let remaining_s = long_breaks
+        .interval_s
+        .saturating_sub(work_since_long_break_s);
+    let due_time = base_time + Duration::seconds(i64::from(remaining_s));
+    if !schedule.mini_breaks.base.enabled {
+        return Some(due_time);
+    }
+
+    match long_breaks.collision {
+        LongBreakCollision::Merge => {
+            let half_interval = Duration::seconds(i64::from(schedule.work_interval_s() / 2));
+            (due_time <= mini_break_time + half_interval).then_some(mini_break_time)
+        }
+        LongBreakCollision::Replace => (due_time <= mini_break_time).then_some(due_time),
+        LongBreakCollision::Defer => (due_time <= mini_break_time).then_some(mini_break_time),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -743,7 +1912,7 @@ mod tests {
     use crate::core::schedule::{MiniBreakSettings, ScheduleSettings};
     use crate::scheduler::test_helpers::*;
 
-    use chrono::Weekday;
+    use chrono::{Local, Weekday};
 
     mod get_active_schedule_tests {
         use super::*;
@@ -752,7 +1921,7 @@ mod tests {
         #[test]
         fn returns_schedule_within_time_range() {
             let mut config = AppConfig::default();
-            config.schedules[0].time_range = time_range(9, 0, 17, 0);
+            config.schedules[0].time_ranges = vec![time_range(9, 0, 17, 0)];
             config.schedules[0].days_of_week = workdays();
             config.schedules[0].enabled = true;
 
@@ -766,7 +1935,7 @@ mod tests {
         #[test]
         fn returns_none_outside_time_range() {
             let mut config = AppConfig::default();
-            config.schedules[0].time_range = time_range(9, 0, 17, 0);
+            config.schedules[0].time_ranges = vec![time_range(9, 0, 17, 0)];
             config.schedules[0].days_of_week = workdays();
             config.schedules[0].enabled = true;
 
@@ -780,7 +1949,7 @@ mod tests {
         #[test]
         fn returns_none_on_non_working_day() {
             let mut config = AppConfig::default();
-            config.schedules[0].time_range = time_range(9, 0, 17, 0);
+            config.schedules[0].time_ranges = vec![time_range(9, 0, 17, 0)];
             config.schedules[0].days_of_week = workdays();
             config.schedules[0].enabled = true;
 
@@ -794,7 +1963,7 @@ mod tests {
         #[test]
         fn ignores_disabled_schedules() {
             let mut config = AppConfig::default();
-            config.schedules[0].time_range = time_range(9, 0, 17, 0);
+            config.schedules[0].time_ranges = vec![time_range(9, 0, 17, 0)];
             config.schedules[0].days_of_week = workdays();
             config.schedules[0].enabled = false;
 
@@ -817,7 +1986,7 @@ mod tests {
             config.schedules[0].notification_before_s = 10;
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             let break_info = result.unwrap();
             let expected_time = now + duration_s(60);
@@ -833,7 +2002,7 @@ mod tests {
             let now = Utc::now();
             let last_break = now - duration_s(30);
 
-            let result = calculate_next_break_pure(&config, now, 0, Some(last_break));
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, Some(last_break));
 
             let break_info = result.unwrap();
             let expected_time = last_break + duration_s(60);
@@ -849,14 +2018,14 @@ mod tests {
             let now = Utc::now();
 
             // Counter is 3 - should schedule mini break
-            let result = calculate_next_break_pure(&config, now, 3, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 3, 0, None);
             assert!(matches!(
                 result.unwrap().event,
                 SchedulerEvent::MiniBreak(_)
             ));
 
             // Counter is 4 - should schedule long break
-            let result = calculate_next_break_pure(&config, now, 4, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 4, 0, None);
             assert!(matches!(
                 result.unwrap().event,
                 SchedulerEvent::LongBreak(_)
@@ -870,7 +2039,7 @@ mod tests {
             config.schedules[0].mini_breaks.base.enabled = false;
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             assert!(result.is_none());
         }
@@ -878,13 +2047,13 @@ mod tests {
         #[test]
         fn returns_none_outside_active_schedule() {
             let mut config = AppConfig::default();
-            config.schedules[0].time_range = time_range(9, 0, 17, 0);
+            config.schedules[0].time_ranges = vec![time_range(9, 0, 17, 0)];
             config.schedules[0].days_of_week = workdays();
 
             // Saturday at 10:00
             let now = test_datetime(2025, 9, 6, 10, 0, 0);
 
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
             assert!(result.is_none());
         }
 
@@ -896,7 +2065,7 @@ mod tests {
             config.schedules[0].notification_before_s = 10;
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             let break_info = result.unwrap();
 
@@ -913,7 +2082,7 @@ mod tests {
             config.schedules[0].notification_before_s = 0;
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             let break_info = result.unwrap();
             assert!(break_info.notification_time.is_none());
@@ -927,7 +2096,7 @@ mod tests {
             config.schedules[0].notification_before_s = 30;
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             // Notification time would be before now, so should be omitted
             let break_info = result.unwrap();
@@ -939,11 +2108,8 @@ mod tests {
         // ===================================================================
 
         /// Cross-midnight time range support
-        /// NOTE: Current implementation of [`TimeRange::contains()`] does NOT support cross-midnight ranges.
-        /// This test is ignored until the feature is implemented.
-        /// Expected behavior: schedules spanning across midnight (e.g., 22:00-02:00) should work correctly
+        /// Schedules spanning across midnight (e.g., 22:00-02:00) should be active on both sides
         #[test]
-        #[ignore = "TimeRange::contains() does not support cross-midnight ranges yet"]
         fn handles_cross_midnight_time_range() {
             let config = TestConfigBuilder::new()
                 .time_range(time_range(22, 0, 2, 0)) // 22:00-02:00 (night shift)
@@ -951,30 +2117,75 @@ mod tests {
                 .build();
 
             // Test during active time (23:00)
-            let now_23h = test_datetime(2025, 9, 3, 23, 0, 0);
-            let result = calculate_next_break_pure(&config, now_23h, 0, None);
+            let now_23h = test_datetime_with_local(2025, 9, 3, 23, 0, 0);
+            let result = calculate_next_break_pure(&config, now_23h, &Local, 0, 0, None);
             assert!(
                 result.is_some(),
                 "Should schedule break at 23:00 (within range)"
             );
 
             // Test after midnight but still in range (01:00)
-            let now_01h = test_datetime(2025, 9, 4, 1, 0, 0);
-            let result = calculate_next_break_pure(&config, now_01h, 0, None);
+            let now_01h = test_datetime_with_local(2025, 9, 4, 1, 0, 0);
+            let result = calculate_next_break_pure(&config, now_01h, &Local, 0, 0, None);
             assert!(
                 result.is_some(),
                 "Should schedule break at 01:00 (within range)"
             );
 
             // Test outside range (10:00)
-            let now_10h = test_datetime(2025, 9, 3, 10, 0, 0);
-            let result = calculate_next_break_pure(&config, now_10h, 0, None);
+            let now_10h = test_datetime_with_local(2025, 9, 3, 10, 0, 0);
+            let result = calculate_next_break_pure(&config, now_10h, &Local, 0, 0, None);
             assert!(
                 result.is_none(),
                 "Should not schedule break at 10:00 (outside range)"
             );
         }
 
+        /// Cross-midnight weekday attribution
+        /// The part after midnight belongs to the day on which the range started
+        #[test]
+        fn cross_midnight_range_belongs_to_start_day() {
+            let config = TestConfigBuilder::new()
+                .time_range(time_range(22, 0, 6, 0))
+                .days_of_week(vec![Weekday::Fri])
+                .build();
+
+            // 2025-09-05 is a Friday
+            let friday_night = test_datetime_with_local(2025, 9, 5, 23, 0, 0);
+            let saturday_early = test_datetime_with_local(2025, 9, 6, 3, 0, 0);
+            let friday_early = test_datetime_with_local(2025, 9, 5, 3, 0, 0);
+            let saturday_night = test_datetime_with_local(2025, 9, 6, 23, 0, 0);
+
+            assert!(calculate_next_break_pure(&config, friday_night, &Local, 0, 0, None).is_some());
+            assert!(
+                calculate_next_break_pure(&config, saturday_early, &Local, 0, 0, None).is_some()
+            );
+            assert!(
+                calculate_next_break_pure(&config, friday_early, &Local, 0, 0, None).is_none(),
+                "Friday 03:00 belongs to Thursday's range"
+            );
+            assert!(
+                calculate_next_break_pure(&config, saturday_night, &Local, 0, 0, None).is_none()
+            );
+        }
+
+        /// Several time ranges per day
+        /// A lunch gap between two ranges of the same schedule is inactive
+        #[test]
+        fn handles_multiple_time_ranges_per_day() {
+            let config = TestConfigBuilder::new()
+                .time_ranges(vec![time_range(9, 0, 12, 0), time_range(13, 30, 18, 0)])
+                .build();
+
+            let morning = test_datetime_with_local(2025, 9, 3, 10, 0, 0);
+            let lunch = test_datetime_with_local(2025, 9, 3, 12, 45, 0);
+            let afternoon = test_datetime_with_local(2025, 9, 3, 15, 0, 0);
+
+            assert!(calculate_next_break_pure(&config, morning, &Local, 0, 0, None).is_some());
+            assert!(calculate_next_break_pure(&config, lunch, &Local, 0, 0, None).is_none());
+            assert!(calculate_next_break_pure(&config, afternoon, &Local, 0, 0, None).is_some());
+        }
+
         /// Notification time of zero (disabled notifications)
         /// Verifies that when `notification_before_s` = 0, no notification time is calculated
         #[test]
@@ -985,7 +2196,7 @@ mod tests {
                 .build();
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             let break_info = result.unwrap();
             assert!(
@@ -1004,7 +2215,7 @@ mod tests {
                 .build();
 
             let now = Utc::now();
-            let result = calculate_next_break_pure(&config, now, 0, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, None);
 
             let break_info = result.unwrap();
             assert!(
@@ -1021,7 +2232,7 @@ mod tests {
             let schedule1 = ScheduleSettings {
                 name: "Morning".to_string(),
                 enabled: true,
-                time_range: time_range(9, 0, 12, 0),
+                time_ranges: vec![time_range(9, 0, 12, 0)],
                 days_of_week: workdays(),
                 notification_before_s: 10,
                 mini_breaks: MiniBreakSettings {
@@ -1035,7 +2246,7 @@ mod tests {
             let schedule2 = ScheduleSettings {
                 name: "Afternoon".to_string(),
                 enabled: true,
-                time_range: time_range(13, 0, 17, 0),
+                time_ranges: vec![time_range(13, 0, 17, 0)],
                 days_of_week: workdays(),
                 notification_before_s: 5,
                 mini_breaks: MiniBreakSettings {
@@ -1053,7 +2264,7 @@ mod tests {
             // Test at 10:00 local time (should use schedule1)
             let now_morning = test_datetime_with_local(2025, 9, 3, 10, 0, 0);
 
-            let result = calculate_next_break_pure(&config, now_morning, 0, None);
+            let result = calculate_next_break_pure(&config, now_morning, &Local, 0, 0, None);
             let break_info = result.unwrap();
             let break_duration = (break_info.break_time - now_morning).num_seconds();
             // Should be around 1800 seconds (30 minutes) from schedule1
@@ -1062,7 +2273,7 @@ mod tests {
             // Test at 14:00 local time (should use schedule2)
             let now_afternoon = test_datetime_with_local(2025, 9, 3, 14, 0, 0);
 
-            let result = calculate_next_break_pure(&config, now_afternoon, 0, None);
+            let result = calculate_next_break_pure(&config, now_afternoon, &Local, 0, 0, None);
             let break_info = result.unwrap();
             let break_duration = (break_info.break_time - now_afternoon).num_seconds();
             // Should be around 1200 seconds (20 minutes) from schedule2
@@ -1071,7 +2282,7 @@ mod tests {
             // Test at 12:30 local time (between schedules, should be None)
             let now_between = test_datetime_with_local(2025, 9, 3, 12, 30, 0);
 
-            let result = calculate_next_break_pure(&config, now_between, 0, None);
+            let result = calculate_next_break_pure(&config, now_between, &Local, 0, 0, None);
             assert!(
                 result.is_none(),
                 "Should not find a schedule at 12:30 local time"
@@ -1090,7 +2301,7 @@ mod tests {
 
             // Counter = 0,1,2 should give mini breaks
             for counter in 0..=2 {
-                let result = calculate_next_break_pure(&config, now, counter, None);
+                let result = calculate_next_break_pure(&config, now, &Local, counter, 0, None);
                 assert!(
                     matches!(result.unwrap().event, SchedulerEvent::MiniBreak(_)),
                     "Counter {counter} should trigger mini break"
@@ -1098,7 +2309,7 @@ mod tests {
             }
 
             // Counter = 3 should give long break
-            let result = calculate_next_break_pure(&config, now, 3, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 3, 0, None);
             assert!(
                 matches!(result.unwrap().event, SchedulerEvent::LongBreak(_)),
                 "Counter 3 should trigger long break"
@@ -1117,11 +2328,126 @@ mod tests {
             let now = Utc::now();
 
             // Even with counter >= threshold, should still give mini break
-            let result = calculate_next_break_pure(&config, now, 5, None);
+            let result = calculate_next_break_pure(&config, now, &Local, 5, 0, None);
             assert!(
                 matches!(result.unwrap().event, SchedulerEvent::MiniBreak(_)),
                 "Should trigger mini break when long breaks are disabled"
             );
         }
+
+        #[test]
+        fn pomodoro_schedule_uses_focus_length() {
+            let schedule = pomodoro_schedule(1500, 4);
+            let now = Utc::now();
+            let last_break = now - duration_s(600);
+
+            // Never active by time of day
+            let config = TestConfigBuilder::new()
+                .schedule_enabled(false)
+                .add_schedule(schedule.clone())
+                .build();
+            assert!(calculate_next_break_pure(&config, now, &Local, 0, 0, None).is_none());
+
+            let result = calculate_break_for_schedule(&schedule, now, 0, 0, Some(last_break));
+            assert_eq!(result.unwrap().break_time, last_break + duration_s(1500));
+
+            let result = calculate_break_for_schedule(&schedule, now, 4, 0, Some(last_break));
+            assert!(matches!(
+                result.unwrap().event,
+                SchedulerEvent::LongBreak(_)
+            ));
+        }
+
+        /// Mini breaks every 20min, a long break every hour of work
+        fn hourly_long_break_config(collision: LongBreakCollision) -> AppConfig {
+            TestConfigBuilder::new()
+                .mini_break_interval_s(1200)
+                .long_break_interval_s(3600, collision)
+                .build()
+        }
+
+        #[test]
+        fn time_based_long_break_ignores_mini_break_counter() {
+            let config = hourly_long_break_config(LongBreakCollision::Merge);
+            let now = Utc::now();
+
+            let result = calculate_next_break_pure(&config, now, &Local, 10, 1200, Some(now));
+            assert!(matches!(
+                result.unwrap().event,
+                SchedulerEvent::MiniBreak(_)
+            ));
+
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 2400, Some(now));
+            let break_info = result.unwrap();
+            assert!(matches!(break_info.event, SchedulerEvent::LongBreak(_)));
+            assert_eq!(break_info.break_time, now + duration_s(1200));
+        }
+
+        #[test]
+        fn merge_takes_long_break_at_closest_mini_break() {
+            let config = hourly_long_break_config(LongBreakCollision::Merge);
+            let now = Utc::now();
+
+            // Due 10min after the mini break: taken in its place
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 2000, Some(now));
+            let break_info = result.unwrap();
+            assert!(matches!(break_info.event, SchedulerEvent::LongBreak(_)));
+            assert_eq!(break_info.break_time, now + duration_s(1200));
+
+            // Due 15min after the mini break: the next mini break is closer
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 1500, Some(now));
+            assert!(matches!(
+                result.unwrap().event,
+                SchedulerEvent::MiniBreak(_)
+            ));
+        }
+
+        #[test]
+        fn replace_takes_long_break_on_time() {
+            let config = hourly_long_break_config(LongBreakCollision::Replace);
+            let now = Utc::now();
+
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 2500, Some(now));
+            let break_info = result.unwrap();
+            assert!(matches!(break_info.event, SchedulerEvent::LongBreak(_)));
+            assert_eq!(break_info.break_time, now + duration_s(1100));
+
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 2000, Some(now));
+            assert!(matches!(
+                result.unwrap().event,
+                SchedulerEvent::MiniBreak(_)
+            ));
+        }
+
+        #[test]
+        fn defer_takes_long_break_at_next_mini_break() {
+            let config = hourly_long_break_config(LongBreakCollision::Defer);
+            let now = Utc::now();
+
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 2000, Some(now));
+            assert!(matches!(
+                result.unwrap().event,
+                SchedulerEvent::MiniBreak(_)
+            ));
+
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 2500, Some(now));
+            let break_info = result.unwrap();
+            assert!(matches!(break_info.event, SchedulerEvent::LongBreak(_)));
+            assert_eq!(break_info.break_time, now + duration_s(1200));
+        }
+
+        #[test]
+        fn time_based_long_break_without_mini_breaks() {
+            let config = TestConfigBuilder::new()
+                .mini_breaks_enabled(false)
+                .long_break_interval_s(3600, LongBreakCollision::Merge)
+                .build();
+            let now = Utc::now();
+
+            let result = calculate_next_break_pure(&config, now, &Local, 0, 0, Some(now));
+            let break_info = result.unwrap();
+            assert!(matches!(break_info.event, SchedulerEvent::LongBreak(_)));
+            assert_eq!(break_info.break_time, now + duration_s(3600));
+        }
     }
 }
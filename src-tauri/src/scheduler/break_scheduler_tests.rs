@@ -11,10 +11,30 @@
 //! - **Configuration**: Config updates, validation
 //! - **Edge Cases**: Boundary conditions, extreme values
 //! - **Error Scenarios**: Window failures, missing events, system time changes
-
+//! - **Virtual Clock**: Time zones and wall-clock jumps with a fake clock
+//! - **Pomodoro**: User-started sessions of focus blocks and rests
+//! - **Idle Credit**: Breaks taken away from the computer while idle
+//! - **Pause Policies**: How the interval continues after each kind of pause
+//! - **Grace Window**: Due breaks waiting for a pause in typing
+//! - **Skip Escalation**: Consequences of skipping breaks repeatedly
+//! - **Break Verification**: Input detected while a break is in progress
+//! - **Watchdog**: Break prompts that never report back
+
+use chrono_tz::Europe::Berlin;
+use tauri::Manager;
 use tokio::sync::mpsc;
 
-use crate::scheduler::models::{Command, PauseReason, SchedulerEvent};
+use crate::config::{BreakVerification, PausePolicy, SharedConfig};
+use crate::core::history::{HistoryStore, PromptOutcome};
+use crate::core::schedule::{LongBreakCollision, ScheduleSettings, SkipEscalation};
+use crate::scheduler::clock::{Clock, FakeClock};
+use crate::scheduler::event_emitter::TestEventEmitter;
+use crate::scheduler::models::{
+    BreakActivity, BreakCredit, Command, CommandReply, PauseDuration, PauseReason, Rejection,
+    Responder, SchedulerEvent, SchedulerPhase,
+};
+use crate::scheduler::progress::{ProgressStore, SchedulerProgress};
+use crate::scheduler::shared_state::SharedState;
 use crate::scheduler::test_helpers::state_machine::*;
 use crate::scheduler::test_helpers::*;
 use crate::{
@@ -48,7 +68,10 @@ async fn test_automatic_startup() {
     advance_time_and_yield(duration_ms(200)).await;
 
     // Verify scheduler automatically started
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     let status = get_latest_status(&emitter);
@@ -119,7 +142,10 @@ async fn test_complete_break_cycle() {
     advance_time_and_yield(duration_s(1)).await;
 
     // Verify next break scheduled
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     let status = get_latest_status(&emitter);
@@ -178,7 +204,10 @@ async fn test_long_break_trigger() {
             .unwrap();
         advance_time_and_yield(duration_s(1)).await;
 
-        cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+        cmd_tx
+            .send(Command::RequestBreakStatus(None))
+            .await
+            .unwrap();
         advance_time_and_yield(duration_ms(200)).await;
         let status = get_latest_status(&emitter);
         assert_eq!(status.mini_break_counter, i);
@@ -204,7 +233,10 @@ async fn test_long_break_trigger() {
         .unwrap();
     advance_time_and_yield(duration_s(1)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     let status = get_latest_status(&emitter);
     assert_eq!(status.mini_break_counter, 0, "Counter should reset");
 
@@ -214,6 +246,115 @@ async fn test_long_break_trigger() {
     task.await.unwrap();
 }
 
+/// **T1.4: Time-Based Long Break**
+///
+/// With `interval_s` set, the long break falls due after that much work, no
+/// matter how many mini breaks were taken. Skipped mini breaks count too.
+#[tokio::test(start_paused = true)]
+async fn test_time_based_long_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(1200)
+        .long_break_after_mini_breaks(1)
+        .long_break_interval_s(3600, LongBreakCollision::Merge)
+        .build();
+    let mini_id = config.schedules[0].mini_breaks.base.id;
+    let long_id = config.schedules[0].long_breaks.base.id;
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+
+    for (expected, skip) in [
+        (SchedulerEvent::MiniBreak(mini_id), false),
+        (SchedulerEvent::MiniBreak(mini_id), true),
+        (SchedulerEvent::LongBreak(long_id), false),
+        (SchedulerEvent::MiniBreak(mini_id), false),
+    ] {
+        emitter.clear();
+        advance_time_and_yield(duration_s(1200)).await;
+        assert_eq!(latest_break_event(&emitter), expected);
+
+        let command = if skip {
+            Command::SkipBreak(None)
+        } else {
+            Command::PromptFinished(expected)
+        };
+        cmd_tx.send(command).await.unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+    }
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **T1.5: Active-Time Scheduling**
+///
+/// A schedule counting active time defers its break by the idle gaps in the
+/// activity samples, and reports its progress in the status.
+#[tokio::test(start_paused = true)]
+async fn test_active_time_defers_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(600)
+        .count_active_time()
+        .build();
+    let mini_id = config.schedules[0].mini_breaks.base.id;
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, app) =
+        create_test_break_scheduler_with_clock(config, clock.clone());
+    let shared_state = app.state::<SharedState>().inner().clone();
+    let record = |idle_s: i64| {
+        shared_state
+            .write()
+            .record_activity(clock.now(), chrono::Duration::seconds(idle_s));
+    };
+    record(0);
+
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+    advance_time_and_yield(duration_ms(200)).await;
+
+    // 300s of work, 100s away from the keyboard, then another 198s of work
+    advance_time_and_yield(duration_s(300)).await;
+    record(0);
+    advance_time_and_yield(duration_s(100)).await;
+    record(100);
+    advance_time_and_yield(duration_s(198)).await;
+    record(0);
+
+    // The wall-clock interval has elapsed, but only 498s were active
+    emitter.clear();
+    advance_time_and_yield(duration_s(3)).await;
+    assert!(!emitter.has_event("scheduler-event"));
+    let status = get_latest_status(&emitter);
+    let active_time = status.active_time.unwrap();
+    assert_eq!((active_time.active_s, active_time.target_s), (498, 600));
+    assert_eq!(status.next_event.unwrap().seconds_until, 102);
+
+    advance_time_and_yield(duration_s(100)).await;
+    record(0);
+    advance_time_and_yield(duration_s(3)).await;
+    assert_eq!(
+        latest_break_event(&emitter),
+        SchedulerEvent::MiniBreak(mini_id)
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
 // ============================================================================
 // Section 2: User Interactions
 // ============================================================================
@@ -236,7 +377,10 @@ async fn test_manual_pause_resume() {
     emitter.clear();
 
     // Verify initially running
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status = get_latest_status(&emitter);
     assert!(!status.paused);
@@ -245,12 +389,15 @@ async fn test_manual_pause_resume() {
 
     // User pauses
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status = get_latest_status(&emitter);
     assert!(status.paused, "Should be paused");
@@ -259,12 +406,15 @@ async fn test_manual_pause_resume() {
 
     // User resumes
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status = get_latest_status(&emitter);
     assert!(!status.paused, "Should be running");
@@ -299,7 +449,10 @@ async fn test_postpone_break() {
     // Wait close to break time
     advance_time_and_yield(duration_s(55)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status_before = get_latest_status(&emitter);
     let seconds_before = status_before.next_event.unwrap().seconds_until;
@@ -307,10 +460,13 @@ async fn test_postpone_break() {
     emitter.clear();
 
     // Postpone
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
     advance_time_and_yield(duration_s(1)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status_after = get_latest_status(&emitter);
     let seconds_after = status_after.next_event.unwrap().seconds_until;
@@ -348,8 +504,11 @@ async fn test_postpone_limit() {
     emitter.clear();
 
     // Postpone once to get baseline (this transitions from InBreak to WaitingForBreak)
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status_before = get_latest_status(&emitter);
     let seconds_before = status_before.next_event.unwrap().seconds_until;
@@ -357,10 +516,13 @@ async fn test_postpone_limit() {
     emitter.clear();
 
     // Postpone once more (limit reached)
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
     emitter.clear();
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status = get_latest_status(&emitter);
     let seconds_after = status.next_event.unwrap().seconds_until;
@@ -369,8 +531,11 @@ async fn test_postpone_limit() {
     emitter.clear();
 
     // Third should fail
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status_after = get_latest_status(&emitter);
     let seconds_after = status_after.next_event.unwrap().seconds_until;
@@ -408,10 +573,13 @@ async fn test_skip_break() {
     advance_time_and_yield(duration_s(30)).await;
 
     // Skip
-    cmd_tx.send(Command::SkipBreak).await.unwrap();
+    cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
     advance_time_and_yield(duration_s(1)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     let status = get_latest_status(&emitter);
@@ -446,9 +614,10 @@ async fn test_manual_trigger() {
 
     // Trigger manually
     cmd_tx
-        .send(Command::TriggerEvent(SchedulerEvent::MiniBreak(
-            BreakId::new(),
-        )))
+        .send(Command::TriggerEvent(
+            SchedulerEvent::MiniBreak(BreakId::new()),
+            None,
+        ))
         .await
         .unwrap();
     advance_time_and_yield(duration_s(1)).await;
@@ -465,6 +634,176 @@ async fn test_manual_trigger() {
     task.await.unwrap();
 }
 
+/// **T2.6: Command Replies**
+///
+/// Commands sent with a responder are answered with the resulting status, or
+/// with the reason they were refused.
+#[tokio::test(start_paused = true)]
+async fn test_command_replies() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .postpone_settings(1, 30)
+        .build();
+
+    let (mut scheduler, _emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let (responder, reply_rx) = Responder::new();
+    cmd_tx
+        .send(Command::PostponeBreak(Some(responder)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    let CommandReply::Accepted(Some(status)) = reply_rx.await.unwrap() else {
+        panic!("First postpone should be accepted with the new status");
+    };
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 90, 1);
+
+    let (responder, reply_rx) = Responder::new();
+    cmd_tx
+        .send(Command::PostponeBreak(Some(responder)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        CommandReply::Rejected(Rejection::PostponeLimitReached)
+    ));
+
+    let (responder, reply_rx) = Responder::new();
+    cmd_tx
+        .send(Command::StopPomodoro(Some(responder)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        CommandReply::Rejected(Rejection::NoPomodoroSession)
+    ));
+
+    // Timed pauses are handled by the manager, not the break scheduler
+    let (responder, reply_rx) = Responder::new();
+    cmd_tx
+        .send(Command::PauseFor(
+            PauseDuration::Seconds(600),
+            Some(responder),
+        ))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        CommandReply::Rejected(Rejection::Unsupported)
+    ));
+
+    // Skipping or triggering a break is refused while paused
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    let (responder, reply_rx) = Responder::new();
+    cmd_tx
+        .send(Command::SkipBreak(Some(responder)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        CommandReply::Rejected(Rejection::Paused)
+    ));
+
+    let (responder, reply_rx) = Responder::new();
+    cmd_tx
+        .send(Command::TriggerEvent(
+            SchedulerEvent::MiniBreak(BreakId::new()),
+            Some(responder),
+        ))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        CommandReply::Rejected(Rejection::Paused)
+    ));
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **T2.7: Detailed Status**
+///
+/// The status tells which schedule is active, what the scheduler is doing, how
+/// often the break was postponed and why the scheduler is paused.
+#[tokio::test(start_paused = true)]
+async fn test_detailed_status() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .mini_break_duration_s(20)
+        .postpone_settings(2, 30)
+        .build();
+
+    let (mut scheduler, emitter, shutdown_tx, app) = create_test_break_scheduler(config);
+    let shared_state = app.state::<SharedState>().inner().clone();
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert_eq!(status.schedule_name.as_deref(), Some("Test Schedule"));
+    assert_eq!(status.phase, SchedulerPhase::WaitingForBreak);
+    assert!(status.pause_reasons.is_empty());
+    assert!(status.next_attention.is_none());
+
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert_eq!(get_latest_status(&emitter).postpone_count, 1);
+
+    advance_time_and_yield(duration_s(95)).await;
+    let status = get_latest_status(&emitter);
+    let SchedulerPhase::InBreak(remaining_s) = status.phase else {
+        panic!("Break should be in progress, got {:?}", status.phase);
+    };
+    assert_duration_near(remaining_s.into(), 20, 1);
+    assert_eq!(status.postpone_count, 1);
+
+    shared_state.write().add_pause_reason(PauseReason::Dnd);
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    shared_state.write().add_pause_reason(PauseReason::UserIdle);
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert!(status.paused);
+    assert_eq!(status.phase, SchedulerPhase::Paused);
+    assert_eq!(
+        status.pause_reasons,
+        vec![PauseReason::UserIdle, PauseReason::Dnd]
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
 // ============================================================================
 // Section 3: Configuration Updates
 // ============================================================================
@@ -533,7 +872,10 @@ async fn test_request_status() {
     advance_time_and_yield(duration_ms(200)).await;
     emitter.clear();
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     assert!(emitter.has_event("scheduler-status"));
@@ -547,45 +889,53 @@ async fn test_request_status() {
     task.await.unwrap();
 }
 
-// ============================================================================
-// Section 4: Edge Cases and Error Scenarios
-// ============================================================================
-
-/// **E1: Commands During `InBreak` State**
+/// **T3.3: Unrelated Configuration Changes Keep the Timers**
 ///
-/// Verify scheduler handles commands correctly while in break.
+/// Saving settings that do not affect when breaks are due keeps the postpone
+/// count, the break time and the notification already sent.
 #[tokio::test(start_paused = true)]
-async fn test_commands_during_break() {
+async fn test_unrelated_config_update_keeps_timers() {
     let config = TestConfigBuilder::new()
-        .mini_break_interval_s(30)
-        .mini_break_duration_s(10)
-        .notification_before_s(0)
-        .postpone_settings(2, 20)
+        .mini_break_interval_s(60)
+        .notification_before_s(10)
+        .postpone_settings(2, 30)
         .build();
 
-    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config.clone());
     let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
     let task = tokio::spawn(async move {
         scheduler.run(cmd_rx).await;
     });
-
     advance_time_and_yield(duration_ms(200)).await;
-    emitter.clear();
 
-    // Trigger break
-    advance_time_and_yield(duration_s(35)).await;
-    emitter.clear();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
 
-    // Try postpone during break (should close window and reschedule)
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
-    advance_time_and_yield(duration_s(1)).await;
+    let mut unrelated = config.clone();
+    unrelated.theme_mode = "dark".to_string();
+    unrelated.schedules[0].mini_breaks.base.max_postpone_count = 5;
+    cmd_tx
+        .send(Command::UpdateConfig(unrelated.clone()))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    let status = get_latest_status(&emitter);
+    assert_eq!(status.postpone_count, 1);
+    assert_eq!(status.phase, SchedulerPhase::WaitingForBreak);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 90, 1);
+
+    // A new interval reschedules from the last break
+    let mut related = unrelated;
+    related.schedules[0].mini_breaks.interval_s = 120;
+    cmd_tx.send(Command::UpdateConfig(related)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     let status = get_latest_status(&emitter);
-    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 20, 1);
+    assert_eq!(status.postpone_count, 0);
+    assert_eq!(status.phase, SchedulerPhase::WaitingForNotification);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 120, 1);
 
     // Cleanup
     drop(cmd_tx);
@@ -593,33 +943,43 @@ async fn test_commands_during_break() {
     task.await.unwrap();
 }
 
-/// **E2: Extreme Configuration - Very Short Interval**
+/// **T3.4: Configuration Changes During a Pause**
 ///
-/// Verify scheduler handles extremely short intervals.
+/// A paused scheduler stays paused when the timing changes, and uses the new
+/// timing once resumed.
 #[tokio::test(start_paused = true)]
-async fn test_extreme_short_interval() {
-    let config = TestConfigBuilder::new()
-        .mini_break_interval_s(5)
-        .mini_break_duration_s(2)
-        .notification_before_s(0)
-        .build();
+async fn test_config_update_while_paused() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
 
-    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config.clone());
     let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
     let task = tokio::spawn(async move {
         scheduler.run(cmd_rx).await;
     });
+    advance_time_and_yield(duration_ms(200)).await;
+
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
 
+    let mut related = config;
+    related.schedules[0].mini_breaks.interval_s = 120;
+    cmd_tx.send(Command::UpdateConfig(related)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
-    emitter.clear();
+    assert_eq!(get_latest_status(&emitter).phase, SchedulerPhase::Paused);
 
-    // Should schedule break in 5 seconds
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::Resume(PauseReason::Manual, None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     let status = get_latest_status(&emitter);
-    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 5, 1);
+    assert!(!status.paused);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 120, 1);
 
     // Cleanup
     drop(cmd_tx);
@@ -627,38 +987,184 @@ async fn test_extreme_short_interval() {
     task.await.unwrap();
 }
 
-/// **E3: Multiple Pause Reasons**
-///
-/// Note: Multiple pause reason coordination is handled by `SchedulerManager`,
-/// not by individual `BreakScheduler` instances. `BreakScheduler` itself only
-/// tracks internal state transitions.
+/// **T3.5: Reordered Schedules Keep the Timers**
 ///
-/// This test verifies that `BreakScheduler` correctly responds to Pause/Resume
-/// commands from its perspective. The logic of "pause only when first reason
-/// added" and "resume only when last reason removed" is in `SchedulerManager`
-/// and tested in manager tests.
+/// Schedules are compared by id, so reordering them keeps the timers while
+/// replacing one with another is a timing change.
 #[tokio::test(start_paused = true)]
-async fn test_pause_resume_cycle() {
-    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+async fn test_reordered_schedules_keep_timers() {
+    let mut config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .postpone_settings(2, 30)
+        .build();
+    config.schedules.push(ScheduleSettings {
+        enabled: false,
+        ..ScheduleSettings::default()
+    });
 
-    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config.clone());
     let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
     let task = tokio::spawn(async move {
         scheduler.run(cmd_rx).await;
     });
+    advance_time_and_yield(duration_ms(200)).await;
 
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
-    emitter.clear();
 
-    // Pause
+    let mut reordered = config;
+    reordered.schedules.reverse();
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::UpdateConfig(reordered.clone()))
         .await
         .unwrap();
-    advance_time_and_yield(duration_ms(100)).await;
+    advance_time_and_yield(duration_ms(200)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    let status = get_latest_status(&emitter);
+    assert_eq!(status.postpone_count, 1);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 90, 1);
+
+    // Same number of schedules, but a different one
+    let mut replaced = reordered;
+    replaced.schedules[0] = ScheduleSettings {
+        enabled: false,
+        ..ScheduleSettings::default()
+    };
+    cmd_tx.send(Command::UpdateConfig(replaced)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert_eq!(status.postpone_count, 0);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 60, 1);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 4: Edge Cases and Error Scenarios
+// ============================================================================
+
+/// **E1: Commands During `InBreak` State**
+///
+/// Verify scheduler handles commands correctly while in break.
+#[tokio::test(start_paused = true)]
+async fn test_commands_during_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(30)
+        .mini_break_duration_s(10)
+        .notification_before_s(0)
+        .postpone_settings(2, 20)
+        .build();
+
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    emitter.clear();
+
+    // Trigger break
+    advance_time_and_yield(duration_s(35)).await;
+    emitter.clear();
+
+    // Try postpone during break (should close window and reschedule)
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+    advance_time_and_yield(duration_s(1)).await;
+
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 20, 1);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **E2: Extreme Configuration - Very Short Interval**
+///
+/// Verify scheduler handles extremely short intervals.
+#[tokio::test(start_paused = true)]
+async fn test_extreme_short_interval() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(5)
+        .mini_break_duration_s(2)
+        .notification_before_s(0)
+        .build();
+
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    emitter.clear();
+
+    // Should schedule break in 5 seconds
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 5, 1);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **E3: Multiple Pause Reasons**
+///
+/// Note: Multiple pause reason coordination is handled by `SchedulerManager`,
+/// not by individual `BreakScheduler` instances. `BreakScheduler` itself only
+/// tracks internal state transitions.
+///
+/// This test verifies that `BreakScheduler` correctly responds to Pause/Resume
+/// commands from its perspective. The logic of "pause only when first reason
+/// added" and "resume only when last reason removed" is in `SchedulerManager`
+/// and tested in manager tests.
+#[tokio::test(start_paused = true)]
+async fn test_pause_resume_cycle() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    emitter.clear();
+
+    // Pause
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(100)).await;
+
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status = get_latest_status(&emitter);
     assert!(status.paused, "Should be paused");
@@ -667,12 +1173,15 @@ async fn test_pause_resume_cycle() {
 
     // Resume
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
 
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
     let status = get_latest_status(&emitter);
     assert!(!status.paused, "Should resume");
@@ -702,7 +1211,10 @@ async fn test_rapid_commands() {
 
     // Send rapid commands
     for _ in 0..10 {
-        cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+        cmd_tx
+            .send(Command::RequestBreakStatus(None))
+            .await
+            .unwrap();
     }
 
     advance_time_and_yield(duration_ms(500)).await;
@@ -748,7 +1260,7 @@ async fn test_inbreak_pause_manual_cleans_session() {
 
     // User manually pauses during break
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(500)).await;
@@ -789,7 +1301,7 @@ async fn test_inbreak_skip_cleans_session() {
     assert!(env.shared_state.read().in_any_session());
 
     // User skips break
-    cmd_tx.send(Command::SkipBreak).await.unwrap();
+    cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
     advance_time_and_yield(duration_ms(500)).await;
 
     // Session must be cleaned
@@ -822,7 +1334,7 @@ async fn test_inbreak_postpone_cleans_session() {
     assert!(env.shared_state.read().in_any_session());
 
     // User postpones break
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
     advance_time_and_yield(duration_ms(500)).await;
 
     // Session must be cleaned
@@ -856,7 +1368,7 @@ async fn test_session_recovery_after_pause_resume() {
 
     // Pause during break
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(500)).await;
@@ -864,7 +1376,7 @@ async fn test_session_recovery_after_pause_resume() {
 
     // Resume
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(500)).await;
@@ -882,3 +1394,1338 @@ async fn test_session_recovery_after_pause_resume() {
     drop(cmd_tx);
     drop(env.shutdown_tx);
 }
+
+// ============================================================================
+// Section 6: Break History
+// ============================================================================
+
+/// Collect recorded outcomes from the app's history store
+fn recorded_outcomes(app: &tauri::AppHandle<tauri::test::MockRuntime>) -> Vec<PromptOutcome> {
+    app.state::<HistoryStore>()
+        .query(
+            chrono::DateTime::<chrono::Utc>::MIN_UTC,
+            chrono::DateTime::<chrono::Utc>::MAX_UTC,
+        )
+        .into_iter()
+        .map(|entry| entry.outcome)
+        .collect()
+}
+
+/// **H1: Completed Break Is Recorded**
+#[tokio::test(start_paused = true)]
+async fn test_history_records_completed_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .mini_break_duration_s(20)
+        .notification_before_s(0)
+        .build();
+
+    let (mut scheduler, emitter, shutdown_tx, app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(60)).await;
+
+    let break_events = emitter.get_events_by_name("scheduler-event");
+    let break_event: SchedulerEvent =
+        serde_json::from_value(break_events[0].clone()).expect("Should parse");
+
+    cmd_tx
+        .send(Command::PromptFinished(break_event))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_s(1)).await;
+
+    assert_eq!(
+        recorded_outcomes(&app),
+        vec![PromptOutcome::Started, PromptOutcome::Completed]
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **H2: Skip, Postpone and Pause Are Recorded**
+#[tokio::test(start_paused = true)]
+async fn test_history_records_interactions() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .mini_break_duration_s(20)
+        .notification_before_s(0)
+        .build();
+
+    let (mut scheduler, _emitter, shutdown_tx, app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+
+    // Postpone before the break starts
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    // Skip the postponed break before it starts
+    cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    // Let the next break start, then pause during it
+    advance_time_and_yield(duration_s(60)).await;
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    assert_eq!(
+        recorded_outcomes(&app),
+        vec![
+            PromptOutcome::Postponed,
+            PromptOutcome::Skipped,
+            PromptOutcome::Started,
+            PromptOutcome::Cancelled,
+        ]
+    );
+
+    let entries = app.state::<HistoryStore>().query(
+        chrono::DateTime::<chrono::Utc>::MIN_UTC,
+        chrono::DateTime::<chrono::Utc>::MAX_UTC,
+    );
+    assert_eq!(
+        entries[1].postpone_count, 1,
+        "Skipped break was postponed once"
+    );
+    assert!(entries.iter().all(|e| e.source_name.is_some()));
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 7: Progress Persistence
+// ============================================================================
+
+/// **P1: Fresh Progress Is Restored**
+///
+/// A restart shortly after the last run keeps the long-break cycle.
+#[tokio::test(start_paused = true)]
+async fn test_restore_fresh_progress() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .long_break_after_mini_breaks(4)
+        .notification_before_s(0)
+        .build();
+
+    let (mut scheduler, emitter, shutdown_tx, app) = create_test_break_scheduler(config);
+    let now = chrono::Utc::now();
+    app.state::<ProgressStore>().save(SchedulerProgress {
+        mini_break_counter: 4,
+        work_since_long_break_s: 1800,
+        last_break_time: Some(now - duration_s(10)),
+        saved_at: now - duration_s(30),
+    });
+
+    scheduler.restore_progress().await;
+    assert_eq!(scheduler.get_mini_break_counter(), 4);
+    assert_eq!(scheduler.get_work_since_long_break_s(), 1800);
+    assert_eq!(scheduler.get_last_break_time(), Some(now - duration_s(10)));
+
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(60)).await;
+
+    let break_events = emitter.get_events_by_name("scheduler-event");
+    let break_event: SchedulerEvent =
+        serde_json::from_value(break_events[0].clone()).expect("Should parse");
+    assert!(
+        matches!(break_event, SchedulerEvent::LongBreak(_)),
+        "Four mini breaks were taken before the restart, so a long break is due"
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **P2: Stale Progress Is Discarded**
+///
+/// Progress older than the idle threshold starts a fresh cycle.
+#[tokio::test(start_paused = true)]
+async fn test_discard_stale_progress() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+
+    let (mut scheduler, _emitter, _shutdown_tx, app) = create_test_break_scheduler(config);
+    let now = chrono::Utc::now();
+    app.state::<ProgressStore>().save(SchedulerProgress {
+        mini_break_counter: 3,
+        work_since_long_break_s: 0,
+        last_break_time: Some(now - duration_h(1)),
+        saved_at: now - duration_h(1),
+    });
+
+    scheduler.restore_progress().await;
+    assert_eq!(scheduler.get_mini_break_counter(), 0);
+    assert_eq!(scheduler.get_last_break_time(), None);
+}
+
+/// **P3: Progress Is Saved After a Break**
+#[tokio::test(start_paused = true)]
+async fn test_progress_saved_after_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .mini_break_duration_s(20)
+        .notification_before_s(0)
+        .build();
+
+    let (mut scheduler, emitter, shutdown_tx, app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(60)).await;
+
+    let break_events = emitter.get_events_by_name("scheduler-event");
+    let break_event: SchedulerEvent =
+        serde_json::from_value(break_events[0].clone()).expect("Should parse");
+    cmd_tx
+        .send(Command::PromptFinished(break_event))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_s(1)).await;
+
+    let progress = app
+        .state::<ProgressStore>()
+        .load()
+        .expect("Progress should be saved");
+    assert_eq!(progress.mini_break_counter, 1);
+    assert!(progress.last_break_time.is_some());
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 8: System Suspend/Resume
+// ============================================================================
+
+/// Run a scheduler due for a long break, report a resume after `slept_s`
+/// and return the first break event
+async fn first_break_after_resume(
+    credit_sleep_as_long_break: bool,
+    slept_s: u64,
+) -> SchedulerEvent {
+    let mut config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .long_break_after_mini_breaks(4)
+        .long_break_duration_s(300)
+        .notification_before_s(0)
+        .build();
+    config.advanced.credit_sleep_as_long_break = credit_sleep_as_long_break;
+
+    let (mut scheduler, emitter, shutdown_tx, app) = create_test_break_scheduler(config);
+    let now = chrono::Utc::now();
+    app.state::<ProgressStore>().save(SchedulerProgress {
+        mini_break_counter: 4,
+        work_since_long_break_s: 0,
+        last_break_time: None,
+        saved_at: now,
+    });
+    scheduler.restore_progress().await;
+
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    cmd_tx.send(Command::SystemResumed(slept_s)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(60)).await;
+
+    let break_events = emitter.get_events_by_name("scheduler-event");
+    let break_event: SchedulerEvent =
+        serde_json::from_value(break_events[0].clone()).expect("Should parse");
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+
+    break_event
+}
+
+/// **S1: Long Suspend Is Credited as a Long Break**
+#[tokio::test(start_paused = true)]
+async fn test_resume_credits_long_break() {
+    let event = first_break_after_resume(true, 600).await;
+    assert!(
+        matches!(event, SchedulerEvent::MiniBreak(_)),
+        "Sleeping longer than a long break should complete the long break cycle"
+    );
+}
+
+/// **S2: Suspend Is Not Credited When Disabled or Too Short**
+#[tokio::test(start_paused = true)]
+async fn test_resume_keeps_long_break_due() {
+    let event = first_break_after_resume(false, 600).await;
+    assert!(
+        matches!(event, SchedulerEvent::LongBreak(_)),
+        "Crediting is opt-in, the long break is still due"
+    );
+
+    let event = first_break_after_resume(true, 120).await;
+    assert!(
+        matches!(event, SchedulerEvent::LongBreak(_)),
+        "Sleeping shorter than a long break does not replace it"
+    );
+}
+
+/// **S3: Resume During a Break Is Ignored**
+///
+/// The resume is broadcast through the manager and must not close the open break.
+#[tokio::test(start_paused = true)]
+async fn test_resume_during_break_keeps_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .mini_break_duration_s(20)
+        .build();
+
+    let env = create_manager_test_env(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    spawn_test_manager(&env, cmd_rx).await;
+    advance_time_and_yield(duration_ms(200)).await;
+
+    // Wait for break to trigger
+    advance_time_and_yield(duration_s(60)).await;
+    assert!(env.shared_state.read().in_break_session());
+
+    cmd_tx.send(Command::SystemResumed(600)).await.unwrap();
+    advance_time_and_yield(duration_ms(500)).await;
+
+    assert!(
+        env.shared_state.read().in_break_session(),
+        "Break session should survive a resume"
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(env.shutdown_tx);
+}
+
+// ============================================================================
+// Section 9: Virtual Clock and Time Zones
+// ============================================================================
+
+/// **C1: Schedules Follow the Clock's Time Zone**
+///
+/// Idle over the weekend, the scheduler wakes up when Monday's schedule starts in
+/// the clock's time zone, not the system one.
+#[tokio::test(start_paused = true)]
+async fn test_schedule_follows_clock_timezone() {
+    let config = TestConfigBuilder::new()
+        .time_range(time_range(9, 0, 17, 0))
+        .days_of_week(workdays())
+        .mini_break_interval_s(1200)
+        .notification_before_s(0)
+        .build();
+
+    // Friday 17:30 in Berlin (CEST, UTC+2)
+    let clock = FakeClock::with_timezone(test_datetime(2025, 9, 5, 15, 30, 0), Berlin);
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock.clone());
+
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    // Until Monday 08:59 in Berlin
+    advance_time_and_yield(duration_h(63) + duration_s(29 * 60)).await;
+    assert!(!emitter.has_event("scheduler-event"));
+
+    // The schedule starts at 09:00, the first break is due at 09:20
+    advance_time_and_yield(duration_s(60)).await;
+    advance_time_and_yield(duration_s(19 * 60)).await;
+    assert!(!emitter.has_event("scheduler-event"));
+    advance_time_and_yield(duration_s(60)).await;
+    assert!(
+        emitter.has_event("scheduler-event"),
+        "Break should trigger 20 minutes after the schedule starts"
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **C2: Wall-Clock Jumps Are Seen by the Scheduler**
+///
+/// Moving the clock forward without advancing tokio time (e.g. a manual clock
+/// change) makes the pending break overdue; it triggers once the timers are
+/// recomputed.
+#[tokio::test(start_paused = true)]
+async fn test_clock_jump_makes_break_overdue() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(1200)
+        .notification_before_s(0)
+        .build();
+
+    let start = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+    let clock = FakeClock::new(start);
+    let (mut scheduler, emitter, shutdown_tx, app) =
+        create_test_break_scheduler_with_clock(config, clock.clone());
+    app.state::<ProgressStore>().save(SchedulerProgress {
+        mini_break_counter: 0,
+        work_since_long_break_s: 0,
+        last_break_time: Some(start),
+        saved_at: start,
+    });
+    scheduler.restore_progress().await;
+
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_s(60)).await;
+    clock.jump(duration_s(30 * 60));
+    cmd_tx.send(Command::SystemResumed(0)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    assert!(
+        emitter.has_event("scheduler-event"),
+        "Break should trigger immediately after the jump"
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **C3: History Is Stamped by the Scheduler's Clock**
+///
+/// Entries carry the virtual time of each transition, and the time a break was
+/// shown is measured on the same clock.
+#[tokio::test(start_paused = true)]
+async fn test_history_follows_clock() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(60)
+        .mini_break_duration_s(20)
+        .notification_before_s(0)
+        .build();
+
+    let start = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+    let clock = FakeClock::new(start);
+    let (mut scheduler, emitter, shutdown_tx, app) =
+        create_test_break_scheduler_with_clock(config, clock.clone());
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(60)).await;
+    advance_time_and_yield(duration_s(15)).await;
+    cmd_tx
+        .send(Command::PromptFinished(latest_break_event(&emitter)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let entries = app.state::<HistoryStore>().query(
+        chrono::DateTime::<chrono::Utc>::MIN_UTC,
+        chrono::DateTime::<chrono::Utc>::MAX_UTC,
+    );
+    assert_eq!(entries.len(), 2);
+    assert_duration_near((entries[0].timestamp - start).num_seconds(), 60, 1);
+    assert_duration_near((entries[1].timestamp - start).num_seconds(), 75, 1);
+    assert_duration_near(entries[1].duration_s.into(), 15, 1);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 10: Pomodoro Sessions
+// ============================================================================
+
+/// Latest `scheduler-event` emitted, i.e. the break that just started
+fn latest_break_event(emitter: &TestEventEmitter) -> SchedulerEvent {
+    let events = emitter.get_events_by_name("scheduler-event");
+    serde_json::from_value(events.last().expect("No break started").clone()).expect("Should parse")
+}
+
+/// **P1: Focus Blocks and Rests**
+///
+/// A session runs focus blocks of `focus_s` separated by the Pomodoro schedule's
+/// rests, with a long rest after `after_mini_breaks` focus blocks, regardless of
+/// the time of day.
+#[tokio::test(start_paused = true)]
+async fn test_pomodoro_focus_blocks_and_rests() {
+    let config = TestConfigBuilder::new()
+        .schedule_enabled(false)
+        .add_schedule(pomodoro_schedule(1500, 2))
+        .build();
+    let mini_id = config.schedules[1].mini_breaks.base.id;
+    let long_id = config.schedules[1].long_breaks.base.id;
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    cmd_tx
+        .send(Command::StartPomodoro(
+            Some("Write report".to_string()),
+            None,
+        ))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    let session = status.pomodoro.expect("Session should be running");
+    assert_eq!(session.task.as_deref(), Some("Write report"));
+    assert_eq!(status.next_event.unwrap().seconds_until, 1500);
+
+    for expected in [
+        SchedulerEvent::MiniBreak(mini_id),
+        SchedulerEvent::MiniBreak(mini_id),
+        SchedulerEvent::LongBreak(long_id),
+    ] {
+        emitter.clear();
+        advance_time_and_yield(duration_s(1499)).await;
+        assert!(!emitter.has_event("scheduler-event"));
+        advance_time_and_yield(duration_s(1)).await;
+        assert_eq!(latest_break_event(&emitter), expected);
+
+        cmd_tx
+            .send(Command::PromptFinished(expected))
+            .await
+            .unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+    }
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **P2: Stopping Hands Back to Interval Schedules**
+///
+/// While a session runs, the active interval schedule is ignored. Stopping the
+/// session starts a fresh interval on it.
+#[tokio::test(start_paused = true)]
+async fn test_pomodoro_stop_resumes_interval_schedule() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(1200)
+        .add_schedule(pomodoro_schedule(1500, 4))
+        .build();
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    cmd_tx
+        .send(Command::StartPomodoro(None, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    // No interval break during the focus block
+    advance_time_and_yield(duration_s(1300)).await;
+    assert!(!emitter.has_event("scheduler-event"));
+
+    cmd_tx.send(Command::StopPomodoro(None)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert!(status.pomodoro.is_none());
+    assert_eq!(status.next_event.unwrap().seconds_until, 1200);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **P3: Starting Requires a Pomodoro Schedule**
+///
+/// Without an enabled Pomodoro schedule, or while paused, no session starts.
+#[tokio::test(start_paused = true)]
+async fn test_pomodoro_start_ignored_without_schedule_or_when_paused() {
+    let mut disabled = pomodoro_schedule(1500, 4);
+    disabled.enabled = false;
+    let config = TestConfigBuilder::new().add_schedule(disabled).build();
+
+    let (mut scheduler, emitter, shutdown_tx, _app) = create_test_break_scheduler(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    cmd_tx
+        .send(Command::StartPomodoro(None, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(get_latest_status(&emitter).pomodoro.is_none());
+
+    let config = TestConfigBuilder::new()
+        .add_schedule(pomodoro_schedule(1500, 4))
+        .build();
+    cmd_tx.send(Command::UpdateConfig(config)).await.unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::StartPomodoro(None, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(get_latest_status(&emitter).pomodoro.is_none());
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 11: Breaks Taken While Idle
+// ============================================================================
+
+/// Parse the payloads of all `break-credited` events
+fn credited_breaks(emitter: &TestEventEmitter) -> Vec<BreakCredit> {
+    emitter
+        .get_events_by_name("break-credited")
+        .into_iter()
+        .map(|payload| serde_json::from_value(payload).expect("Should parse"))
+        .collect()
+}
+
+/// **C1: Long Idle Period Counts as a Long Break**
+///
+/// The idle pause comes `inactive_s` after the last input, so that time counts too.
+#[tokio::test(start_paused = true)]
+async fn test_idle_period_credited_as_long_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(1200)
+        .long_break_duration_s(300)
+        .build();
+    let mini_id = config.schedules[0].mini_breaks.base.id;
+    let long_id = config.schedules[0].long_breaks.base.id;
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(1200)).await;
+    cmd_tx
+        .send(Command::PromptFinished(SchedulerEvent::MiniBreak(mini_id)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(400)).await;
+    assert_eq!(get_latest_status(&emitter).mini_break_counter, 1);
+
+    // Away for lunch
+    cmd_tx
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(600)).await;
+    cmd_tx
+        .send(Command::Resume(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let credit = credited_breaks(&emitter)
+        .pop()
+        .expect("Break should be credited");
+    assert_eq!(credit.event, SchedulerEvent::LongBreak(long_id));
+    assert_eq!(credit.paused_s, 900);
+    assert_eq!(
+        recorded_outcomes(&app).last(),
+        Some(&PromptOutcome::Credited)
+    );
+
+    let status = get_latest_status(&emitter);
+    assert_eq!(status.mini_break_counter, 0);
+    assert_eq!(status.next_event.unwrap().seconds_until, 1200);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **C2: Shorter Idle Period Counts as a Mini Break**
+#[tokio::test(start_paused = true)]
+async fn test_idle_period_credited_as_mini_break() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(1200)
+        .mini_break_duration_s(20)
+        .long_break_duration_s(600)
+        .build();
+    let mini_id = config.schedules[0].mini_breaks.base.id;
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(1000)).await;
+    cmd_tx
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(10)).await;
+    cmd_tx
+        .send(Command::Resume(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    assert_eq!(
+        credited_breaks(&emitter),
+        vec![BreakCredit {
+            event: SchedulerEvent::MiniBreak(mini_id),
+            paused_s: 310,
+        }]
+    );
+    assert_eq!(get_latest_status(&emitter).mini_break_counter, 1);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 12: Pause Policies
+// ============================================================================
+
+/// **Q1: Frozen Interval Continues After a Pause**
+///
+/// With the `Freeze` policy, the countdown continues where it was paused
+/// instead of restarting.
+#[tokio::test(start_paused = true)]
+async fn test_freeze_policy_continues_interval() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(1200)
+        .pause_policy(PauseReason::Dnd, PausePolicy::Freeze)
+        .build();
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_ms(599_600)).await;
+
+    // A 15 minute meeting with Do Not Disturb on
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(900)).await;
+    cmd_tx
+        .send(Command::Resume(PauseReason::Dnd, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = get_latest_status(&emitter);
+    assert_eq!(status.next_event.unwrap().seconds_until, 600);
+    assert!(!emitter.has_event("break-credited"));
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **Q2: Policies Apply per Pause Reason**
+///
+/// A manual pause resets the interval by default, and counts as a break when
+/// configured with `CreditBreak`.
+#[tokio::test(start_paused = true)]
+async fn test_pause_policy_per_reason() {
+    for (policy, credited) in [
+        (PausePolicy::Reset, false),
+        (PausePolicy::CreditBreak, true),
+    ] {
+        let config = TestConfigBuilder::new()
+            .mini_break_interval_s(1200)
+            .long_break_duration_s(300)
+            .pause_policy(PauseReason::Manual, policy)
+            .build();
+
+        let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+        let (mut scheduler, emitter, shutdown_tx, _app) =
+            create_test_break_scheduler_with_clock(config, clock);
+        let (cmd_tx, cmd_rx) = mpsc::channel(32);
+        let task = tokio::spawn(async move {
+            scheduler.run(cmd_rx).await;
+        });
+
+        advance_time_and_yield(duration_ms(200)).await;
+        advance_time_and_yield(duration_s(600)).await;
+        cmd_tx
+            .send(Command::Pause(PauseReason::Manual, None))
+            .await
+            .unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+        advance_time_and_yield(duration_s(400)).await;
+        cmd_tx
+            .send(Command::Resume(PauseReason::Manual, None))
+            .await
+            .unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+
+        let status = get_latest_status(&emitter);
+        assert_eq!(status.next_event.unwrap().seconds_until, 1200);
+        assert_eq!(emitter.has_event("break-credited"), credited, "{policy:?}");
+
+        // Cleanup
+        drop(cmd_tx);
+        drop(shutdown_tx);
+        task.await.unwrap();
+    }
+}
+
+/// **Q3: Overlapping Pauses Follow the Reason That Ends Them**
+///
+/// The manager only forwards the first pause reason and the last one cleared.
+/// The policy of the reason that ends the pause applies: time away that ends
+/// with the user returning is credited, a DND pause ending last continues
+/// the interval from when the user went idle (300s before the idle pause).
+#[tokio::test(start_paused = true)]
+async fn test_overlapping_pauses_apply_resuming_policy() {
+    for (paused_by, resumed_by, credited, seconds_until) in [
+        (PauseReason::Dnd, PauseReason::UserIdle, true, 1200),
+        (PauseReason::UserIdle, PauseReason::Dnd, false, 900),
+    ] {
+        let config = TestConfigBuilder::new()
+            .mini_break_interval_s(1200)
+            .long_break_duration_s(300)
+            .pause_policy(PauseReason::Dnd, PausePolicy::Freeze)
+            .pause_policy(PauseReason::UserIdle, PausePolicy::CreditBreak)
+            .build();
+
+        let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+        let (mut scheduler, emitter, shutdown_tx, _app) =
+            create_test_break_scheduler_with_clock(config, clock);
+        let (cmd_tx, cmd_rx) = mpsc::channel(32);
+        let task = tokio::spawn(async move {
+            scheduler.run(cmd_rx).await;
+        });
+
+        advance_time_and_yield(duration_ms(200)).await;
+        advance_time_and_yield(duration_ms(599_600)).await;
+        cmd_tx.send(Command::Pause(paused_by, None)).await.unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+        advance_time_and_yield(duration_s(900)).await;
+        cmd_tx
+            .send(Command::Resume(resumed_by, None))
+            .await
+            .unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+
+        let status = get_latest_status(&emitter);
+        assert_eq!(
+            status.next_event.unwrap().seconds_until,
+            seconds_until,
+            "{paused_by} then {resumed_by}"
+        );
+        assert_eq!(
+            emitter.has_event("break-credited"),
+            credited,
+            "{paused_by} then {resumed_by}"
+        );
+
+        // Cleanup
+        drop(cmd_tx);
+        drop(shutdown_tx);
+        task.await.unwrap();
+    }
+}
+
+// ============================================================================
+// Section 13: Break Grace Window
+// ============================================================================
+
+/// Start a scheduler whose breaks wait up to 30s for a pause in typing
+///
+/// Returns a closure recording an activity sample with the given idle seconds.
+fn spawn_grace_window_scheduler() -> (
+    impl Fn(i64),
+    TestEventEmitter,
+    mpsc::Sender<Command>,
+    tokio::sync::watch::Sender<()>,
+    tokio::task::JoinHandle<()>,
+) {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(600)
+        .break_grace_s(30)
+        .build();
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, app) =
+        create_test_break_scheduler_with_clock(config, clock.clone());
+    let shared_state = app.state::<SharedState>().inner().clone();
+    let record = move |idle_s: i64| {
+        shared_state
+            .write()
+            .record_activity(clock.now(), chrono::Duration::seconds(idle_s));
+    };
+    record(0);
+
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+    (record, emitter, cmd_tx, shutdown_tx, task)
+}
+
+/// **G1: Due Break Waits for a Pause in Typing**
+///
+/// A break falling due while the user types opens as soon as input stops.
+#[tokio::test(start_paused = true)]
+async fn test_break_waits_for_pause_in_typing() {
+    let (record, emitter, cmd_tx, shutdown_tx, task) = spawn_grace_window_scheduler();
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(590)).await;
+    record(0);
+    advance_time_and_yield(duration_s(15)).await;
+    assert!(!emitter.has_event("scheduler-event"));
+
+    record(3);
+    advance_time_and_yield(duration_s(2)).await;
+    assert!(matches!(
+        latest_break_event(&emitter),
+        SchedulerEvent::MiniBreak(_)
+    ));
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **G2: Break Opens at the End of the Grace Window**
+///
+/// Without a pause in typing, the break opens once the grace window has passed.
+#[tokio::test(start_paused = true)]
+async fn test_break_opens_after_grace_window() {
+    let (record, emitter, cmd_tx, shutdown_tx, task) = spawn_grace_window_scheduler();
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(590)).await;
+    for _ in 0..17 {
+        record(0);
+        advance_time_and_yield(duration_s(2)).await;
+    }
+    assert!(!emitter.has_event("scheduler-event"));
+
+    for _ in 0..4 {
+        record(0);
+        advance_time_and_yield(duration_s(2)).await;
+    }
+    assert!(matches!(
+        latest_break_event(&emitter),
+        SchedulerEvent::MiniBreak(_)
+    ));
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 14: Skip Escalation
+// ============================================================================
+
+/// **E1: Skipped Breaks Shorten the Interval**
+///
+/// After two skips in a row the next mini break comes after half the interval,
+/// and taking it restores the usual interval.
+#[tokio::test(start_paused = true)]
+async fn test_skipped_breaks_shorten_interval() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(600)
+        .mini_break_skip_escalation(SkipEscalation::ShortenInterval, 2)
+        .build();
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+    advance_time_and_yield(duration_ms(200)).await;
+
+    for _ in 0..2 {
+        advance_time_and_yield(duration_s(600)).await;
+        cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
+        advance_time_and_yield(duration_ms(200)).await;
+    }
+    let status = get_latest_status(&emitter);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 300, 1);
+
+    advance_time_and_yield(duration_s(300)).await;
+    let break_event = latest_break_event(&emitter);
+    cmd_tx
+        .send(Command::PromptFinished(break_event))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    let status = get_latest_status(&emitter);
+    assert_duration_near(status.next_event.unwrap().seconds_until.into(), 600, 1);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+/// **E2: Skipped Breaks Bring a Long Break Forward**
+///
+/// With the `LongBreak` escalation, the break after a skipped mini break is a
+/// long break. Completing the long break ends the escalation.
+#[tokio::test(start_paused = true)]
+async fn test_skipped_breaks_bring_long_break_forward() {
+    let config = TestConfigBuilder::new()
+        .mini_break_interval_s(600)
+        .mini_break_skip_escalation(SkipEscalation::LongBreak, 1)
+        .build();
+    let mini_id = config.schedules[0].mini_breaks.base.id;
+    let long_id = config.schedules[0].long_breaks.base.id;
+
+    let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+    let (mut scheduler, emitter, shutdown_tx, _app) =
+        create_test_break_scheduler_with_clock(config, clock);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let task = tokio::spawn(async move {
+        scheduler.run(cmd_rx).await;
+    });
+    advance_time_and_yield(duration_ms(200)).await;
+
+    advance_time_and_yield(duration_s(600)).await;
+    assert_eq!(
+        latest_break_event(&emitter),
+        SchedulerEvent::MiniBreak(mini_id)
+    );
+    cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    let status = get_latest_status(&emitter);
+    assert_eq!(
+        status.next_event.unwrap().kind,
+        SchedulerEvent::LongBreak(long_id)
+    );
+
+    advance_time_and_yield(duration_s(600)).await;
+    assert_eq!(
+        latest_break_event(&emitter),
+        SchedulerEvent::LongBreak(long_id)
+    );
+    cmd_tx
+        .send(Command::PromptFinished(SchedulerEvent::LongBreak(long_id)))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    let status = get_latest_status(&emitter);
+    assert_eq!(
+        status.next_event.unwrap().kind,
+        SchedulerEvent::MiniBreak(mini_id)
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(shutdown_tx);
+    task.await.unwrap();
+}
+
+// ============================================================================
+// Section 15: Break Verification
+// ============================================================================
+
+/// Scheduler with a 60s mini break every 10 minutes, verified in the given mode
+struct VerifiedScheduler {
+    emitter: TestEventEmitter,
+    app: tauri::AppHandle<tauri::test::MockRuntime>,
+    clock: FakeClock,
+    cmd_tx: mpsc::Sender<Command>,
+    shutdown_tx: tokio::sync::watch::Sender<()>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl VerifiedScheduler {
+    fn spawn(mode: BreakVerification) -> Self {
+        let config = TestConfigBuilder::new()
+            .mini_break_interval_s(600)
+            .mini_break_duration_s(60)
+            .break_verification(mode)
+            .build();
+
+        let clock = FakeClock::new(test_datetime_with_local(2025, 9, 1, 10, 0, 0));
+        let (mut scheduler, emitter, shutdown_tx, app) =
+            create_test_break_scheduler_with_clock(config, clock.clone());
+        let (cmd_tx, cmd_rx) = mpsc::channel(32);
+        let task = tokio::spawn(async move {
+            scheduler.run(cmd_rx).await;
+        });
+
+        let verified = Self {
+            emitter,
+            app,
+            clock,
+            cmd_tx,
+            shutdown_tx,
+            task,
+        };
+        verified.record(5);
+        verified
+    }
+
+    /// Record an activity sample with `idle_s` seconds since the last input
+    fn record(&self, idle_s: i64) {
+        self.app
+            .state::<SharedState>()
+            .write()
+            .record_activity(self.clock.now(), chrono::Duration::seconds(idle_s));
+    }
+
+    async fn shutdown(self) {
+        drop(self.cmd_tx);
+        drop(self.shutdown_tx);
+        self.task.await.unwrap();
+    }
+}
+
+/// **V1: Input During a Break Extends or Restarts It**
+///
+/// `Extend` adds the time spent typing to the break, `Restart` starts the
+/// countdown over. The prompt is told how much of the break is left.
+#[tokio::test(start_paused = true)]
+async fn test_break_activity_extends_or_restarts_break() {
+    for (mode, remaining_s) in [
+        (BreakVerification::Extend, 40),
+        (BreakVerification::Restart, 60),
+    ] {
+        let scheduler = VerifiedScheduler::spawn(mode);
+
+        advance_time_and_yield(duration_ms(200)).await;
+        advance_time_and_yield(duration_s(600)).await;
+        scheduler.record(5);
+        advance_time_and_yield(duration_s(20)).await;
+        assert!(!scheduler.emitter.has_event("break-activity"), "{mode:?}");
+
+        scheduler.record(0);
+        advance_time_and_yield(duration_s(2)).await;
+        let activity: BreakActivity = serde_json::from_value(
+            scheduler.emitter.get_events_by_name("break-activity")[0].clone(),
+        )
+        .expect("Should parse");
+        assert_duration_near(activity.remaining_s.into(), remaining_s, 1);
+
+        // Cleanup
+        scheduler.shutdown().await;
+    }
+}
+
+/// **V2: Break With Input Is Recorded as Interrupted**
+///
+/// With `Record`, the countdown is left alone and the break is recorded as
+/// interrupted once the prompt finishes.
+#[tokio::test(start_paused = true)]
+async fn test_break_activity_recorded_as_interrupted() {
+    let scheduler = VerifiedScheduler::spawn(BreakVerification::Record);
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(600)).await;
+    advance_time_and_yield(duration_s(20)).await;
+    scheduler.record(0);
+    advance_time_and_yield(duration_s(2)).await;
+    assert!(scheduler.emitter.has_event("break-activity"));
+
+    scheduler
+        .cmd_tx
+        .send(Command::PromptFinished(latest_break_event(
+            &scheduler.emitter,
+        )))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert_eq!(
+        recorded_outcomes(&scheduler.app),
+        vec![PromptOutcome::Started, PromptOutcome::Interrupted]
+    );
+
+    // Cleanup
+    scheduler.shutdown().await;
+}
+
+// ============================================================================
+// Section 16: Stuck Break Watchdog
+// ============================================================================
+
+/// **W1: Break Prompt That Never Finishes Is Recovered**
+///
+/// A minute after the break should have ended, the session is cancelled and
+/// scheduling resumes as if the prompt had been closed.
+#[tokio::test(start_paused = true)]
+async fn test_watchdog_recovers_stuck_break() {
+    let scheduler = VerifiedScheduler::spawn(BreakVerification::Off);
+    let shared_state = scheduler.app.state::<SharedState>().inner().clone();
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(600)).await;
+    advance_time_and_yield(duration_s(20)).await;
+    assert!(shared_state.read().in_break_session());
+
+    advance_time_and_yield(duration_s(99)).await;
+    assert!(shared_state.read().in_break_session());
+    assert_eq!(
+        recorded_outcomes(&scheduler.app),
+        vec![PromptOutcome::Started]
+    );
+
+    advance_time_and_yield(duration_s(2)).await;
+    assert!(!shared_state.read().in_break_session());
+    assert_eq!(
+        recorded_outcomes(&scheduler.app),
+        vec![PromptOutcome::Started, PromptOutcome::Cancelled]
+    );
+    // The break was not taken and does not count towards a long break
+    let status = get_latest_status(&scheduler.emitter);
+    assert!(status.next_event.is_some());
+    assert_eq!(status.mini_break_counter, 0);
+
+    // Cleanup
+    scheduler.shutdown().await;
+}
+
+/// **W2: Watchdog Follows Extended Breaks**
+///
+/// A break restarted by input ends later, and so does the time the watchdog
+/// waits for its prompt.
+#[tokio::test(start_paused = true)]
+async fn test_watchdog_follows_restarted_break() {
+    let scheduler = VerifiedScheduler::spawn(BreakVerification::Restart);
+    let shared_state = scheduler.app.state::<SharedState>().inner().clone();
+
+    advance_time_and_yield(duration_ms(200)).await;
+    advance_time_and_yield(duration_s(600)).await;
+    scheduler.record(5);
+    advance_time_and_yield(duration_s(40)).await;
+    scheduler.record(0);
+    advance_time_and_yield(duration_s(2)).await;
+    scheduler.record(5);
+
+    advance_time_and_yield(duration_s(100)).await;
+    assert!(shared_state.read().in_break_session());
+
+    advance_time_and_yield(duration_s(25)).await;
+    assert!(!shared_state.read().in_break_session());
+
+    // Cleanup
+    scheduler.shutdown().await;
+}
+
+/// **W3: Break of Unknown Length Is Still Recovered**
+///
+/// A break whose schedule disappeared from the config before it started has no
+/// known end, the watchdog gives up on it a minute after it started.
+#[tokio::test(start_paused = true)]
+async fn test_watchdog_recovers_break_of_unknown_length() {
+    let scheduler = VerifiedScheduler::spawn(BreakVerification::Off);
+    let shared_state = scheduler.app.state::<SharedState>().inner().clone();
+
+    advance_time_and_yield(duration_ms(200)).await;
+    {
+        let config = scheduler.app.state::<SharedConfig>();
+        config.write().await.schedules[0].mini_breaks.base.id = BreakId::new();
+    }
+    advance_time_and_yield(duration_s(600)).await;
+    assert!(shared_state.read().in_break_session());
+
+    advance_time_and_yield(duration_s(59)).await;
+    assert!(shared_state.read().in_break_session());
+
+    advance_time_and_yield(duration_s(2)).await;
+    assert!(!shared_state.read().in_break_session());
+    assert_eq!(
+        recorded_outcomes(&scheduler.app),
+        vec![PromptOutcome::Started, PromptOutcome::Cancelled]
+    );
+
+    // Cleanup
+    scheduler.shutdown().await;
+}
@@ -0,0 +1,159 @@
+//! Source of the current time for the schedulers
+//!
+//! The schedulers wait on tokio timers but compare break and attention times with
+//! the wall clock, and evaluate schedules in local time. Reading both through a
+//! [`Clock`] lets tests and simulations run them on virtual time in any time zone.
+//!
+//! - [`SystemClock`]: the system wall clock and local time zone (production)
+//! - [`FakeClock`]: follows tokio time, so it is driven by `tokio::time::pause`
+//!   and `advance`, can be moved independently to mimic suspends and clock
+//!   changes, and evaluates schedules in a configurable time zone
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+use parking_lot::Mutex;
+
+/// Source of the current wall-clock time and local time zone
+pub trait Clock: Clone + Send + Sync + 'static {
+    /// Time zone schedules and attentions are evaluated in
+    type Tz: TimeZone + Send + Sync;
+
+    /// Current time
+    fn now(&self) -> DateTime<Utc>;
+
+    /// The local time zone
+    fn timezone(&self) -> Self::Tz;
+
+    /// Current time in the local time zone
+    fn now_local(&self) -> DateTime<Self::Tz> {
+        self.now().with_timezone(&self.timezone())
+    }
+}
+
+/// The system wall clock in the system time zone
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Tz = Local;
+
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn timezone(&self) -> Local {
+        Local
+    }
+}
+
+/// A controllable clock that advances with tokio time
+///
+/// With paused tokio time it only moves when tokio time is advanced, keeping the
+/// wall clock and the schedulers' timers in step. Clones share the same time, so
+/// a test can keep a handle to move the clock seen by the schedulers.
+#[derive(Debug, Clone)]
+pub struct FakeClock<Tz: TimeZone = Local> {
+    /// Wall-clock time at a tokio instant
+    anchor: Arc<Mutex<(DateTime<Utc>, tokio::time::Instant)>>,
+    timezone: Tz,
+}
+
+impl FakeClock<Local> {
+    /// Create a clock reading `start` now, in the system time zone
+    #[must_use]
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self::with_timezone(start, Local)
+    }
+}
+
+impl<Tz: TimeZone> FakeClock<Tz> {
+    /// Create a clock reading `start` now, evaluating local time in `timezone`
+    #[must_use]
+    pub fn with_timezone(start: DateTime<Utc>, timezone: Tz) -> Self {
+        Self {
+            anchor: Arc::new(Mutex::new((start, tokio::time::Instant::now()))),
+            timezone,
+        }
+    }
+
+    /// Set the wall clock without advancing tokio time
+    ///
+    /// Mimics a manual clock change, or a suspend when moved forward.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.anchor.lock() = (now, tokio::time::Instant::now());
+    }
+
+    /// Move the wall clock by `delta` without advancing tokio time
+    pub fn jump(&self, delta: chrono::Duration) {
+        let now = self.read();
+        self.set(now + delta);
+    }
+
+    fn read(&self) -> DateTime<Utc> {
+        let (at, instant) = *self.anchor.lock();
+        let elapsed =
+            chrono::Duration::from_std(instant.elapsed()).unwrap_or(chrono::Duration::MAX);
+        at + elapsed
+    }
+}
+
+impl<Tz> Clock for FakeClock<Tz>
+where
+    Tz: TimeZone + Send + Sync + 'static,
+    Tz::Offset: Send + Sync,
+{
+    type Tz = Tz;
+
+    fn now(&self) -> DateTime<Utc> {
+        self.read()
+    }
+
+    fn timezone(&self) -> Tz {
+        self.timezone.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::test_helpers::*;
+
+    use chrono::{Datelike, Timelike};
+    use chrono_tz::Europe::Berlin;
+
+    #[tokio::test(start_paused = true)]
+    async fn fake_clock_follows_tokio_time() {
+        let start = test_datetime(2025, 9, 1, 9, 0, 0);
+        let clock = FakeClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        tokio::time::advance(std::time::Duration::from_secs(90)).await;
+        assert_eq!(clock.now(), start + duration_s(90));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fake_clock_jump_is_shared_by_clones() {
+        let start = test_datetime(2025, 9, 1, 9, 0, 0);
+        let clock = FakeClock::new(start);
+        let scheduler_clock = clock.clone();
+
+        clock.jump(duration_h(2));
+        tokio::time::advance(std::time::Duration::from_secs(30)).await;
+
+        assert_eq!(
+            scheduler_clock.now(),
+            start + duration_h(2) + duration_s(30)
+        );
+    }
+
+    #[test]
+    fn fake_clock_uses_configured_timezone() {
+        // 2025-10-25 22:30 UTC is Sunday 00:30 in Berlin (CEST, UTC+2)
+        let clock = FakeClock::with_timezone(test_datetime(2025, 10, 25, 22, 30, 0), Berlin);
+
+        let local = clock.now_local();
+        assert_eq!(local.weekday(), chrono::Weekday::Sun);
+        assert_eq!((local.hour(), local.minute()), (0, 30));
+    }
+}
@@ -1,7 +1,10 @@
-use chrono::Utc;
-use chrono::{DateTime, Local, NaiveTime, Weekday};
+use chrono::offset::LocalResult;
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday,
+};
 
-use crate::{config::AppConfig, core::schedule::ScheduleSettings};
+use crate::config::AppConfig;
+use crate::core::schedule::{ScheduleKind, ScheduleSettings};
 
 // This module is now primarily used internally by BreakScheduler
 // The EventSource pattern is deprecated in favor of direct calculation
@@ -22,9 +25,79 @@ pub fn get_active_schedule(
     now_time: NaiveTime,
     now_day: Weekday,
 ) -> Option<&ScheduleSettings> {
-    config.schedules.iter().find(|s| {
-        s.enabled && s.days_of_week.contains(&now_day) && s.time_range.contains(&now_time)
-    })
+    config
+        .schedules
+        .iter()
+        .find(|s| s.is_active_at(now_time, now_day))
+}
+
+/// Get the schedule Pomodoro sessions run on: the first enabled Pomodoro schedule
+#[must_use]
+pub fn get_pomodoro_schedule(config: &AppConfig) -> Option<&ScheduleSettings> {
+    config
+        .schedules
+        .iter()
+        .find(|s| s.enabled && s.kind == ScheduleKind::Pomodoro)
+}
+
+/// Get the next instant after `now` at which the active schedule changes
+///
+/// Candidates are the starts of schedule time ranges and the first second after
+/// their ends over the coming week, including overnight ranges from yesterday.
+/// Returns `None` if the active schedule never changes, e.g. a single full-day
+/// schedule on every day or no enabled schedule.
+#[must_use]
+pub fn next_schedule_boundary<Tz: TimeZone>(
+    config: &AppConfig,
+    now: &DateTime<Tz>,
+) -> Option<DateTime<Utc>> {
+    let timezone = now.timezone();
+    let current = get_active_schedule(config, now.time(), now.weekday());
+    let today = now.date_naive();
+
+    let mut candidates: Vec<DateTime<Tz>> = config
+        .schedules
+        .iter()
+        .filter(|s| s.enabled && s.kind == ScheduleKind::Interval)
+        .flat_map(|s| {
+            (-1..=7).flat_map(move |offset| {
+                let date = today + Duration::days(offset);
+                s.time_ranges
+                    .iter()
+                    .flat_map(move |r| r.boundaries_on(date))
+            })
+        })
+        .filter_map(|naive| localize(naive, &timezone))
+        .filter(|candidate| candidate > now)
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            let next = get_active_schedule(config, candidate.time(), candidate.weekday());
+            !is_same_schedule(current, next)
+        })
+        .map(|candidate| candidate.with_timezone(&Utc))
+}
+
+fn is_same_schedule(a: Option<&ScheduleSettings>, b: Option<&ScheduleSettings>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => std::ptr::eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Convert a naive datetime in `timezone`, moving times skipped by DST forward by an hour
+pub(crate) fn localize<Tz: TimeZone>(naive: NaiveDateTime, timezone: &Tz) -> Option<DateTime<Tz>> {
+    match timezone.from_local_datetime(&naive) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Some(dt),
+        LocalResult::None => timezone
+            .from_local_datetime(&(naive + Duration::hours(1)))
+            .earliest(),
+    }
 }
 
 #[cfg(test)]
@@ -35,6 +108,7 @@ mod tests {
     use crate::scheduler::test_helpers::*;
 
     use chrono::Weekday;
+    use chrono_tz::Europe::Berlin;
 
     fn create_test_config() -> AppConfig {
         AppConfig {
@@ -42,7 +116,7 @@ mod tests {
                 ScheduleSettings {
                     name: "Weekday Schedule".to_string(),
                     enabled: true,
-                    time_range: time_range(9, 0, 17, 0),
+                    time_ranges: vec![time_range(9, 0, 17, 0)],
                     days_of_week: vec![
                         Weekday::Mon,
                         Weekday::Tue,
@@ -55,14 +129,14 @@ mod tests {
                 ScheduleSettings {
                     name: "Weekend Schedule".to_string(),
                     enabled: true,
-                    time_range: time_range(10, 0, 14, 0),
+                    time_ranges: vec![time_range(10, 0, 14, 0)],
                     days_of_week: vec![Weekday::Sat, Weekday::Sun],
                     ..Default::default()
                 },
                 ScheduleSettings {
                     name: "Disabled Schedule".to_string(),
                     enabled: false, // DISABLED
-                    time_range: full_time_range(),
+                    time_ranges: vec![full_time_range()],
                     days_of_week: vec![Weekday::Mon],
                     ..Default::default()
                 },
@@ -138,4 +212,230 @@ mod tests {
         let active_schedule = get_active_schedule(&config, now_time, now_day);
         assert!(active_schedule.is_none());
     }
+
+    #[test]
+    fn test_get_active_schedule_ignores_pomodoro() {
+        let mut config = create_test_config();
+        config.schedules.insert(
+            0,
+            ScheduleSettings {
+                name: "Pomodoro".to_string(),
+                kind: ScheduleKind::Pomodoro,
+                time_ranges: vec![full_time_range()],
+                days_of_week: all_weekdays(),
+                ..Default::default()
+            },
+        );
+
+        let active_schedule = get_active_schedule(&config, naive_time(10, 30, 0), Weekday::Mon);
+        assert_eq!(active_schedule.unwrap().name, "Weekday Schedule");
+        assert_eq!(get_pomodoro_schedule(&config).unwrap().name, "Pomodoro");
+    }
+
+    #[test]
+    fn test_get_pomodoro_schedule_ignores_disabled() {
+        let mut config = create_test_config();
+        config.schedules.push(ScheduleSettings {
+            name: "Pomodoro".to_string(),
+            enabled: false,
+            kind: ScheduleKind::Pomodoro,
+            ..Default::default()
+        });
+
+        assert!(get_pomodoro_schedule(&config).is_none());
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_before_start() {
+        let config = create_test_config();
+        // Monday 08:00, weekday schedule starts at 09:00
+        let now = test_local_datetime(2025, 9, 1, 8, 0, 0);
+
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 1, 9, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_inside_range() {
+        let config = create_test_config();
+        // Monday 10:30, the inclusive end at 17:00 stops one second later
+        let now = test_local_datetime(2025, 9, 1, 10, 30, 0);
+
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 1, 17, 0, 1))
+        );
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_skips_inactive_days() {
+        let config = create_test_config();
+        // Friday evening, next activation is the weekend schedule on Saturday
+        let now = test_local_datetime(2025, 9, 5, 20, 0, 0);
+
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 6, 10, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_between_adjacent_schedules() {
+        let config = AppConfig {
+            schedules: vec![
+                ScheduleSettings {
+                    name: "Morning".to_string(),
+                    time_ranges: vec![time_range(9, 0, 11, 59)],
+                    days_of_week: all_weekdays(),
+                    ..Default::default()
+                },
+                ScheduleSettings {
+                    name: "Afternoon".to_string(),
+                    time_ranges: vec![time_range(12, 0, 18, 0)],
+                    days_of_week: all_weekdays(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+
+        // Morning ends at 11:59:01 and afternoon starts at 12:00
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 1, 11, 59, 1))
+        );
+
+        let now = test_local_datetime(2025, 9, 1, 11, 59, 30);
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 1, 12, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_overnight_range() {
+        let config = AppConfig {
+            schedules: vec![ScheduleSettings {
+                name: "Night Shift".to_string(),
+                time_ranges: vec![time_range(22, 0, 6, 0)],
+                days_of_week: vec![Weekday::Mon],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // Monday night, the range started on Monday and ends on Tuesday morning
+        let now = test_local_datetime(2025, 9, 1, 23, 0, 0);
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 2, 6, 0, 1))
+        );
+
+        // Tuesday 03:00 is still covered by Monday's range
+        let now = test_local_datetime(2025, 9, 2, 3, 0, 0);
+        assert_eq!(
+            get_active_schedule(&config, now.time(), now.weekday()).map(|s| s.name.as_str()),
+            Some("Night Shift")
+        );
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 2, 6, 0, 1))
+        );
+
+        // After the range ends, the next activation is the following Monday
+        let now = test_local_datetime(2025, 9, 2, 7, 0, 0);
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 8, 22, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_multiple_ranges() {
+        let config = AppConfig {
+            schedules: vec![ScheduleSettings {
+                time_ranges: vec![time_range(9, 0, 12, 0), time_range(13, 30, 18, 0)],
+                days_of_week: all_weekdays(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let now = test_local_datetime(2025, 9, 1, 12, 30, 0);
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(
+            boundary,
+            Some(test_datetime_with_local(2025, 9, 1, 13, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_none_for_full_day_every_day() {
+        let config = AppConfig {
+            schedules: vec![ScheduleSettings {
+                time_ranges: vec![full_time_range()],
+                days_of_week: all_weekdays(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+
+        assert!(next_schedule_boundary(&config, &now).is_none());
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_in_other_timezone() {
+        let config = create_test_config();
+        // Friday 23:30 UTC is already Saturday 01:30 in Berlin (CEST, UTC+2)
+        let now = test_datetime(2025, 9, 5, 23, 30, 0).with_timezone(&Berlin);
+
+        // The weekend schedule starts at 10:00 Berlin time
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(boundary, Some(test_datetime(2025, 9, 6, 8, 0, 0)));
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_across_dst_changes() {
+        let config = AppConfig {
+            schedules: vec![ScheduleSettings {
+                time_ranges: vec![time_range(2, 30, 8, 0)],
+                days_of_week: vec![Weekday::Sun],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // 2025-03-30: clocks skip from 02:00 to 03:00, the start moves to 03:30 CEST
+        let now = Berlin.with_ymd_and_hms(2025, 3, 30, 1, 0, 0).unwrap();
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(boundary, Some(test_datetime(2025, 3, 30, 1, 30, 0)));
+
+        // 2025-10-26: 02:30 occurs twice, the schedule starts at the first one (CEST)
+        let now = Berlin.with_ymd_and_hms(2025, 10, 26, 1, 0, 0).unwrap();
+        let boundary = next_schedule_boundary(&config, &now);
+        assert_eq!(boundary, Some(test_datetime(2025, 10, 26, 0, 30, 0)));
+    }
+
+    #[test]
+    fn test_next_schedule_boundary_none_without_enabled_schedules() {
+        let config = AppConfig {
+            schedules: vec![],
+            ..Default::default()
+        };
+        let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+
+        assert!(next_schedule_boundary(&config, &now).is_none());
+    }
 }
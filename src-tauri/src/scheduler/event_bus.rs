@@ -0,0 +1,205 @@
+//! Typed events published by the scheduler
+//!
+//! Schedulers publish [`DomainEvent`]s on an [`EventBus`] instead of emitting
+//! Tauri events by name. Any number of subscribers observe them without the
+//! schedulers knowing about each other:
+//!
+//! ```text
+//!   BreakScheduler ─┐                        ┌──► Frontend bridge (Tauri events)
+//!   AttentionTimer ─┼──► EventBus (broadcast)┼──► Tray menu
+//!   Manager ────────┤                        └──► ...
+//!   Supervisor ─────┘
+//! ```
+//!
+//! The frontend keeps receiving the same event names and payloads, forwarded
+//! by [`spawn_frontend_bridge`].
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use super::event_emitter::EventEmitter;
+use super::models::{BreakActivity, BreakCredit, SchedulerEvent, SchedulerStatus, TaskFailure};
+use crate::core::history::HistoryEntry;
+
+/// Number of events kept for subscribers that fall behind
+const EVENT_BUS_CAPACITY: usize = 256;
+
+/// Something that happened in the scheduler
+///
+/// Serializes to the payload of the matching frontend event.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum DomainEvent {
+    /// The scheduler status changed
+    Status(Box<SchedulerStatus>),
+    /// A break or attention prompt is shown
+    PromptStarted(SchedulerEvent),
+    /// A postpone was refused because the break was postponed too often
+    PostponeLimitReached,
+    /// Time away from the computer was counted as a break
+    BreakCredited(BreakCredit),
+    /// Input was detected during a break
+    BreakActivity(BreakActivity),
+    /// The scheduler was paused for its first reason
+    Paused,
+    /// The last pause reason was cleared
+    Resumed,
+    /// A scheduler task panicked and will be restarted
+    TaskFailed(TaskFailure),
+    /// A prompt went through a lifecycle transition, already recorded in the history
+    PromptTransition(HistoryEntry),
+}
+
+impl DomainEvent {
+    /// Name of the event sent to the frontend
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::Status(_) => "scheduler-status",
+            DomainEvent::PromptStarted(_) => "scheduler-event",
+            DomainEvent::PostponeLimitReached => "postpone-limit-reached",
+            DomainEvent::BreakCredited(_) => "break-credited",
+            DomainEvent::BreakActivity(_) => "break-activity",
+            DomainEvent::Paused => "scheduler-paused",
+            DomainEvent::Resumed => "scheduler-resumed",
+            DomainEvent::TaskFailed(_) => "scheduler-task-failed",
+            DomainEvent::PromptTransition(_) => "prompt-transition",
+        }
+    }
+}
+
+/// Broadcast channel of [`DomainEvent`]s
+///
+/// Cheap to clone, all clones publish to the same subscribers.
+#[derive(Clone)]
+pub struct EventBus {
+    tx: broadcast::Sender<DomainEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    #[must_use]
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        Self { tx }
+    }
+
+    /// Publish `event` to every current subscriber
+    pub fn publish(&self, event: DomainEvent) {
+        tracing::trace!("Publishing {}", event.name());
+        // Nobody listening is fine, e.g. before the frontend bridge starts
+        let _ = self.tx.send(event);
+    }
+
+    /// Receive the events published from now on
+    #[must_use]
+    pub fn subscribe(&self) -> Subscription {
+        Subscription(self.tx.subscribe())
+    }
+}
+
+impl EventEmitter for EventBus {
+    fn emit(&self, event: DomainEvent) {
+        self.publish(event);
+    }
+}
+
+/// Events received by one subscriber, in the order they were published
+pub struct Subscription(broadcast::Receiver<DomainEvent>);
+
+impl Subscription {
+    /// Wait for the next event, `None` once the bus is gone
+    ///
+    /// A subscriber too slow to keep up misses the oldest events, which is
+    /// logged and otherwise ignored.
+    pub async fn recv(&mut self) -> Option<DomainEvent> {
+        loop {
+            match self.0.recv().await {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(missed)) => {
+                    tracing::warn!("Event subscriber fell behind, missed {missed} event(s)");
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+/// Forward every event to the frontend as a Tauri event
+pub fn spawn_frontend_bridge<R: Runtime>(app_handle: AppHandle<R>, events: &EventBus) {
+    let mut subscription = events.subscribe();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = subscription.recv().await {
+            if let Err(e) = app_handle.emit(event.name(), &event) {
+                tracing::warn!("Failed to emit {}: {e}", event.name());
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::schedule::BreakId;
+
+    #[tokio::test]
+    async fn subscribers_receive_events_in_order() {
+        let bus = EventBus::new();
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+
+        bus.publish(DomainEvent::Paused);
+        bus.publish(DomainEvent::Resumed);
+
+        for subscription in [&mut first, &mut second] {
+            assert!(matches!(
+                subscription.recv().await,
+                Some(DomainEvent::Paused)
+            ));
+            assert!(matches!(
+                subscription.recv().await,
+                Some(DomainEvent::Resumed)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn lagging_subscriber_skips_to_retained_events() {
+        let bus = EventBus::new();
+        let mut subscription = bus.subscribe();
+
+        for _ in 0..=EVENT_BUS_CAPACITY {
+            bus.publish(DomainEvent::Paused);
+        }
+        bus.publish(DomainEvent::Resumed);
+
+        let mut received = 0;
+        while let Some(event) = subscription.recv().await {
+            received += 1;
+            if matches!(event, DomainEvent::Resumed) {
+                break;
+            }
+        }
+        assert_eq!(received, EVENT_BUS_CAPACITY);
+    }
+
+    #[test]
+    fn serializes_to_frontend_payload() {
+        let event = DomainEvent::PromptStarted(SchedulerEvent::MiniBreak(BreakId::new()));
+        assert_eq!(event.name(), "scheduler-event");
+        assert_eq!(
+            serde_json::to_value(&event).unwrap()["type"],
+            serde_json::json!("miniBreak")
+        );
+        assert_eq!(
+            serde_json::to_value(DomainEvent::PostponeLimitReached).unwrap(),
+            serde_json::Value::Null
+        );
+    }
+}
@@ -2,68 +2,38 @@
 //!
 //! This module provides a trait-based abstraction for event emission,
 //! allowing schedulers to be tested without requiring a real Tauri `AppHandle`.
+//! In the app, events go to the [`EventBus`](super::event_bus::EventBus).
 
-#[cfg(test)]
+#[cfg(any(test, feature = "simulator"))]
 use std::sync::Arc;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "simulator"))]
 use parking_lot::Mutex;
 
-use serde::Serialize;
-use tauri::{AppHandle, Emitter};
+use super::event_bus::DomainEvent;
 
 /// Trait for emitting events from schedulers
 ///
 /// This abstraction allows for dependency injection of event emission,
 /// making schedulers testable without requiring a full Tauri runtime.
-///
-/// The `emit` method signature matches Tauri's `Emitter::emit` for consistency.
 pub trait EventEmitter: Send + Sync {
-    /// Emit an event with the given name and payload
-    ///
-    /// # Arguments
-    /// * `event` - The event name (e.g., "scheduler-event")
-    /// * `payload` - The event payload as a serializable value
-    ///
-    /// # Returns
-    /// Ok(()) if emission succeeded, Err with message if failed
-    fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<(), String>;
-}
-
-/// Production implementation using Tauri's `AppHandle`
-///
-/// This is the real implementation used in the application.
-/// It wraps `AppHandle.emit()` with our `EventEmitter` interface.
-pub struct TauriEventEmitter {
-    app_handle: AppHandle,
-}
-
-impl TauriEventEmitter {
-    /// Create a new `TauriEventEmitter`
-    pub fn new(app_handle: AppHandle) -> Self {
-        Self { app_handle }
-    }
-}
-
-impl EventEmitter for TauriEventEmitter {
-    fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<(), String> {
-        self.app_handle
-            .emit(event, payload)
-            .map_err(|e| e.to_string())
-    }
+    /// Emit `event` to everyone observing the scheduler
+    fn emit(&self, event: DomainEvent);
 }
 
 /// Test implementation that records emitted events
 ///
-/// This implementation stores all emitted events in a Vec,
-/// allowing tests to verify that correct events were emitted.
-#[cfg(test)]
+/// This implementation stores all emitted events in a Vec, by the name and
+/// payload the frontend receives, allowing tests to verify that correct events
+/// were emitted.
+#[cfg(any(test, feature = "simulator"))]
 #[derive(Clone)]
 pub struct TestEventEmitter {
     events: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "simulator"))]
+#[cfg_attr(not(test), allow(dead_code))] // Only a subset is used by the simulator
 impl TestEventEmitter {
     /// Create a new `TestEventEmitter`
     pub fn new() -> Self {
@@ -106,17 +76,17 @@ impl TestEventEmitter {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "simulator"))]
 impl EventEmitter for TestEventEmitter {
-    fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<(), String> {
-        let json_value = serde_json::to_value(payload)
-            .map_err(|e| format!("Failed to serialize payload: {e}"))?;
-        self.events.lock().push((event.to_string(), json_value));
-        Ok(())
+    fn emit(&self, event: DomainEvent) {
+        let json_value = serde_json::to_value(&event).expect("Failed to serialize payload");
+        self.events
+            .lock()
+            .push((event.name().to_string(), json_value));
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "simulator"))]
 impl Default for TestEventEmitter {
     fn default() -> Self {
         Self::new()
@@ -126,40 +96,49 @@ impl Default for TestEventEmitter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json::json;
+    use crate::core::schedule::BreakId;
+    use crate::scheduler::SchedulerEvent;
+    use crate::scheduler::models::BreakCredit;
+
+    fn credit(paused_s: u32) -> DomainEvent {
+        DomainEvent::BreakCredited(BreakCredit {
+            event: SchedulerEvent::MiniBreak(BreakId::new()),
+            paused_s,
+        })
+    }
 
     #[test]
     fn test_event_emitter_records_events() {
         let emitter = TestEventEmitter::new();
 
-        emitter.emit("test-event", json!({"key": "value"})).unwrap();
-        emitter.emit("another-event", json!({"num": 42})).unwrap();
+        emitter.emit(DomainEvent::Paused);
+        emitter.emit(DomainEvent::Resumed);
 
         let events = emitter.get_events();
         assert_eq!(events.len(), 2);
-        assert_eq!(events[0].0, "test-event");
-        assert_eq!(events[1].0, "another-event");
+        assert_eq!(events[0].0, "scheduler-paused");
+        assert_eq!(events[1].0, "scheduler-resumed");
     }
 
     #[test]
     fn test_get_events_by_name() {
         let emitter = TestEventEmitter::new();
 
-        emitter.emit("event-a", json!({"id": 1})).unwrap();
-        emitter.emit("event-b", json!({"id": 2})).unwrap();
-        emitter.emit("event-a", json!({"id": 3})).unwrap();
+        emitter.emit(credit(1));
+        emitter.emit(DomainEvent::Paused);
+        emitter.emit(credit(3));
 
-        let events = emitter.get_events_by_name("event-a");
+        let events = emitter.get_events_by_name("break-credited");
         assert_eq!(events.len(), 2);
-        assert_eq!(events[0]["id"], 1);
-        assert_eq!(events[1]["id"], 3);
+        assert_eq!(events[0]["pausedS"], 1);
+        assert_eq!(events[1]["pausedS"], 3);
     }
 
     #[test]
     fn test_clear_events() {
         let emitter = TestEventEmitter::new();
 
-        emitter.emit("test", json!({})).unwrap();
+        emitter.emit(DomainEvent::Paused);
         assert_eq!(emitter.event_count(), 1);
 
         emitter.clear();
@@ -170,9 +149,9 @@ mod tests {
     fn test_has_event() {
         let emitter = TestEventEmitter::new();
 
-        emitter.emit("exists", json!({})).unwrap();
+        emitter.emit(DomainEvent::PostponeLimitReached);
 
-        assert!(emitter.has_event("exists"));
+        assert!(emitter.has_event("postpone-limit-reached"));
         assert!(!emitter.has_event("does-not-exist"));
     }
 }
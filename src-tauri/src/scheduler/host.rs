@@ -0,0 +1,160 @@
+//! What the schedulers need from the app they run in
+//!
+//! The schedulers read the config, keep their stores up to date and show
+//! prompts through a [`SchedulerHost`] instead of a Tauri [`AppHandle`]:
+//!
+//! - [`AppHandle`]: the app's managed state, prompt windows and notifications
+//! - [`HeadlessHost`]: a config and an in-memory history, for simulations that
+//!   run without any Tauri runtime
+
+use std::future::Future;
+#[cfg(any(test, feature = "simulator"))]
+use std::sync::Arc;
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::models::{Escalation, SchedulerEvent};
+use super::progress::ProgressStore;
+#[cfg(any(test, feature = "simulator"))]
+use crate::config::AppConfig;
+use crate::config::SharedConfig;
+use crate::core::history::HistoryStore;
+use crate::core::payload::PromptPayloadStore;
+use crate::platform::{create_prompt_windows, send_break_notification};
+
+/// Config, stores and windows available to the schedulers
+pub trait SchedulerHost: Clone + Send + Sync + 'static {
+    /// The current configuration
+    fn config(&self) -> &SharedConfig;
+
+    /// Where the break cycle is saved, `None` if it is not
+    fn progress_store(&self) -> Option<&ProgressStore>;
+
+    /// Payloads of the open prompt windows, `None` without windows
+    fn payload_store(&self) -> Option<&PromptPayloadStore>;
+
+    /// Where prompt transitions are recorded, `None` if they are not
+    fn history_store(&self) -> Option<&HistoryStore>;
+
+    /// Notify the user that a break of `break_type` starts in `seconds`
+    fn notify_break(
+        &self,
+        break_type: &str,
+        seconds: u32,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+
+    /// Open the prompt windows of `event`
+    fn show_prompt(
+        &self,
+        event: SchedulerEvent,
+        postpone_count: u8,
+        escalation: Option<Escalation>,
+        task: Option<String>,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+
+    /// Close the prompt windows whose label starts with `prefix`
+    fn close_prompts(&self, prefix: &str);
+}
+
+impl<R: Runtime> SchedulerHost for AppHandle<R> {
+    fn config(&self) -> &SharedConfig {
+        self.state::<SharedConfig>().inner()
+    }
+
+    fn progress_store(&self) -> Option<&ProgressStore> {
+        self.try_state::<ProgressStore>().map(|store| store.inner())
+    }
+
+    fn payload_store(&self) -> Option<&PromptPayloadStore> {
+        self.try_state::<PromptPayloadStore>()
+            .map(|store| store.inner())
+    }
+
+    fn history_store(&self) -> Option<&HistoryStore> {
+        self.try_state::<HistoryStore>().map(|store| store.inner())
+    }
+
+    fn notify_break(
+        &self,
+        break_type: &str,
+        seconds: u32,
+    ) -> impl Future<Output = Result<(), String>> + Send {
+        send_break_notification(self, break_type, seconds)
+    }
+
+    fn show_prompt(
+        &self,
+        event: SchedulerEvent,
+        postpone_count: u8,
+        escalation: Option<Escalation>,
+        task: Option<String>,
+    ) -> impl Future<Output = Result<(), String>> + Send {
+        create_prompt_windows(self, event, postpone_count, escalation, task)
+    }
+
+    fn close_prompts(&self, prefix: &str) {
+        for (label, window) in self.webview_windows() {
+            if label.starts_with(prefix) {
+                tracing::debug!("Closing prompt window: {label}");
+                let _ = window.close();
+            }
+        }
+    }
+}
+
+/// Host without windows, for simulations
+///
+/// History is kept in memory and the break cycle is not saved.
+#[cfg(any(test, feature = "simulator"))]
+#[derive(Clone)]
+pub struct HeadlessHost {
+    config: Arc<SharedConfig>,
+    history: Arc<HistoryStore>,
+}
+
+#[cfg(any(test, feature = "simulator"))]
+impl HeadlessHost {
+    /// Create a host running the schedulers with `config`
+    #[must_use]
+    pub fn new(config: AppConfig) -> Self {
+        Self {
+            config: Arc::new(SharedConfig::from(config)),
+            history: Arc::new(HistoryStore::in_memory()),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "simulator"))]
+impl SchedulerHost for HeadlessHost {
+    fn config(&self) -> &SharedConfig {
+        &self.config
+    }
+
+    fn progress_store(&self) -> Option<&ProgressStore> {
+        None
+    }
+
+    fn payload_store(&self) -> Option<&PromptPayloadStore> {
+        None
+    }
+
+    fn history_store(&self) -> Option<&HistoryStore> {
+        Some(&self.history)
+    }
+
+    async fn notify_break(&self, _break_type: &str, _seconds: u32) -> Result<(), String> {
+        Ok(())
+    }
+
+    async fn show_prompt(
+        &self,
+        _event: SchedulerEvent,
+        _postpone_count: u8,
+        _escalation: Option<Escalation>,
+        _task: Option<String>,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn close_prompts(&self, _prefix: &str) {}
+}
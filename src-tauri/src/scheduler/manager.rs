@@ -1,11 +1,18 @@
-use tauri::{AppHandle, Emitter, Runtime};
+use chrono::{DateTime, Utc};
+use futures::future::pending;
+use tauri::AppHandle;
 use tokio::sync::{mpsc, watch};
+use tokio::time::sleep;
 
 use super::attention_timer::AttentionTimer;
 use super::break_scheduler::BreakScheduler;
-use super::event_emitter::TauriEventEmitter;
-use super::models::{Command, PauseReason, SchedulerStatus};
+use super::clock::{Clock, SystemClock};
+use super::event_bus::{DomainEvent, EventBus};
+use super::event_emitter::EventEmitter;
+use super::host::SchedulerHost;
+use super::models::{Command, PauseDuration, PauseReason, Responder, SchedulerTask};
 use super::shared_state::{SharedState, create_shared_state};
+use super::supervisor::{TASK_COMMAND_BUFFER, supervise};
 use crate::scheduler::SchedulerEvent;
 
 /// Top-level scheduler manager that coordinates break scheduling and attention timers
@@ -18,68 +25,136 @@ impl SchedulerManager {
     /// - Command sender for external control
     /// - Shutdown sender for graceful shutdown
     /// - Shared scheduler state for monitors and status queries
-    pub fn init(app_handle: &AppHandle) -> (mpsc::Sender<Command>, watch::Sender<()>, SharedState) {
-        let (cmd_tx, cmd_rx) = mpsc::channel::<Command>(32);
+    ///
+    /// Each task runs under a supervisor that restarts it if it panics. All of
+    /// them publish what happens on `events`.
+    pub fn init(
+        app_handle: &AppHandle,
+        events: &EventBus,
+    ) -> (mpsc::Sender<Command>, watch::Sender<()>, SharedState) {
+        let (cmd_tx, cmd_rx) = mpsc::channel::<Command>(TASK_COMMAND_BUFFER);
         let (shutdown_tx, shutdown_rx) = watch::channel(());
 
         // Create shared state
         let shared_state = create_shared_state();
 
         // Create separate channels for each scheduler
-        let (break_cmd_tx, break_cmd_rx) = mpsc::channel::<Command>(32);
-        let (attention_cmd_tx, attention_cmd_rx) = mpsc::channel::<Command>(32);
+        let (break_cmd_tx, break_cmd_rx) = mpsc::channel::<Command>(TASK_COMMAND_BUFFER);
+        let (attention_cmd_tx, attention_cmd_rx) = mpsc::channel::<Command>(TASK_COMMAND_BUFFER);
 
-        // Spawn break scheduler
+        // Spawn break scheduler, restored from saved progress on every (re)start
         let break_scheduler_handle = app_handle.clone();
-        let break_event_emitter = TauriEventEmitter::new(app_handle.clone());
         let break_shutdown_rx = shutdown_rx.clone();
         let break_shared_state = shared_state.clone();
-        tokio::spawn(async move {
+        let break_events = events.clone();
+        let spawn_break_scheduler = move |cmd_rx| {
             let mut scheduler = BreakScheduler::new(
-                break_scheduler_handle,
-                break_event_emitter,
-                break_shutdown_rx,
-                break_shared_state,
+                break_scheduler_handle.clone(),
+                break_events.clone(),
+                break_shutdown_rx.clone(),
+                break_shared_state.clone(),
+                SystemClock,
             );
-            scheduler.run(break_cmd_rx).await;
-        });
+            tokio::spawn(async move {
+                scheduler.restore_progress().await;
+                scheduler.run(cmd_rx).await;
+            })
+        };
+        tokio::spawn(supervise(
+            SchedulerTask::BreakScheduler,
+            break_cmd_rx,
+            spawn_break_scheduler,
+            shared_state.clone(),
+            events.clone(),
+            shutdown_rx.clone(),
+        ));
 
         // Spawn attention timer
         let attention_timer_handle = app_handle.clone();
-        let attention_event_emitter = TauriEventEmitter::new(app_handle.clone());
         let attention_shutdown_rx = shutdown_rx.clone();
         let attention_shared_state = shared_state.clone();
-        tokio::spawn(async move {
+        let attention_events = events.clone();
+        let spawn_attention_timer = move |cmd_rx| {
             let mut timer = AttentionTimer::new(
-                attention_timer_handle,
-                attention_event_emitter,
-                attention_shutdown_rx,
-                attention_shared_state,
+                attention_timer_handle.clone(),
+                attention_events.clone(),
+                attention_shutdown_rx.clone(),
+                attention_shared_state.clone(),
+                SystemClock,
             );
-            timer.run(attention_cmd_rx).await;
-        });
+            tokio::spawn(async move {
+                timer.run(cmd_rx).await;
+            })
+        };
+        tokio::spawn(supervise(
+            SchedulerTask::AttentionTimer,
+            attention_cmd_rx,
+            spawn_attention_timer,
+            shared_state.clone(),
+            events.clone(),
+            shutdown_rx.clone(),
+        ));
 
         // Spawn command broadcaster
         let router_shutdown_rx = shutdown_rx.clone();
         let router_shared_state = shared_state.clone();
         let router_app_handle = app_handle.clone();
-        tokio::spawn(async move {
-            broadcast_commands(
+        let router_targets = CommandTargets {
+            break_cmd_tx,
+            attention_cmd_tx,
+            events: events.clone(),
+        };
+        let spawn_broadcaster = move |cmd_rx| {
+            tokio::spawn(broadcast_commands(
                 cmd_rx,
-                break_cmd_tx,
-                attention_cmd_tx,
-                router_shutdown_rx,
-                router_shared_state,
-                router_app_handle,
-            )
-            .await;
-        });
+                router_targets.clone(),
+                router_shutdown_rx.clone(),
+                router_shared_state.clone(),
+                router_app_handle.clone(),
+                SystemClock,
+            ))
+        };
+        tokio::spawn(supervise(
+            SchedulerTask::CommandBroadcaster,
+            cmd_rx,
+            spawn_broadcaster,
+            shared_state.clone(),
+            events.clone(),
+            shutdown_rx,
+        ));
 
         tracing::info!("SchedulerManager initialized with shared state management");
         (cmd_tx, shutdown_tx, shared_state)
     }
 }
 
+/// Where the command broadcaster routes commands and publishes pause events
+#[derive(Clone)]
+pub(crate) struct CommandTargets<E> {
+    /// Commands for [`BreakScheduler`]
+    pub break_cmd_tx: mpsc::Sender<Command>,
+    /// Commands for [`AttentionTimer`]
+    pub attention_cmd_tx: mpsc::Sender<Command>,
+    /// Receives [`DomainEvent::Paused`] and [`DomainEvent::Resumed`]
+    pub events: E,
+}
+
+impl<E: EventEmitter> CommandTargets<E> {
+    /// Send `cmd` to every scheduler
+    async fn broadcast(&self, cmd: Command) {
+        let _ = self.break_cmd_tx.send(cmd.clone()).await;
+        let _ = self.attention_cmd_tx.send(cmd).await;
+    }
+
+    /// Have `BreakScheduler` emit its status after the pause reasons or expiry changed
+    async fn refresh_status(&self, responder: Option<Responder>) {
+        let _ = self
+            .break_cmd_tx
+            .send(Command::RequestBreakStatus(responder))
+            .await;
+    }
+}
+
 /// Broadcast incoming commands to appropriate schedulers
 ///
 /// # Command Processing Architecture
@@ -92,9 +167,14 @@ impl SchedulerManager {
 /// These commands affect global state and all schedulers:
 /// - **Pause(reason)**: Updates [`SharedState`], forwards to all schedulers
 /// - **Resume(reason)**: Updates [`SharedState`], forwards only if all reasons cleared
+/// - **PauseFor(duration)**: Pauses with [`PauseReason::Manual`] and resumes when the
+///   duration ends
 ///
 /// Flow: Command → Update [`SharedState`] → Forward to schedulers → Emit events
 ///
+/// Their responder is answered by [`BreakScheduler`] with its status afterwards,
+/// whether or not the paused state changed.
+///
 /// ## 2. Broadcast Commands (Forwarded to All)
 ///
 /// These commands are sent to all schedulers for processing:
@@ -108,6 +188,7 @@ impl SchedulerManager {
 /// - **TriggerEvent(event)**: Routed by event type (Break → [`BreakScheduler`], Attention → [`AttentionTimer`])
 /// - **PromptFinished(event)**: Routed by event type
 /// - **PostponeBreak/SkipBreak/RequestBreakStatus**: Only to [`BreakScheduler`]
+/// - **StartPomodoro/StopPomodoro**: Only to [`BreakScheduler`]
 ///
 /// Flow: Command → Pattern match → Forward to appropriate scheduler
 ///
@@ -116,72 +197,109 @@ impl SchedulerManager {
 /// - **[`SharedState`]**: Single source of truth for pause reasons and sessions
 /// - **Schedulers**: Implement business logic and internal state machines
 /// - **Manager**: Coordinates state updates and command routing
-pub(crate) async fn broadcast_commands<R: Runtime>(
+pub(crate) async fn broadcast_commands<H: SchedulerHost, E: EventEmitter, C: Clock>(
     mut cmd_rx: mpsc::Receiver<Command>,
-    break_cmd_tx: mpsc::Sender<Command>,
-    attention_cmd_tx: mpsc::Sender<Command>,
+    targets: CommandTargets<E>,
     mut shutdown_rx: watch::Receiver<()>,
     shared_state: SharedState,
-    app_handle: AppHandle<R>,
+    host: H,
+    clock: C,
 ) {
     loop {
+        // Timed manual pauses resume when they expire
+        let pause_until = shared_state.read().manual_pause_until();
+        let pause_expired = async {
+            match pause_until {
+                Some(until) => {
+                    let remaining = (until - clock.now()).to_std().unwrap_or_default();
+                    sleep(remaining).await;
+                }
+                None => pending().await,
+            }
+        };
+
         tokio::select! {
             biased;
             _ = shutdown_rx.changed() => {
                 tracing::info!("Command broadcaster shutting down");
                 break;
             }
-            Some(cmd) = cmd_rx.recv() => {
+            cmd = cmd_rx.recv() => {
+                let Some(cmd) = cmd else {
+                    tracing::info!("Command channel closed, broadcaster shutting down");
+                    break;
+                };
                 tracing::debug!("Routing command: {cmd}");
 
                 match cmd {
                     // === GLOBAL COMMANDS: Process + Forward ===
 
-                    Command::Pause(reason) => {
+                    Command::Pause(reason, responder) => {
+                        if reason == PauseReason::Manual {
+                            // An indefinite pause replaces a timed one
+                            shared_state.write().set_manual_pause_until(None);
+                        }
                         handle_pause_command(
                             reason,
+                            responder,
                             &shared_state,
-                            &break_cmd_tx,
-                            &attention_cmd_tx,
-                            &app_handle,
+                            &targets,
                         ).await;
                     }
 
-                    Command::Resume(reason) => {
+                    Command::PauseFor(duration, responder) => {
+                        let until = manual_pause_expiry(duration, &host, &clock).await;
+                        tracing::info!("Pausing scheduler for {duration} (until {until:?})");
+                        handle_pause_for_command(
+                            until,
+                            responder,
+                            &shared_state,
+                            &targets,
+                        ).await;
+                    }
+
+                    Command::Resume(reason, responder) => {
                         handle_resume_command(
                             reason,
+                            responder,
                             &shared_state,
-                            &break_cmd_tx,
-                            &attention_cmd_tx,
-                            &app_handle,
+                            &targets,
                         ).await;
                     }
 
                     // === BROADCAST COMMANDS: Forward to All ===
 
-                    Command::UpdateConfig(_) => {
-                        tracing::debug!("Broadcasting UpdateConfig to all schedulers");
-                        let _ = break_cmd_tx.send(cmd.clone()).await;
-                        let _ = attention_cmd_tx.send(cmd).await;
+                    Command::UpdateConfig(_) | Command::SystemResumed(_) => {
+                        tracing::debug!("Broadcasting {cmd} to all schedulers");
+                        targets.broadcast(cmd).await;
                     }
 
                     // === TARGETED COMMANDS: Route by Event Type ===
 
-                    Command::TriggerEvent(event) | Command::PromptFinished(event)  => {
-                        route_event_command(cmd, event, &break_cmd_tx, &attention_cmd_tx).await;
+                    Command::TriggerEvent(event, _) | Command::PromptFinished(event)  => {
+                        route_event_command(cmd, event, &targets).await;
                     }
 
                     // === BREAK-SPECIFIC COMMANDS ===
 
-                    Command::RequestBreakStatus | Command::PostponeBreak | Command::SkipBreak => {
+                    Command::RequestBreakStatus(_)
+                    | Command::PostponeBreak(_)
+                    | Command::SkipBreak(_)
+                    | Command::StartPomodoro(..)
+                    | Command::StopPomodoro(_) => {
                         tracing::debug!("Forwarding break-specific command to BreakScheduler");
-                        let _ = break_cmd_tx.send(cmd).await;
+                        let _ = targets.break_cmd_tx.send(cmd).await;
                     }
                 }
             }
-            else => {
-                tracing::info!("Command channel closed, broadcaster shutting down");
-                break;
+            () = pause_expired => {
+                tracing::info!("Timed pause expired, resuming");
+                handle_resume_command(
+                    PauseReason::Manual,
+                    None,
+                    &shared_state,
+                    &targets,
+                ).await;
             }
         }
     }
@@ -192,12 +310,13 @@ pub(crate) async fn broadcast_commands<R: Runtime>(
 /// This implements the "add pause reason" logic:
 /// - If first pause reason → forward to schedulers (trigger pause)
 /// - If additional reason → only update `SharedState` (already paused)
-async fn handle_pause_command<R: Runtime>(
+///
+/// Either way `BreakScheduler` answers `responder` with its status.
+async fn handle_pause_command<E: EventEmitter>(
     reason: PauseReason,
+    responder: Option<Responder>,
     shared_state: &SharedState,
-    break_cmd_tx: &mpsc::Sender<Command>,
-    attention_cmd_tx: &mpsc::Sender<Command>,
-    app_handle: &AppHandle<R>,
+    targets: &CommandTargets<E>,
 ) {
     let should_pause = shared_state.write().add_pause_reason(reason);
 
@@ -205,35 +324,63 @@ async fn handle_pause_command<R: Runtime>(
         // State transition: Running → Paused
         tracing::info!("Scheduler paused (first reason: {reason})");
 
-        // Emit events for frontend
-        let status = SchedulerStatus {
-            paused: true,
-            next_event: None,
-            mini_break_counter: 0, // Counter is not relevant when paused
-        };
-        let _ = app_handle.emit("scheduler-status", &status);
-        let _ = app_handle.emit("scheduler-paused", ());
+        // Emit event for frontend (BreakScheduler will emit detailed status)
+        targets.events.emit(DomainEvent::Paused);
 
         // Forward to all schedulers to update their internal state
-        let _ = break_cmd_tx.send(Command::Pause(reason)).await;
-        let _ = attention_cmd_tx.send(Command::Pause(reason)).await;
+        let _ = targets
+            .break_cmd_tx
+            .send(Command::Pause(reason, responder))
+            .await;
+        let _ = targets
+            .attention_cmd_tx
+            .send(Command::Pause(reason, None))
+            .await;
     } else {
         // Already paused, just added another reason
         tracing::debug!("Added pause reason {reason} (already paused)");
+        targets.refresh_status(responder).await;
     }
 }
 
+/// When a manual pause for `duration` starting now ends (`None` if it does not)
+async fn manual_pause_expiry<H: SchedulerHost, C: Clock>(
+    duration: PauseDuration,
+    host: &H,
+    clock: &C,
+) -> Option<DateTime<Utc>> {
+    let config_guard = host.config().read().await;
+    duration.expiry(&config_guard, &clock.now_local())
+}
+
+/// Handle `PauseFor` command: Record when the manual pause ends, then pause
+///
+/// The broadcaster resumes with [`PauseReason::Manual`] once `until` is reached.
+/// A new `PauseFor` replaces the expiry of a previous one.
+async fn handle_pause_for_command<E: EventEmitter>(
+    until: Option<DateTime<Utc>>,
+    responder: Option<Responder>,
+    shared_state: &SharedState,
+    targets: &CommandTargets<E>,
+) {
+    // Set before pausing, so the status emitted for the pause shows the expiry
+    shared_state.write().set_manual_pause_until(until);
+
+    handle_pause_command(PauseReason::Manual, responder, shared_state, targets).await;
+}
+
 /// Handle Resume command: Update `SharedState` and forward if all reasons cleared
 ///
 /// This implements the "remove pause reason" logic:
 /// - If last reason removed → forward to schedulers (trigger resume)
 /// - If reasons remain → only update `SharedState` (stay paused)
-async fn handle_resume_command<R: Runtime>(
+///
+/// Either way `BreakScheduler` answers `responder` with its status.
+async fn handle_resume_command<E: EventEmitter>(
     reason: PauseReason,
+    responder: Option<Responder>,
     shared_state: &SharedState,
-    break_cmd_tx: &mpsc::Sender<Command>,
-    attention_cmd_tx: &mpsc::Sender<Command>,
-    app_handle: &AppHandle<R>,
+    targets: &CommandTargets<E>,
 ) {
     let should_resume = shared_state.write().remove_pause_reason(reason);
 
@@ -242,14 +389,21 @@ async fn handle_resume_command<R: Runtime>(
         tracing::info!("Scheduler resumed (all pause reasons cleared)");
 
         // Emit resume event (schedulers will emit detailed status)
-        let _ = app_handle.emit("scheduler-resumed", ());
+        targets.events.emit(DomainEvent::Resumed);
 
         // Forward to all schedulers to recalculate next events
-        let _ = break_cmd_tx.send(Command::Resume(reason)).await;
-        let _ = attention_cmd_tx.send(Command::Resume(reason)).await;
+        let _ = targets
+            .break_cmd_tx
+            .send(Command::Resume(reason, responder))
+            .await;
+        let _ = targets
+            .attention_cmd_tx
+            .send(Command::Resume(reason, None))
+            .await;
     } else {
         // Still paused (other reasons remain)
         tracing::debug!("Removed pause reason {reason} (still paused)");
+        targets.refresh_status(responder).await;
     }
 }
 
@@ -257,20 +411,19 @@ async fn handle_resume_command<R: Runtime>(
 ///
 /// - Break events (MiniBreak/LongBreak) → `BreakScheduler`
 /// - Attention events → `AttentionTimer`
-async fn route_event_command(
+async fn route_event_command<E: EventEmitter>(
     cmd: Command,
     event: SchedulerEvent,
-    break_cmd_tx: &mpsc::Sender<Command>,
-    attention_cmd_tx: &mpsc::Sender<Command>,
+    targets: &CommandTargets<E>,
 ) {
     if matches!(
         event,
         SchedulerEvent::MiniBreak(_) | SchedulerEvent::LongBreak(_)
     ) {
         tracing::debug!("Routing {event} command to BreakScheduler");
-        let _ = break_cmd_tx.send(cmd).await;
+        let _ = targets.break_cmd_tx.send(cmd).await;
     } else if matches!(event, SchedulerEvent::Attention(_)) {
         tracing::debug!("Routing {event} command to AttentionTimer");
-        let _ = attention_cmd_tx.send(cmd).await;
+        let _ = targets.attention_cmd_tx.send(cmd).await;
     }
 }
@@ -22,7 +22,9 @@
 
 use tokio::sync::mpsc;
 
-use crate::scheduler::models::{Command, PauseReason};
+use crate::scheduler::models::{
+    Command, CommandReply, PauseDuration, PauseReason, Responder, SchedulerStatus,
+};
 use crate::scheduler::test_helpers::manager::*;
 use crate::scheduler::test_helpers::state_machine::advance_time_and_yield;
 use crate::scheduler::test_helpers::*;
@@ -46,7 +48,7 @@ async fn test_first_pause_reason() {
 
     // Add first pause reason
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -78,14 +80,17 @@ async fn test_multiple_pause_reasons() {
 
     // Add multiple pause reasons
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Verify all reasons tracked
@@ -118,15 +123,18 @@ async fn test_partial_resume_stays_paused() {
 
     // Add multiple pause reasons
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Remove one reason
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -160,18 +168,18 @@ async fn test_last_resume_triggers_resume() {
 
     // Add two pause reasons
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Remove both reasons
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -183,7 +191,7 @@ async fn test_last_resume_triggers_resume() {
     }
 
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -218,15 +226,15 @@ async fn test_duplicate_pause_reason_idempotent() {
 
     // Add same reason multiple times
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -239,7 +247,7 @@ async fn test_duplicate_pause_reason_idempotent() {
 
     // Single resume should clear it
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -270,14 +278,14 @@ async fn test_resume_non_existent_reason() {
 
     // Add one reason
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Try to remove different reason
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -314,16 +322,19 @@ async fn test_all_pause_reasons_coexist() {
 
     // Add all pause reasons
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::AppExclusion))
+        .send(Command::Pause(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -358,33 +369,36 @@ async fn test_clear_reasons_different_order() {
 
     // Add in order: Manual, Idle, DND
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Remove in order: DND, Manual, Idle (reverse)
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
     assert!(env.shared_state.read().is_paused());
 
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
     assert!(env.shared_state.read().is_paused());
 
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -446,13 +460,16 @@ async fn test_break_commands_route_correctly() {
     advance_time_and_yield(duration_ms(200)).await;
 
     // Send break-specific commands
-    cmd_tx.send(Command::RequestBreakStatus).await.unwrap();
+    cmd_tx
+        .send(Command::RequestBreakStatus(None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
-    cmd_tx.send(Command::PostponeBreak).await.unwrap();
+    cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
-    cmd_tx.send(Command::SkipBreak).await.unwrap();
+    cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Should not crash - commands routed correctly
@@ -482,11 +499,11 @@ async fn test_rapid_command_sequence() {
     // Send many commands rapidly
     for _ in 0..10 {
         cmd_tx
-            .send(Command::Pause(PauseReason::Manual))
+            .send(Command::Pause(PauseReason::Manual, None))
             .await
             .unwrap();
         cmd_tx
-            .send(Command::Resume(PauseReason::Manual))
+            .send(Command::Resume(PauseReason::Manual, None))
             .await
             .unwrap();
     }
@@ -518,20 +535,23 @@ async fn test_interleaved_pause_reasons() {
 
     // Interleave add/remove of different reasons
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -568,11 +588,11 @@ async fn test_pause_immediate_resume() {
 
     // Pause then immediately resume
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -605,16 +625,19 @@ async fn test_many_reasons_then_clear_all() {
     // Add all reasons multiple times
     for _ in 0..5 {
         cmd_tx
-            .send(Command::Pause(PauseReason::Manual))
+            .send(Command::Pause(PauseReason::Manual, None))
+            .await
+            .unwrap();
+        cmd_tx
+            .send(Command::Pause(PauseReason::UserIdle, None))
             .await
             .unwrap();
         cmd_tx
-            .send(Command::Pause(PauseReason::UserIdle))
+            .send(Command::Pause(PauseReason::Dnd, None))
             .await
             .unwrap();
-        cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
         cmd_tx
-            .send(Command::Pause(PauseReason::AppExclusion))
+            .send(Command::Pause(PauseReason::AppExclusion, None))
             .await
             .unwrap();
     }
@@ -628,19 +651,19 @@ async fn test_many_reasons_then_clear_all() {
 
     // Clear all
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::AppExclusion))
+        .send(Command::Resume(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -656,3 +679,170 @@ async fn test_many_reasons_then_clear_all() {
     drop(cmd_tx);
     drop(env.shutdown_tx);
 }
+
+// ============================================================================
+// Timed Pause Tests
+// ============================================================================
+
+/// **M6.1: Timed Pause Resumes Automatically**
+#[tokio::test(start_paused = true)]
+async fn test_timed_pause_auto_resumes() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+
+    let env = create_manager_test_env(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    spawn_test_manager(&env, cmd_rx).await;
+    advance_time_and_yield(duration_ms(200)).await;
+
+    cmd_tx
+        .send(Command::PauseFor(PauseDuration::Seconds(1800), None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    {
+        let state = env.shared_state.read();
+        assert!(state.pause_reasons().contains(&PauseReason::Manual));
+        assert!(state.manual_pause_until().is_some());
+    }
+
+    // Still paused shortly before the expiry
+    advance_time_and_yield(duration_s(1790)).await;
+    assert!(env.shared_state.read().is_paused());
+
+    advance_time_and_yield(duration_s(20)).await;
+    {
+        let state = env.shared_state.read();
+        assert!(!state.is_paused(), "Timed pause should have expired");
+        assert!(state.manual_pause_until().is_none());
+    }
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(env.shutdown_tx);
+}
+
+/// **M6.2: Indefinite Pause Replaces a Timed One**
+#[tokio::test(start_paused = true)]
+async fn test_indefinite_pause_replaces_timed_pause() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+
+    let env = create_manager_test_env(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    spawn_test_manager(&env, cmd_rx).await;
+    advance_time_and_yield(duration_ms(200)).await;
+
+    cmd_tx
+        .send(Command::PauseFor(PauseDuration::Seconds(600), None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    assert!(env.shared_state.read().manual_pause_until().is_none());
+
+    advance_time_and_yield(duration_s(700)).await;
+    assert!(
+        env.shared_state.read().is_paused(),
+        "Indefinite pause should not expire"
+    );
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(env.shutdown_tx);
+}
+
+/// **M6.3: Expired Timed Pause Keeps Other Reasons**
+#[tokio::test(start_paused = true)]
+async fn test_timed_pause_expiry_keeps_other_reasons() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+
+    let env = create_manager_test_env(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    spawn_test_manager(&env, cmd_rx).await;
+    advance_time_and_yield(duration_ms(200)).await;
+
+    cmd_tx
+        .send(Command::PauseFor(PauseDuration::Seconds(600), None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+
+    advance_time_and_yield(duration_s(601)).await;
+    {
+        let state = env.shared_state.read();
+        assert!(state.is_paused(), "Should stay paused by UserIdle");
+        assert_eq!(state.pause_reasons(), vec![PauseReason::UserIdle]);
+    }
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(env.shutdown_tx);
+}
+
+// ============================================================================
+// Command Reply Tests
+// ============================================================================
+
+/// Send the command built around a responder and return the status it replies with
+async fn request_status(
+    cmd_tx: &mpsc::Sender<Command>,
+    command: impl FnOnce(Option<Responder>) -> Command,
+) -> SchedulerStatus {
+    let (responder, mut reply_rx) = Responder::new();
+    cmd_tx.send(command(Some(responder))).await.unwrap();
+    advance_time_and_yield(duration_ms(200)).await;
+    match reply_rx.try_recv() {
+        Ok(CommandReply::Accepted(Some(status))) => *status,
+        other => panic!("Expected a status reply, got {other:?}"),
+    }
+}
+
+/// **M7.1: Pause and Resume Reply With the Resulting Status**
+///
+/// A manual resume while another reason remains leaves the scheduler paused,
+/// which the reply shows.
+#[tokio::test(start_paused = true)]
+async fn test_pause_and_resume_reply_with_status() {
+    let config = TestConfigBuilder::new().mini_break_interval_s(60).build();
+
+    let env = create_manager_test_env(config);
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+
+    spawn_test_manager(&env, cmd_rx).await;
+    advance_time_and_yield(duration_ms(200)).await;
+
+    let status = request_status(&cmd_tx, |r| Command::Pause(PauseReason::Manual, r)).await;
+    assert!(status.paused);
+
+    let status = request_status(&cmd_tx, |r| {
+        Command::PauseFor(PauseDuration::Seconds(600), r)
+    })
+    .await;
+    assert!(status.paused);
+    assert!(status.pause_expiry.is_some());
+
+    let status = request_status(&cmd_tx, |r| Command::Pause(PauseReason::Dnd, r)).await;
+    assert_eq!(status.pause_reasons.len(), 2);
+
+    // Other reasons remain
+    let status = request_status(&cmd_tx, |r| Command::Resume(PauseReason::Manual, r)).await;
+    assert!(status.paused);
+    assert_eq!(status.pause_reasons, vec![PauseReason::Dnd]);
+
+    let status = request_status(&cmd_tx, |r| Command::Resume(PauseReason::Dnd, r)).await;
+    assert!(!status.paused);
+
+    // Cleanup
+    drop(cmd_tx);
+    drop(env.shutdown_tx);
+}
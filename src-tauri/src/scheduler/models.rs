@@ -1,12 +1,16 @@
 use bitflags::bitflags;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc};
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::sync::Arc;
 use strum_macros::{Display as StrumDisplay, EnumIter, EnumString};
+use tokio::sync::oneshot;
 use ts_rs::TS;
 
+use super::event::{localize, next_schedule_boundary};
 use crate::config::AppConfig;
-use crate::core::schedule::{AttentionId, BreakId};
+use crate::core::schedule::{AttentionId, BreakId, SkipEscalation};
 
 // ============================================================================
 // Event Types - Sent to Frontend
@@ -59,6 +63,15 @@ impl SchedulerEvent {
     pub fn is_long(&self) -> bool {
         matches!(self, SchedulerEvent::LongBreak(_))
     }
+
+    /// Id of the break settings, `None` for attention reminders
+    #[must_use]
+    pub fn break_id(&self) -> Option<BreakId> {
+        match self {
+            SchedulerEvent::MiniBreak(id) | SchedulerEvent::LongBreak(id) => Some(*id),
+            SchedulerEvent::Attention(_) => None,
+        }
+    }
 }
 
 // ============================================================================
@@ -72,6 +85,18 @@ pub(crate) struct BreakInfo {
     pub notification_time: Option<DateTime<Utc>>,
     pub event: SchedulerEvent,
     pub postpone_count: u8,
+    pub escalation: Option<Escalation>,
+}
+
+/// Escalation of a break after breaks were skipped in a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escalation {
+    /// Break settings that were skipped
+    pub skipped: BreakId,
+    /// Number of breaks skipped in a row
+    pub skip_count: u8,
+    /// How the break escalates
+    pub policy: SkipEscalation,
 }
 
 // ============================================================================
@@ -79,37 +104,169 @@ pub(crate) struct BreakInfo {
 // ============================================================================
 
 /// Commands sent to the scheduler from external sources
+///
+/// Commands that can be refused carry an optional [`Responder`] for the
+/// scheduler's [`CommandReply`].
 #[derive(Debug, Clone)]
 pub enum Command {
     /// Update the scheduler configuration
     UpdateConfig(AppConfig),
     /// Pause the break scheduler
-    Pause(PauseReason),
+    Pause(PauseReason, Option<Responder>),
+    /// Pause manually for a limited time, resuming automatically afterwards
+    PauseFor(PauseDuration, Option<Responder>),
     /// Resume the break scheduler
-    Resume(PauseReason),
+    Resume(PauseReason, Option<Responder>),
     /// Postpone the current break
-    PostponeBreak,
+    PostponeBreak(Option<Responder>),
     /// Skip the current break immediately
-    SkipBreak,
+    SkipBreak(Option<Responder>),
     /// Manually trigger a break for testing/debugging
-    TriggerEvent(SchedulerEvent),
+    TriggerEvent(SchedulerEvent, Option<Responder>),
     /// Request the break scheduler to emit its current status
-    RequestBreakStatus,
+    RequestBreakStatus(Option<Responder>),
     /// Notify that a break or an attention (i.e., a prompt) has finished normally
     PromptFinished(SchedulerEvent),
+    /// Notify that the system resumed from suspend after the given seconds asleep
+    SystemResumed(u64),
+    /// Start a Pomodoro session, optionally labelled with the task being worked on
+    StartPomodoro(Option<String>, Option<Responder>),
+    /// Stop the running Pomodoro session
+    StopPomodoro(Option<Responder>),
 }
 
 impl Display for Command {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Command::UpdateConfig(_) => write!(f, "UpdateConfig"),
-            Command::Pause(reason) => write!(f, "Pause({reason})"),
-            Command::Resume(reason) => write!(f, "Resume({reason})"),
-            Command::PostponeBreak => write!(f, "PostponeBreak"),
-            Command::TriggerEvent(event) => write!(f, "TriggerBreak({event})"),
-            Command::SkipBreak => write!(f, "SkipBreak"),
-            Command::RequestBreakStatus => write!(f, "RequestBreakStatus"),
+            Command::Pause(reason, _) => write!(f, "Pause({reason})"),
+            Command::PauseFor(duration, _) => write!(f, "PauseFor({duration})"),
+            Command::Resume(reason, _) => write!(f, "Resume({reason})"),
+            Command::PostponeBreak(_) => write!(f, "PostponeBreak"),
+            Command::TriggerEvent(event, _) => write!(f, "TriggerBreak({event})"),
+            Command::SkipBreak(_) => write!(f, "SkipBreak"),
+            Command::RequestBreakStatus(_) => write!(f, "RequestBreakStatus"),
             Command::PromptFinished(event) => write!(f, "PromptFinished({event})"),
+            Command::SystemResumed(slept_s) => write!(f, "SystemResumed({slept_s}s)"),
+            Command::StartPomodoro(..) => write!(f, "StartPomodoro"),
+            Command::StopPomodoro(_) => write!(f, "StopPomodoro"),
+        }
+    }
+}
+
+/// Reply of the scheduler to a command
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum CommandReply {
+    /// The command was carried out, with the break scheduler's status afterwards
+    /// (none for commands handled by the attention timer)
+    Accepted(Option<Box<SchedulerStatus>>),
+    /// The command was refused
+    Rejected(Rejection),
+}
+
+/// Why the scheduler refused a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, StrumDisplay, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum Rejection {
+    /// The scheduler is paused
+    Paused,
+    /// There is no break to act on
+    NoBreak,
+    /// The break was already postponed as often as allowed
+    PostponeLimitReached,
+    /// No Pomodoro schedule is enabled
+    NoPomodoroSchedule,
+    /// No Pomodoro session is running
+    NoPomodoroSession,
+    /// The command reached a task that does not handle it
+    Unsupported,
+}
+
+/// Channel for the reply to a command
+///
+/// Commands are cloned when they are broadcast, so clones share the channel and
+/// only the first reply is delivered. Dropping every clone without replying
+/// closes the channel.
+#[derive(Clone)]
+pub struct Responder(Arc<Mutex<Option<oneshot::Sender<CommandReply>>>>);
+
+impl Responder {
+    /// Create a responder and the receiver its reply arrives on
+    #[must_use]
+    pub fn new() -> (Self, oneshot::Receiver<CommandReply>) {
+        let (tx, rx) = oneshot::channel();
+        (Self(Arc::new(Mutex::new(Some(tx)))), rx)
+    }
+
+    /// Send the reply, unless one was sent already or the receiver is gone
+    pub fn send(&self, reply: CommandReply) {
+        if let Some(tx) = self.0.lock().take() {
+            let _ = tx.send(reply);
+        }
+    }
+}
+
+impl std::fmt::Debug for Responder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Responder")
+    }
+}
+
+/// How long a manual pause lasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum PauseDuration {
+    /// Until resumed manually
+    Indefinite,
+    /// For the given number of seconds
+    Seconds(u32),
+    /// Until the active schedule ends or hands over to another one
+    UntilScheduleEnd,
+    /// Until midnight
+    UntilTomorrow,
+}
+
+impl Display for PauseDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PauseDuration::Indefinite => write!(f, "Indefinite"),
+            PauseDuration::Seconds(seconds) => write!(f, "{seconds}s"),
+            PauseDuration::UntilScheduleEnd => write!(f, "UntilScheduleEnd"),
+            PauseDuration::UntilTomorrow => write!(f, "UntilTomorrow"),
+        }
+    }
+}
+
+impl PauseDuration {
+    /// Resolve when a pause starting at `now` ends, `None` for an indefinite pause
+    ///
+    /// `UntilScheduleEnd` falls back to midnight if the active schedule never
+    /// changes (e.g. a full-day schedule on every day).
+    #[must_use]
+    pub fn expiry<Tz: TimeZone>(
+        self,
+        config: &AppConfig,
+        now: &DateTime<Tz>,
+    ) -> Option<DateTime<Utc>> {
+        let midnight = || {
+            let tomorrow = now.date_naive().succ_opt()?;
+            localize(tomorrow.and_time(NaiveTime::MIN), &now.timezone())
+                .map(|dt| dt.with_timezone(&Utc))
+        };
+
+        match self {
+            PauseDuration::Indefinite => None,
+            PauseDuration::Seconds(seconds) => {
+                Some(now.with_timezone(&Utc) + Duration::seconds(i64::from(seconds)))
+            }
+            PauseDuration::UntilScheduleEnd => {
+                next_schedule_boundary(config, now).or_else(midnight)
+            }
+            PauseDuration::UntilTomorrow => midnight(),
         }
     }
 }
@@ -119,7 +276,22 @@ impl Display for Command {
 // ============================================================================
 
 /// Reason for pausing the scheduler
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, StrumDisplay, EnumString, EnumIter)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    StrumDisplay,
+    EnumString,
+    EnumIter,
+    TS,
+)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
 #[strum(serialize_all = "PascalCase")]
 pub enum PauseReason {
     UserIdle,
@@ -187,10 +359,54 @@ impl PauseReasons {
 pub struct SchedulerStatus {
     /// Whether the break scheduler is currently paused
     pub paused: bool,
+    /// Why the scheduler is paused, empty while it runs
+    pub pause_reasons: Vec<PauseReason>,
+    /// What the break scheduler is currently doing
+    pub phase: SchedulerPhase,
+    /// Name of the active schedule (if any)
+    pub schedule_name: Option<String>,
     /// The next scheduled break event (if any)
     pub next_event: Option<SchedulerEventInfo>,
     /// The current mini break counter (for tracking long break triggers)
     pub mini_break_counter: u8,
+    /// Number of times the next or current break has been postponed
+    pub postpone_count: u8,
+    /// The next attention reminder (if any)
+    pub next_attention: Option<SchedulerEventInfo>,
+    /// When a timed manual pause ends (if paused for a limited time)
+    pub pause_expiry: Option<PauseExpiry>,
+    /// The running Pomodoro session (if any)
+    pub pomodoro: Option<PomodoroSession>,
+    /// Progress towards the next break of a schedule counting active time
+    pub active_time: Option<ActiveTimeProgress>,
+}
+
+/// Phase of the break scheduler's state machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum SchedulerPhase {
+    /// No break to wait for, e.g. outside the time range of every schedule
+    Idle,
+    /// Paused for at least one reason
+    Paused,
+    /// Waiting to notify about the next break
+    WaitingForNotification,
+    /// Waiting for the next break to start
+    WaitingForBreak,
+    /// A break is in progress, with the seconds left in it
+    InBreak(u32),
+}
+
+/// A Pomodoro session started by the user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct PomodoroSession {
+    /// Name of the Pomodoro schedule the session follows
+    pub schedule_name: String,
+    /// What the user is working on, shown in break prompts
+    pub task: Option<String>,
 }
 
 /// Information about a scheduled event
@@ -206,6 +422,103 @@ pub struct SchedulerEventInfo {
     pub seconds_until: i32,
 }
 
+impl SchedulerEventInfo {
+    #[must_use]
+    pub fn new(kind: SchedulerEvent, time: DateTime<Utc>, now: DateTime<Utc>) -> Self {
+        Self {
+            kind,
+            time: time.to_rfc3339(),
+            seconds_until: i32::try_from((time - now).num_seconds()).unwrap_or(i32::MAX),
+        }
+    }
+}
+
+/// Information about the end of a timed pause
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(rename_all = "camelCase")]
+pub struct PauseExpiry {
+    /// When the scheduler resumes (ISO 8601 timestamp)
+    pub time: String,
+    /// Seconds until the scheduler resumes
+    pub seconds_until: i32,
+}
+
+impl PauseExpiry {
+    #[must_use]
+    pub fn new(until: DateTime<Utc>, now: DateTime<Utc>) -> Self {
+        Self {
+            time: until.to_rfc3339(),
+            seconds_until: i32::try_from((until - now).num_seconds()).unwrap_or(i32::MAX),
+        }
+    }
+}
+
+/// A break counted from time the user spent away from the computer
+///
+/// Emitted as `break-credited` when the scheduler resumes after a pause whose
+/// policy is to credit breaks (idle pauses by default).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct BreakCredit {
+    /// The break that was counted
+    pub event: SchedulerEvent,
+    /// Seconds the user was away, including the idle time before an idle pause
+    pub paused_s: u32,
+}
+
+/// Long-running task of the scheduler system, watched by the supervisor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, StrumDisplay, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub enum SchedulerTask {
+    BreakScheduler,
+    AttentionTimer,
+    CommandBroadcaster,
+}
+
+/// A scheduler task that panicked and is being restarted
+///
+/// Emitted as `scheduler-task-failed`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct TaskFailure {
+    /// The task that failed
+    pub task: SchedulerTask,
+    /// Panic message
+    pub message: String,
+    /// Failures in a row, including this one
+    pub failures: u32,
+    /// Seconds until the task is restarted
+    pub retry_in_s: u32,
+}
+
+/// Input detected during a break that is being verified
+///
+/// Emitted as `break-activity` so the prompt can update its countdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct BreakActivity {
+    /// The break in progress
+    pub event: SchedulerEvent,
+    /// Seconds left in the break after the activity was accounted for
+    pub remaining_s: u32,
+}
+
+/// Active time worked towards the next break
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(rename_all = "camelCase")]
+pub struct ActiveTimeProgress {
+    /// Active seconds since the last break
+    pub active_s: u32,
+    /// Active seconds after which the next break is due
+    pub target_s: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,18 +551,121 @@ mod tests {
     // Command tests
     #[test]
     fn test_command_display() {
-        assert_eq!(Command::PostponeBreak.to_string(), "PostponeBreak");
-        assert_eq!(Command::SkipBreak.to_string(), "SkipBreak");
+        assert_eq!(Command::PostponeBreak(None).to_string(), "PostponeBreak");
+        assert_eq!(Command::SkipBreak(None).to_string(), "SkipBreak");
         assert_eq!(
-            Command::RequestBreakStatus.to_string(),
+            Command::RequestBreakStatus(None).to_string(),
             "RequestBreakStatus"
         );
     }
 
+    #[test]
+    fn test_responder_delivers_first_reply() {
+        let (responder, mut reply_rx) = Responder::new();
+        let cmd = Command::PostponeBreak(Some(responder));
+        let Command::PostponeBreak(Some(responder)) = cmd.clone() else {
+            unreachable!();
+        };
+
+        responder.send(CommandReply::Rejected(Rejection::PostponeLimitReached));
+        if let Command::PostponeBreak(Some(responder)) = cmd {
+            responder.send(CommandReply::Accepted(None));
+        }
+        assert!(matches!(
+            reply_rx.try_recv(),
+            Ok(CommandReply::Rejected(Rejection::PostponeLimitReached))
+        ));
+    }
+
     #[test]
     fn test_command_pause_display() {
-        let cmd = Command::Pause(PauseReason::UserIdle);
+        let cmd = Command::Pause(PauseReason::UserIdle, None);
         assert_eq!(cmd.to_string(), "Pause(UserIdle)");
+
+        let cmd = Command::PauseFor(PauseDuration::Seconds(1800), None);
+        assert_eq!(cmd.to_string(), "PauseFor(1800s)");
+    }
+
+    // PauseDuration tests
+    mod pause_duration_tests {
+        use super::*;
+        use crate::core::schedule::ScheduleSettings;
+        use crate::scheduler::test_helpers::*;
+
+        use chrono_tz::Europe::Berlin;
+
+        #[test]
+        fn indefinite_never_expires() {
+            let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+            let config = AppConfig::default();
+            assert_eq!(PauseDuration::Indefinite.expiry(&config, &now), None);
+        }
+
+        #[test]
+        fn seconds_expire_after_duration() {
+            let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+            let config = AppConfig::default();
+            assert_eq!(
+                PauseDuration::Seconds(1800).expiry(&config, &now),
+                Some(test_datetime_with_local(2025, 9, 1, 10, 30, 0))
+            );
+        }
+
+        #[test]
+        fn until_tomorrow_expires_at_midnight() {
+            let now = test_local_datetime(2025, 9, 1, 22, 15, 0);
+            let config = AppConfig::default();
+            assert_eq!(
+                PauseDuration::UntilTomorrow.expiry(&config, &now),
+                Some(test_datetime_with_local(2025, 9, 2, 0, 0, 0))
+            );
+        }
+
+        #[test]
+        fn until_tomorrow_uses_timezone_of_now() {
+            // 2025-10-25 23:00 UTC is already Sunday 01:00 in Berlin, the day
+            // the clocks go back, so Monday starts 23 hours later (CET, UTC+1)
+            let now = test_datetime(2025, 10, 25, 23, 0, 0).with_timezone(&Berlin);
+            let config = AppConfig::default();
+            assert_eq!(
+                PauseDuration::UntilTomorrow.expiry(&config, &now),
+                Some(test_datetime(2025, 10, 26, 23, 0, 0))
+            );
+        }
+
+        #[test]
+        fn until_schedule_end_expires_at_boundary() {
+            let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+            let config = AppConfig {
+                schedules: vec![ScheduleSettings {
+                    time_ranges: vec![time_range(9, 0, 17, 0)],
+                    days_of_week: all_weekdays(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            };
+            assert_eq!(
+                PauseDuration::UntilScheduleEnd.expiry(&config, &now),
+                Some(test_datetime_with_local(2025, 9, 1, 17, 0, 1))
+            );
+        }
+
+        #[test]
+        fn until_schedule_end_falls_back_to_midnight() {
+            let now = test_local_datetime(2025, 9, 1, 10, 0, 0);
+            let config = AppConfig {
+                schedules: vec![ScheduleSettings {
+                    time_ranges: vec![full_time_range()],
+                    days_of_week: all_weekdays(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            };
+            assert_eq!(
+                PauseDuration::UntilScheduleEnd.expiry(&config, &now),
+                Some(test_datetime_with_local(2025, 9, 2, 0, 0, 0))
+            );
+        }
     }
 
     // PauseReason tests
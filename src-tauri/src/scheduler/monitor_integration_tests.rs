@@ -50,7 +50,7 @@ async fn test_idle_monitor_triggers_pause() {
 
     // Simulate IdleMonitor detecting idle
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -82,7 +82,7 @@ async fn test_idle_monitor_resumes() {
 
     // Simulate idle -> pause
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -91,7 +91,7 @@ async fn test_idle_monitor_resumes() {
 
     // Simulate activity -> resume
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -122,7 +122,10 @@ async fn test_dnd_monitor_triggers_pause() {
     advance_time_and_yield(duration_ms(200)).await;
 
     // Simulate DND enabled
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Verify paused
@@ -151,12 +154,15 @@ async fn test_dnd_monitor_resumes() {
     advance_time_and_yield(duration_ms(200)).await;
 
     // Simulate DND enabled -> paused
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Simulate DND disabled -> resumed
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -188,7 +194,7 @@ async fn test_app_whitelist_monitor_triggers_pause() {
 
     // Simulate excluded app active
     cmd_tx
-        .send(Command::Pause(PauseReason::AppExclusion))
+        .send(Command::Pause(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -220,14 +226,14 @@ async fn test_app_whitelist_monitor_resumes() {
 
     // Simulate excluded app active -> paused
     cmd_tx
-        .send(Command::Pause(PauseReason::AppExclusion))
+        .send(Command::Pause(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Simulate app closed -> resumed
     cmd_tx
-        .send(Command::Resume(PauseReason::AppExclusion))
+        .send(Command::Resume(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -263,13 +269,16 @@ async fn test_two_monitors_both_pause() {
 
     // IdleMonitor detects idle
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
 
     // DndMonitor detects DND (shortly after)
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
 
     // Verify both reasons tracked
@@ -301,15 +310,18 @@ async fn test_two_monitors_sequential_resume() {
 
     // Both monitors trigger pause
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // User becomes active (IdleMonitor resumes)
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -324,7 +336,7 @@ async fn test_two_monitors_sequential_resume() {
 
     // DND disabled (DndMonitor resumes)
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -356,12 +368,15 @@ async fn test_three_monitors_coordinated() {
 
     // All three monitors trigger pause
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::AppExclusion))
+        .send(Command::Pause(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -374,21 +389,21 @@ async fn test_three_monitors_coordinated() {
 
     // Clear in order: App, Idle, DND
     cmd_tx
-        .send(Command::Resume(PauseReason::AppExclusion))
+        .send(Command::Resume(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(50)).await;
     assert!(env.shared_state.read().is_paused()); // Still 2 reasons
 
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(50)).await;
     assert!(env.shared_state.read().is_paused()); // Still 1 reason
 
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(50)).await;
@@ -415,11 +430,11 @@ async fn test_monitor_flapping() {
     // Simulate rapid pause/resume cycles (e.g., user moving mouse sporadically)
     for _ in 0..10 {
         cmd_tx
-            .send(Command::Pause(PauseReason::UserIdle))
+            .send(Command::Pause(PauseReason::UserIdle, None))
             .await
             .unwrap();
         cmd_tx
-            .send(Command::Resume(PauseReason::UserIdle))
+            .send(Command::Resume(PauseReason::UserIdle, None))
             .await
             .unwrap();
     }
@@ -456,14 +471,14 @@ async fn test_monitor_and_user_pause() {
 
     // Monitor detects idle
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
 
     // User manually pauses
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -495,16 +510,19 @@ async fn test_monitor_resumes_user_still_paused() {
     advance_time_and_yield(duration_ms(200)).await;
 
     // Monitor + user both pause
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // Monitor condition clears
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -536,16 +554,19 @@ async fn test_user_resume_during_monitor_pause() {
     advance_time_and_yield(duration_ms(200)).await;
 
     // Both monitor and user pause
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
 
     // User tries to resume manually
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -582,16 +603,16 @@ async fn test_monitor_storm() {
 
     // Simulate chaotic sequence of monitor events
     let events = vec![
-        Command::Pause(PauseReason::UserIdle),
-        Command::Pause(PauseReason::Dnd),
-        Command::Resume(PauseReason::UserIdle),
-        Command::Pause(PauseReason::AppExclusion),
-        Command::Resume(PauseReason::Dnd),
-        Command::Pause(PauseReason::Manual),
-        Command::Resume(PauseReason::AppExclusion),
-        Command::Pause(PauseReason::Dnd),
-        Command::Resume(PauseReason::Manual),
-        Command::Resume(PauseReason::Dnd),
+        Command::Pause(PauseReason::UserIdle, None),
+        Command::Pause(PauseReason::Dnd, None),
+        Command::Resume(PauseReason::UserIdle, None),
+        Command::Pause(PauseReason::AppExclusion, None),
+        Command::Resume(PauseReason::Dnd, None),
+        Command::Pause(PauseReason::Manual, None),
+        Command::Resume(PauseReason::AppExclusion, None),
+        Command::Pause(PauseReason::Dnd, None),
+        Command::Resume(PauseReason::Manual, None),
+        Command::Resume(PauseReason::Dnd, None),
     ];
 
     for event in events {
@@ -626,18 +647,21 @@ async fn test_monitor_cascade() {
 
     // User goes idle
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
 
     // While idle, OS enables DND (common on Windows/macOS)
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::Dnd, None))
+        .await
+        .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
 
     // While still in DND, user opens excluded app (e.g., game)
     cmd_tx
-        .send(Command::Pause(PauseReason::AppExclusion))
+        .send(Command::Pause(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(100)).await;
@@ -650,7 +674,7 @@ async fn test_monitor_cascade() {
 
     // User becomes active -> idle clears
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(50)).await;
@@ -660,7 +684,7 @@ async fn test_monitor_cascade() {
 
     // User closes game -> app exclusion clears
     cmd_tx
-        .send(Command::Resume(PauseReason::AppExclusion))
+        .send(Command::Resume(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(50)).await;
@@ -670,7 +694,7 @@ async fn test_monitor_cascade() {
 
     // DND disables -> all clear
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(50)).await;
@@ -698,7 +722,10 @@ async fn test_monitor_redundant_commands() {
 
     // Monitor sends pause multiple times (e.g., checked every second, condition persists)
     for _ in 0..5 {
-        cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
+        cmd_tx
+            .send(Command::Pause(PauseReason::Dnd, None))
+            .await
+            .unwrap();
         advance_time_and_yield(duration_ms(50)).await;
     }
 
@@ -711,7 +738,7 @@ async fn test_monitor_redundant_commands() {
     // Monitor sends resume multiple times
     for _ in 0..5 {
         cmd_tx
-            .send(Command::Resume(PauseReason::Dnd))
+            .send(Command::Resume(PauseReason::Dnd, None))
             .await
             .unwrap();
         advance_time_and_yield(duration_ms(50)).await;
@@ -747,16 +774,19 @@ async fn test_all_four_pause_reasons() {
 
     // All four reasons trigger
     cmd_tx
-        .send(Command::Pause(PauseReason::Manual))
+        .send(Command::Pause(PauseReason::Manual, None))
+        .await
+        .unwrap();
+    cmd_tx
+        .send(Command::Pause(PauseReason::UserIdle, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::UserIdle))
+        .send(Command::Pause(PauseReason::Dnd, None))
         .await
         .unwrap();
-    cmd_tx.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
     cmd_tx
-        .send(Command::Pause(PauseReason::AppExclusion))
+        .send(Command::Pause(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
@@ -769,19 +799,19 @@ async fn test_all_four_pause_reasons() {
 
     // Clear all four
     cmd_tx
-        .send(Command::Resume(PauseReason::Manual))
+        .send(Command::Resume(PauseReason::Manual, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::UserIdle))
+        .send(Command::Resume(PauseReason::UserIdle, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::Dnd))
+        .send(Command::Resume(PauseReason::Dnd, None))
         .await
         .unwrap();
     cmd_tx
-        .send(Command::Resume(PauseReason::AppExclusion))
+        .send(Command::Resume(PauseReason::AppExclusion, None))
         .await
         .unwrap();
     advance_time_and_yield(duration_ms(200)).await;
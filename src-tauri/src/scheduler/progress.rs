@@ -0,0 +1,211 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+const PROGRESS_FILE_NAME: &str = "scheduler_progress.json";
+
+/// How often the break scheduler saves its progress while running
+///
+/// The save time doubles as a heartbeat: it tells how long ago the app was last
+/// running when progress is restored after a restart or crash.
+pub const PROGRESS_SAVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Break cycle state of the break scheduler that survives restarts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulerProgress {
+    /// Mini breaks taken since the last long break
+    pub mini_break_counter: u8,
+    /// Seconds worked since the last long break (time-based long breaks)
+    #[serde(default)]
+    pub work_since_long_break_s: u32,
+    /// When the last break ended (start of the current interval)
+    pub last_break_time: Option<DateTime<Utc>>,
+    /// When the progress was saved
+    pub saved_at: DateTime<Utc>,
+}
+
+impl SchedulerProgress {
+    /// Whether the progress is too old to be restored at `now`
+    ///
+    /// A `max_age_s` of 0 never restores. Progress saved in the future
+    /// (e.g. after the system clock was turned back) is also stale.
+    #[must_use]
+    pub fn is_stale(&self, now: DateTime<Utc>, max_age_s: u32) -> bool {
+        let age = now - self.saved_at;
+        max_age_s == 0 || age < Duration::zero() || age > Duration::seconds(i64::from(max_age_s))
+    }
+}
+
+/// Store for the latest [`SchedulerProgress`]
+///
+/// The progress is kept in memory and mirrored to a JSON file under the app data
+/// directory. A store without a file (see [`ProgressStore::in_memory`]) is used
+/// when the data directory is unavailable and in tests.
+#[derive(Debug, Default)]
+pub struct ProgressStore {
+    path: Option<PathBuf>,
+    progress: Mutex<Option<SchedulerProgress>>,
+}
+
+impl ProgressStore {
+    /// Create a store that is not backed by a file
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open a store backed by the given file
+    ///
+    /// A missing or malformed file results in an empty store.
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let progress = if path.exists() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read progress file: {}", path.display()))?;
+            serde_json::from_str(&content)
+                .inspect_err(|e| tracing::warn!("Ignoring malformed scheduler progress: {e}"))
+                .ok()
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).context("Failed to create progress directory")?;
+            }
+            None
+        };
+
+        Ok(Self {
+            path: Some(path),
+            progress: Mutex::new(progress),
+        })
+    }
+
+    /// Get the latest saved progress
+    #[must_use]
+    pub fn load(&self) -> Option<SchedulerProgress> {
+        *self.progress.lock()
+    }
+
+    /// Save the progress
+    ///
+    /// Write failures are logged; the progress is still kept in memory.
+    pub fn save(&self, progress: SchedulerProgress) {
+        if let Some(path) = &self.path
+            && let Err(e) = write_progress(path, &progress)
+        {
+            tracing::error!("Failed to write scheduler progress: {e}");
+        }
+        *self.progress.lock() = Some(progress);
+    }
+}
+
+/// Write through a temporary file so a crash never leaves a truncated file behind
+fn write_progress(path: &PathBuf, progress: &SchedulerProgress) -> Result<()> {
+    let content =
+        serde_json::to_string(progress).context("Failed to serialize scheduler progress")?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, content)
+        .with_context(|| format!("Failed to write progress file: {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to replace progress file: {}", path.display()))?;
+    Ok(())
+}
+
+/// Load the scheduler progress store from the app data directory
+///
+/// Falls back to an in-memory store if the file cannot be opened.
+pub fn load_progress<R: Runtime>(app_handle: &AppHandle<R>) -> ProgressStore {
+    let path = match app_handle.path().app_data_dir() {
+        Ok(data_dir) => data_dir.join(PROGRESS_FILE_NAME),
+        Err(e) => {
+            tracing::error!("Failed to resolve progress path, progress will not be persisted: {e}");
+            return ProgressStore::in_memory();
+        }
+    };
+
+    match ProgressStore::open(path) {
+        Ok(store) => store,
+        Err(e) => {
+            tracing::error!(
+                "Failed to load scheduler progress, progress will not be persisted: {e}"
+            );
+            ProgressStore::in_memory()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn progress_saved_at(saved_at: DateTime<Utc>) -> SchedulerProgress {
+        SchedulerProgress {
+            mini_break_counter: 3,
+            work_since_long_break_s: 2400,
+            last_break_time: Some(saved_at - Duration::minutes(5)),
+            saved_at,
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, 3, 14, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn progress_is_fresh_within_max_age() {
+        let progress = progress_saved_at(base_time());
+        let now = base_time() + Duration::seconds(299);
+        assert!(!progress.is_stale(now, 300));
+    }
+
+    #[test]
+    fn progress_is_stale_after_max_age() {
+        let progress = progress_saved_at(base_time());
+        let now = base_time() + Duration::seconds(301);
+        assert!(progress.is_stale(now, 300));
+    }
+
+    #[test]
+    fn progress_is_stale_when_disabled_or_saved_in_future() {
+        let progress = progress_saved_at(base_time());
+        assert!(progress.is_stale(base_time(), 0));
+        assert!(progress.is_stale(base_time() - Duration::seconds(10), 300));
+    }
+
+    #[test]
+    fn store_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PROGRESS_FILE_NAME);
+        let progress = progress_saved_at(base_time());
+
+        let store = ProgressStore::open(path.clone()).unwrap();
+        assert!(store.load().is_none());
+        store.save(progress);
+
+        let reopened = ProgressStore::open(path).unwrap();
+        assert_eq!(reopened.load(), Some(progress));
+    }
+
+    #[test]
+    fn progress_without_work_time_loads() {
+        let json =
+            r#"{"miniBreakCounter":2,"lastBreakTime":null,"savedAt":"2025-09-03T14:00:00Z"}"#;
+        let progress: SchedulerProgress = serde_json::from_str(json).unwrap();
+        assert_eq!(progress.mini_break_counter, 2);
+        assert_eq!(progress.work_since_long_break_s, 0);
+    }
+
+    #[test]
+    fn store_ignores_malformed_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PROGRESS_FILE_NAME);
+        fs::write(&path, "{ not json").unwrap();
+
+        let store = ProgressStore::open(path).unwrap();
+        assert!(store.load().is_none());
+    }
+}
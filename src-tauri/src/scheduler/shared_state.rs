@@ -14,6 +14,9 @@
 //!                     │ pause_reasons   │ ◄─── Manager (add/remove)
 //!                     │ in_break_...    │ ◄─── BreakScheduler (start/end)
 //!                     │ in_attention_.. │ ◄─── AttentionTimer (start/end)
+//!                     │ pomodoro        │ ◄─── BreakScheduler (start/stop)
+//!                     │ activity        │ ◄─── ActivityMonitor (record),
+//!                     │                 │      BreakScheduler (reset)
 //!                     └────────┬────────┘
 //!                              │
 //!                 ┌────────────┼────────────┐
@@ -128,11 +131,13 @@
 //! - `monitors::dnd` - Example of session-aware monitoring
 
 use std::sync::Arc;
-use std::time::Instant;
 
+use chrono::{DateTime, Duration, Utc};
 use parking_lot::RwLock;
+use tokio::time::Instant;
 
-use super::models::{PauseReason, PauseReasons};
+use super::activity::ActivityTracker;
+use super::models::{PauseReason, PauseReasons, PomodoroSession};
 
 /// Shared state between all schedulers and monitors
 ///
@@ -146,6 +151,11 @@ pub struct SharedSchedulerState {
     /// Multiple reasons can be active simultaneously.
     pause_reasons: PauseReasons,
 
+    /// When the manual pause ends, if it was requested for a limited time
+    ///
+    /// Cleared when the `Manual` pause reason is removed.
+    manual_pause_until: Option<DateTime<Utc>>,
+
     /// Whether currently in a break session (mini or long)
     ///
     /// Set to true when a break starts, false when it finishes.
@@ -163,6 +173,16 @@ pub struct SharedSchedulerState {
 
     /// When the attention session started (for logging/debugging)
     attention_session_start: Option<Instant>,
+
+    /// The running Pomodoro session
+    ///
+    /// While set, breaks follow the Pomodoro schedule instead of the time of day.
+    pomodoro: Option<PomodoroSession>,
+
+    /// Active time of the user since the current interval started
+    ///
+    /// Used by schedules that count active time instead of wall-clock time.
+    activity: ActivityTracker,
 }
 
 impl SharedSchedulerState {
@@ -171,10 +191,13 @@ impl SharedSchedulerState {
     pub fn new() -> Self {
         Self {
             pause_reasons: PauseReasons::empty(),
+            manual_pause_until: None,
             in_break_session: false,
             in_attention_session: false,
             break_session_start: None,
             attention_session_start: None,
+            pomodoro: None,
+            activity: ActivityTracker::default(),
         }
     }
 
@@ -224,6 +247,9 @@ impl SharedSchedulerState {
     pub fn remove_pause_reason(&mut self, reason: PauseReason) -> bool {
         let was_set = self.pause_reasons.contains(reason.into());
         self.pause_reasons.remove(reason.into());
+        if reason == PauseReason::Manual {
+            self.manual_pause_until = None;
+        }
         let is_now_running = self.pause_reasons.is_empty();
 
         if is_now_running {
@@ -259,6 +285,60 @@ impl SharedSchedulerState {
         self.pause_reasons.to_vec()
     }
 
+    /// Get when the manual pause ends (`None` if not paused for a limited time)
+    #[must_use]
+    pub fn manual_pause_until(&self) -> Option<DateTime<Utc>> {
+        self.manual_pause_until
+    }
+
+    /// Set when the manual pause ends (`None` for an indefinite pause)
+    pub fn set_manual_pause_until(&mut self, until: Option<DateTime<Utc>>) {
+        self.manual_pause_until = until;
+    }
+
+    /// Get the running Pomodoro session (`None` if not in one)
+    #[must_use]
+    pub fn pomodoro(&self) -> Option<&PomodoroSession> {
+        self.pomodoro.as_ref()
+    }
+
+    /// Start (`Some`) or end (`None`) a Pomodoro session
+    pub fn set_pomodoro(&mut self, session: Option<PomodoroSession>) {
+        self.pomodoro = session;
+    }
+
+    /// Account for an idle time sample taken at `now`
+    pub fn record_activity(&mut self, now: DateTime<Utc>, idle: Duration) {
+        self.activity.record(now, idle);
+    }
+
+    /// Start counting active time from zero at `now`
+    pub fn reset_activity(&mut self, now: DateTime<Utc>) {
+        self.activity.reset(now);
+    }
+
+    /// Continue counting from `active_s` at `now`, ignoring the time in between
+    pub fn resume_activity(&mut self, now: DateTime<Utc>, active_s: u32) {
+        self.activity.resume(now, active_s);
+    }
+
+    /// Stop counting active time, e.g. because idle detection is unavailable
+    pub fn stop_activity_sampling(&mut self) {
+        self.activity.stop_sampling();
+    }
+
+    /// Active seconds since the current interval started, `None` if not sampled
+    #[must_use]
+    pub fn active_s(&self) -> Option<u32> {
+        self.activity.active_s()
+    }
+
+    /// Seconds without input at the latest activity sample, `None` if not sampled
+    #[must_use]
+    pub fn idle_s(&self) -> Option<u64> {
+        self.activity.idle_s()
+    }
+
     /// Check if in any session (break or attention)
     ///
     /// This is used by monitors (especially `DndMonitor`) to avoid interfering
@@ -417,6 +497,23 @@ mod tests {
         assert_eq!(state.pause_reasons().len(), 1);
     }
 
+    #[test]
+    fn test_manual_pause_expiry_cleared_on_resume() {
+        let mut state = SharedSchedulerState::new();
+        let until = Utc::now() + chrono::Duration::minutes(30);
+
+        state.add_pause_reason(PauseReason::Manual);
+        state.set_manual_pause_until(Some(until));
+        assert_eq!(state.manual_pause_until(), Some(until));
+
+        // Other reasons keep the expiry
+        state.remove_pause_reason(PauseReason::Dnd);
+        assert_eq!(state.manual_pause_until(), Some(until));
+
+        state.remove_pause_reason(PauseReason::Manual);
+        assert_eq!(state.manual_pause_until(), None);
+    }
+
     #[test]
     fn test_remove_nonexistent_reason() {
         let mut state = SharedSchedulerState::new();
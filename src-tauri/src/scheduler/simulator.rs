@@ -0,0 +1,497 @@
+//! Headless dry run of the schedulers against a virtual clock
+//!
+//! Runs the break scheduler, attention timer and command broadcaster on a tokio
+//! runtime with paused time and a [`FakeClock`], so hours of scheduling complete
+//! in moments. Emitted `scheduler-event` and `scheduler-status` payloads are
+//! recorded with the virtual time at which they occurred.
+//!
+//! A [`SimulationScript`] injects user input (idle periods, DND toggles, pauses,
+//! postpones, skips, Pomodoro sessions) at offsets from the start. Prompts are finished
+//! automatically once their configured duration has passed, unless postponed or
+//! skipped first.
+//!
+//! The schedulers run against a [`HeadlessHost`], so no window or notification is
+//! shown, nothing is written to disk and no Tauri runtime is needed. Available in tests and with
+//! the default `simulator` feature, which also adds the
+//! `--simulate <config.toml> [script.toml]` command line mode.
+//!
+//! # Script Format
+//!
+//! ```toml
+//! start = "2025-09-01T08:30:00" # local time, defaults to now
+//! durationS = 14400
+//!
+//! [[steps]]
+//! atS = 3600
+//! action = "idleStart" # idleEnd, dndOn, dndOff, pause, resume, postpone, skip,
+//!                      # pomodoroStart, pomodoroStop
+//! ```
+
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{Local, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, watch};
+use tokio::time::{Duration, Instant, sleep};
+
+use super::attention_timer::AttentionTimer;
+use super::break_scheduler::BreakScheduler;
+use super::clock::FakeClock;
+use super::event::localize;
+use super::event_emitter::TestEventEmitter;
+use super::host::HeadlessHost;
+use super::manager::{CommandTargets, broadcast_commands};
+use super::models::{Command, PauseReason, SchedulerEvent};
+use super::shared_state::create_shared_state;
+use crate::config::{AppConfig, load_config_file};
+
+/// Default length of a simulation (8 hours)
+const DEFAULT_DURATION_S: u64 = 8 * 60 * 60;
+
+/// Virtual time between two polls of the schedulers
+const TICK: Duration = Duration::from_secs(1);
+
+/// How many times to yield so the scheduler tasks can process pending commands
+const SETTLE_YIELDS: usize = 20;
+
+/// Synthetic user input to replay during a simulation
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationScript {
+    /// Local time at which the simulation starts (now if not given)
+    #[serde(default)]
+    pub start: Option<NaiveDateTime>,
+    /// How long to simulate (seconds)
+    #[serde(default = "default_duration_s")]
+    pub duration_s: u64,
+    /// Inputs to inject, in any order
+    #[serde(default)]
+    pub steps: Vec<ScriptStep>,
+}
+
+fn default_duration_s() -> u64 {
+    DEFAULT_DURATION_S
+}
+
+impl Default for SimulationScript {
+    fn default() -> Self {
+        Self {
+            start: None,
+            duration_s: DEFAULT_DURATION_S,
+            steps: Vec::new(),
+        }
+    }
+}
+
+/// A single input at an offset from the start of the simulation
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptStep {
+    /// Seconds since the start of the simulation
+    pub at_s: u64,
+    pub action: ScriptAction,
+}
+
+/// Synthetic user input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScriptAction {
+    /// The user goes idle
+    IdleStart,
+    /// The user is active again
+    IdleEnd,
+    /// System Do Not Disturb turns on
+    DndOn,
+    /// System Do Not Disturb turns off
+    DndOff,
+    /// The user pauses the scheduler
+    Pause,
+    /// The user resumes the scheduler
+    Resume,
+    /// The user postpones the current or next break
+    Postpone,
+    /// The user skips the current break
+    Skip,
+    /// The user starts a Pomodoro session
+    PomodoroStart,
+    /// The user stops the Pomodoro session
+    PomodoroStop,
+}
+
+impl ScriptAction {
+    /// The command the monitors or the UI would send for this input
+    fn command(self) -> Command {
+        match self {
+            Self::IdleStart => Command::Pause(PauseReason::UserIdle, None),
+            Self::IdleEnd => Command::Resume(PauseReason::UserIdle, None),
+            Self::DndOn => Command::Pause(PauseReason::Dnd, None),
+            Self::DndOff => Command::Resume(PauseReason::Dnd, None),
+            Self::Pause => Command::Pause(PauseReason::Manual, None),
+            Self::Resume => Command::Resume(PauseReason::Manual, None),
+            Self::Postpone => Command::PostponeBreak(None),
+            Self::Skip => Command::SkipBreak(None),
+            Self::PomodoroStart => Command::StartPomodoro(None, None),
+            Self::PomodoroStop => Command::StopPomodoro(None),
+        }
+    }
+}
+
+/// An event emitted by the schedulers during a simulation
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedEmission {
+    /// Seconds since the start of the simulation
+    pub at_s: u64,
+    /// Event name, e.g. `scheduler-event`
+    pub event: String,
+    pub payload: serde_json::Value,
+}
+
+impl Display for SimulatedEmission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}  {}  {}",
+            self.at_s / 3600,
+            self.at_s / 60 % 60,
+            self.at_s % 60,
+            self.event,
+            self.payload
+        )
+    }
+}
+
+/// Simulate `config` with the inputs of `script`
+///
+/// Runs on a dedicated runtime with paused time and returns every emitted event
+/// in order.
+pub fn simulate(config: AppConfig, script: &SimulationScript) -> Result<Vec<SimulatedEmission>> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .context("Failed to create simulation runtime")?;
+    runtime.block_on(run_simulation(config, script))
+}
+
+async fn run_simulation(
+    config: AppConfig,
+    script: &SimulationScript,
+) -> Result<Vec<SimulatedEmission>> {
+    let start_time = match script.start {
+        Some(start) => localize(start, &Local)
+            .with_context(|| format!("Invalid start time: {start}"))?
+            .with_timezone(&Utc),
+        None => Utc::now(),
+    };
+    let clock = FakeClock::new(start_time);
+
+    let host = HeadlessHost::new(config.clone());
+    let emitter = TestEventEmitter::new();
+    let shared_state = create_shared_state();
+    let (shutdown_tx, shutdown_rx) = watch::channel(());
+    let (cmd_tx, cmd_rx) = mpsc::channel(32);
+    let (break_cmd_tx, break_cmd_rx) = mpsc::channel(32);
+    let (attention_cmd_tx, attention_cmd_rx) = mpsc::channel(32);
+
+    let mut break_scheduler = BreakScheduler::new(
+        host.clone(),
+        emitter.clone(),
+        shutdown_rx.clone(),
+        shared_state.clone(),
+        clock.clone(),
+    )
+    .headless();
+    tokio::spawn(async move { break_scheduler.run(break_cmd_rx).await });
+
+    let mut attention_timer = AttentionTimer::new(
+        host.clone(),
+        emitter.clone(),
+        shutdown_rx.clone(),
+        shared_state.clone(),
+        clock.clone(),
+    )
+    .headless();
+    tokio::spawn(async move { attention_timer.run(attention_cmd_rx).await });
+
+    let targets = CommandTargets {
+        break_cmd_tx,
+        attention_cmd_tx,
+        events: emitter.clone(),
+    };
+    tokio::spawn(broadcast_commands(
+        cmd_rx,
+        targets,
+        shutdown_rx,
+        shared_state,
+        host,
+        clock,
+    ));
+
+    let mut steps = script.steps.clone();
+    steps.sort_by_key(|step| step.at_s);
+    let mut steps = steps.into_iter().peekable();
+
+    let start = Instant::now();
+    let mut emissions: Vec<SimulatedEmission> = Vec::new();
+    // Prompts currently shown, with the time at which they finish
+    let mut open_prompts: Vec<(u64, SchedulerEvent)> = Vec::new();
+
+    loop {
+        let at_s = start.elapsed().as_secs();
+
+        while let Some(step) = steps.next_if(|step| step.at_s <= at_s) {
+            if matches!(step.action, ScriptAction::Postpone | ScriptAction::Skip) {
+                // The user closed the break prompt
+                open_prompts.retain(|(_, event)| !event.is_break());
+            }
+            send(&cmd_tx, step.action.command()).await?;
+        }
+
+        let (finished, still_open): (Vec<_>, Vec<_>) = open_prompts
+            .into_iter()
+            .partition(|(finish_s, _)| *finish_s <= at_s);
+        open_prompts = still_open;
+        for (_, event) in finished {
+            send(&cmd_tx, Command::PromptFinished(event)).await?;
+        }
+
+        settle().await;
+
+        let new_events = emitter.get_events().into_iter().skip(emissions.len());
+        for (event, payload) in new_events {
+            if event == "scheduler-event"
+                && let Ok(kind) = serde_json::from_value::<SchedulerEvent>(payload.clone())
+            {
+                let finish_s = at_s + u64::from(prompt_duration_s(&config, kind));
+                open_prompts.push((finish_s, kind));
+            }
+            emissions.push(SimulatedEmission {
+                at_s,
+                event,
+                payload,
+            });
+        }
+
+        if at_s >= script.duration_s {
+            break;
+        }
+        sleep(TICK).await;
+    }
+
+    let _ = shutdown_tx.send(());
+    Ok(emissions)
+}
+
+async fn send(cmd_tx: &mpsc::Sender<Command>, cmd: Command) -> Result<()> {
+    cmd_tx
+        .send(cmd)
+        .await
+        .map_err(|e| anyhow!("Scheduler stopped during simulation: {e}"))
+}
+
+/// Let the scheduler tasks process pending commands without advancing time
+async fn settle() {
+    for _ in 0..SETTLE_YIELDS {
+        tokio::task::yield_now().await;
+    }
+}
+
+/// How long a prompt stays open when the user lets it finish
+fn prompt_duration_s(config: &AppConfig, event: SchedulerEvent) -> u32 {
+    match event {
+        SchedulerEvent::MiniBreak(id) => config
+            .schedules
+            .iter()
+            .map(|s| &s.mini_breaks.base)
+            .find(|b| b.id == id)
+            .map_or(0, |b| b.duration_s),
+        SchedulerEvent::LongBreak(id) => config
+            .schedules
+            .iter()
+            .map(|s| &s.long_breaks.base)
+            .find(|b| b.id == id)
+            .map_or(0, |b| b.duration_s),
+        SchedulerEvent::Attention(id) => config
+            .attentions
+            .iter()
+            .find(|a| a.id == id)
+            .map_or(0, |a| a.duration_s),
+    }
+}
+
+/// Run the `--simulate <config.toml> [script.toml]` command line mode
+///
+/// Prints one line per emitted event. Returns the process exit code, or `None`
+/// if `args` do not ask for a simulation.
+#[must_use]
+pub fn run_cli(args: &[String]) -> Option<i32> {
+    let index = args.iter().position(|arg| arg == "--simulate")?;
+
+    let result = match &args[index + 1..] {
+        [config] => simulate_files(Path::new(config), None),
+        [config, script] => simulate_files(Path::new(config), Some(Path::new(script))),
+        _ => Err(anyhow!("Usage: --simulate <config.toml> [script.toml]")),
+    };
+
+    Some(match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Simulation failed: {e:#}");
+            1
+        }
+    })
+}
+
+fn simulate_files(config_path: &Path, script_path: Option<&Path>) -> Result<()> {
+    let config = load_config_file(config_path)?;
+    let script = match script_path {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read script: {}", path.display()))?;
+            toml::from_str(&content).context("Failed to parse simulation script")?
+        }
+        None => SimulationScript::default(),
+    };
+
+    for emission in simulate(config, &script)? {
+        println!("{emission}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::test_helpers::*;
+
+    fn break_times(emissions: &[SimulatedEmission]) -> Vec<u64> {
+        emissions
+            .iter()
+            .filter(|e| e.event == "scheduler-event")
+            .map(|e| e.at_s)
+            .collect()
+    }
+
+    fn script(duration_s: u64, steps: &[(u64, ScriptAction)]) -> SimulationScript {
+        SimulationScript {
+            start: None,
+            duration_s,
+            steps: steps
+                .iter()
+                .map(|&(at_s, action)| ScriptStep { at_s, action })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn breaks_finish_after_their_duration() {
+        // 20 min interval, 20 s mini breaks
+        let config = TestConfigBuilder::new().build();
+
+        let emissions = simulate(config, &script(3000, &[])).unwrap();
+
+        // The second interval starts when the first break ends
+        assert_eq!(break_times(&emissions), vec![1200, 2420]);
+    }
+
+    #[test]
+    fn skip_ends_break_early() {
+        let config = TestConfigBuilder::new().build();
+
+        let emissions = simulate(config, &script(3000, &[(1205, ScriptAction::Skip)])).unwrap();
+
+        assert_eq!(break_times(&emissions), vec![1200, 2405]);
+    }
+
+    #[test]
+    fn idle_period_pauses_breaks() {
+        let config = TestConfigBuilder::new().build();
+        let steps = [
+            (600, ScriptAction::IdleStart),
+            (3000, ScriptAction::IdleEnd),
+        ];
+
+        let emissions = simulate(config, &script(3600, &steps)).unwrap();
+
+        assert!(break_times(&emissions).is_empty());
+        assert!(
+            emissions
+                .iter()
+                .any(|e| e.at_s == 600 && e.payload["paused"] == true)
+        );
+    }
+
+    #[test]
+    fn schedules_follow_virtual_time_of_day() {
+        let config = TestConfigBuilder::new()
+            .time_range(time_range(9, 0, 17, 0))
+            .days_of_week(workdays())
+            .build();
+        let script = SimulationScript {
+            // Monday 16:30, the schedule ends at 17:00
+            start: Some(test_local_datetime(2025, 9, 1, 16, 30, 0).naive_local()),
+            ..script(2 * 3600, &[])
+        };
+
+        let emissions = simulate(config, &script).unwrap();
+
+        assert_eq!(break_times(&emissions), vec![1200]);
+    }
+
+    #[test]
+    fn pomodoro_session_runs_focus_blocks() {
+        // 25 min focus blocks, 5 min rests
+        let config = TestConfigBuilder::new()
+            .schedule_enabled(false)
+            .add_schedule(pomodoro_schedule(1500, 4))
+            .build();
+        let steps = [
+            (600, ScriptAction::PomodoroStart),
+            (4000, ScriptAction::PomodoroStop),
+        ];
+
+        let emissions = simulate(config, &script(6000, &steps)).unwrap();
+
+        assert_eq!(break_times(&emissions), vec![2100, 3900]);
+    }
+
+    #[test]
+    fn script_parses_from_toml() {
+        let script: SimulationScript = toml::from_str(
+            r#"
+            start = "2025-09-01T08:30:00"
+            durationS = 7200
+
+            [[steps]]
+            atS = 60
+            action = "dndOn"
+
+            [[steps]]
+            atS = 120
+            action = "postpone"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            script.start,
+            Some(test_local_datetime(2025, 9, 1, 8, 30, 0).naive_local())
+        );
+        assert_eq!(script.duration_s, 7200);
+        assert_eq!(script.steps.len(), 2);
+        assert_eq!(script.steps[0].action, ScriptAction::DndOn);
+        assert_eq!(script.steps[1].action, ScriptAction::Postpone);
+    }
+
+    #[test]
+    fn cli_ignores_other_arguments() {
+        assert_eq!(run_cli(&["focust".to_string()]), None);
+        assert_eq!(
+            run_cli(&["focust".to_string(), "--simulate".to_string()]),
+            Some(1)
+        );
+    }
+}
@@ -59,17 +59,19 @@ mod tests {
         let tx3 = cmd_tx.clone();
 
         let task1 = tokio::spawn(async move {
-            tx1.send(Command::Pause(PauseReason::UserIdle))
+            tx1.send(Command::Pause(PauseReason::UserIdle, None))
                 .await
                 .unwrap();
         });
 
         let task2 = tokio::spawn(async move {
-            tx2.send(Command::Pause(PauseReason::Dnd)).await.unwrap();
+            tx2.send(Command::Pause(PauseReason::Dnd, None))
+                .await
+                .unwrap();
         });
 
         let task3 = tokio::spawn(async move {
-            tx3.send(Command::Pause(PauseReason::AppExclusion))
+            tx3.send(Command::Pause(PauseReason::AppExclusion, None))
                 .await
                 .unwrap();
         });
@@ -114,7 +116,7 @@ mod tests {
         // Rapid cycling: 200 pause/resume cycles
         for i in 1..=200 {
             cmd_tx
-                .send(Command::Pause(PauseReason::Manual))
+                .send(Command::Pause(PauseReason::Manual, None))
                 .await
                 .unwrap();
             advance_time_and_yield(Duration::milliseconds(10)).await;
@@ -131,7 +133,7 @@ mod tests {
             }
 
             cmd_tx
-                .send(Command::Resume(PauseReason::Manual))
+                .send(Command::Resume(PauseReason::Manual, None))
                 .await
                 .unwrap();
             advance_time_and_yield(Duration::milliseconds(10)).await;
@@ -181,12 +183,12 @@ mod tests {
         for i in 0..1000 {
             // Pause
             cmd_tx
-                .send(Command::Pause(PauseReason::Manual))
+                .send(Command::Pause(PauseReason::Manual, None))
                 .await
                 .unwrap();
             // Resume
             cmd_tx
-                .send(Command::Resume(PauseReason::Manual))
+                .send(Command::Resume(PauseReason::Manual, None))
                 .await
                 .unwrap();
 
@@ -235,10 +237,14 @@ mod tests {
         let storm_tx = cmd_tx.clone();
         let storm_task = tokio::spawn(async move {
             for _ in 0..50 {
-                let _ = storm_tx.send(Command::Pause(PauseReason::UserIdle)).await;
-                let _ = storm_tx.send(Command::Resume(PauseReason::UserIdle)).await;
-                let _ = storm_tx.send(Command::Pause(PauseReason::Dnd)).await;
-                let _ = storm_tx.send(Command::Resume(PauseReason::Dnd)).await;
+                let _ = storm_tx
+                    .send(Command::Pause(PauseReason::UserIdle, None))
+                    .await;
+                let _ = storm_tx
+                    .send(Command::Resume(PauseReason::UserIdle, None))
+                    .await;
+                let _ = storm_tx.send(Command::Pause(PauseReason::Dnd, None)).await;
+                let _ = storm_tx.send(Command::Resume(PauseReason::Dnd, None)).await;
                 tokio::time::sleep(std::time::Duration::from_micros(100)).await;
             }
         });
@@ -285,18 +291,18 @@ mod tests {
 
         // Complex sequence of state transitions
         let transitions = vec![
-            (Command::Pause(PauseReason::Manual), "User pause"),
-            (Command::Pause(PauseReason::UserIdle), "Idle pause"),
-            (Command::Resume(PauseReason::Manual), "User resume"),
+            (Command::Pause(PauseReason::Manual, None), "User pause"),
+            (Command::Pause(PauseReason::UserIdle, None), "Idle pause"),
+            (Command::Resume(PauseReason::Manual, None), "User resume"),
             // Still paused due to Idle
-            (Command::Pause(PauseReason::Dnd), "Dnd pause"),
-            (Command::Pause(PauseReason::AppExclusion), "App pause"),
-            (Command::Resume(PauseReason::UserIdle), "Idle resume"),
+            (Command::Pause(PauseReason::Dnd, None), "Dnd pause"),
+            (Command::Pause(PauseReason::AppExclusion, None), "App pause"),
+            (Command::Resume(PauseReason::UserIdle, None), "Idle resume"),
             // Still paused due to Dnd and AppWhitelist
-            (Command::Resume(PauseReason::Dnd), "Dnd resume"),
+            (Command::Resume(PauseReason::Dnd, None), "Dnd resume"),
             // Still paused due to AppWhitelist
             (
-                Command::Resume(PauseReason::AppExclusion),
+                Command::Resume(PauseReason::AppExclusion, None),
                 "App resume - now fully running",
             ),
         ];
@@ -366,7 +372,7 @@ mod tests {
         ];
 
         for reason in &all_reasons {
-            cmd_tx.send(Command::Pause(*reason)).await.unwrap();
+            cmd_tx.send(Command::Pause(*reason, None)).await.unwrap();
             advance_time_and_yield(Duration::milliseconds(20)).await;
         }
 
@@ -390,7 +396,7 @@ mod tests {
         ];
 
         for (i, reason) in resume_order.iter().enumerate() {
-            cmd_tx.send(Command::Resume(*reason)).await.unwrap();
+            cmd_tx.send(Command::Resume(*reason, None)).await.unwrap();
             advance_time_and_yield(Duration::milliseconds(20)).await;
 
             let state = env.shared_state.read();
@@ -435,7 +441,7 @@ mod tests {
 
         // Edge case 1: Resume without Pause
         cmd_tx
-            .send(Command::Resume(PauseReason::Manual))
+            .send(Command::Resume(PauseReason::Manual, None))
             .await
             .unwrap();
         advance_time_and_yield(Duration::milliseconds(50)).await;
@@ -452,7 +458,7 @@ mod tests {
         // Edge case 2: Multiple Pause commands for same reason
         for _ in 0..5 {
             cmd_tx
-                .send(Command::Pause(PauseReason::UserIdle))
+                .send(Command::Pause(PauseReason::UserIdle, None))
                 .await
                 .unwrap();
             advance_time_and_yield(Duration::milliseconds(10)).await;
@@ -471,7 +477,7 @@ mod tests {
         // Edge case 3: Multiple Resume commands for same reason
         for _ in 0..5 {
             cmd_tx
-                .send(Command::Resume(PauseReason::UserIdle))
+                .send(Command::Resume(PauseReason::UserIdle, None))
                 .await
                 .unwrap();
             advance_time_and_yield(Duration::milliseconds(10)).await;
@@ -485,19 +491,19 @@ mod tests {
 
         // Edge case 4: Mixed invalid commands
         cmd_tx
-            .send(Command::Resume(PauseReason::Dnd))
+            .send(Command::Resume(PauseReason::Dnd, None))
             .await
             .unwrap();
         cmd_tx
-            .send(Command::Pause(PauseReason::Manual))
+            .send(Command::Pause(PauseReason::Manual, None))
             .await
             .unwrap();
         cmd_tx
-            .send(Command::Resume(PauseReason::AppExclusion))
+            .send(Command::Resume(PauseReason::AppExclusion, None))
             .await
             .unwrap();
         cmd_tx
-            .send(Command::Resume(PauseReason::Manual))
+            .send(Command::Resume(PauseReason::Manual, None))
             .await
             .unwrap();
         advance_time_and_yield(Duration::milliseconds(100)).await;
@@ -0,0 +1,280 @@
+//! Supervision of the long-running scheduler tasks
+//!
+//! [`BreakScheduler`](super::break_scheduler::BreakScheduler), `AttentionTimer` and
+//! the command broadcaster each run in their own task. If one of them panicked,
+//! reminders would silently stop while the rest of the app keeps running, so
+//! every task is run under [`supervise`].
+//!
+//! # Behavior
+//!
+//! - Commands for the task are relayed through the supervisor, so they reach
+//!   the restarted task instead of being lost with the dead one
+//! - A task that panics is restarted from its last known state: saved progress
+//!   and the pause reasons in [`SharedState`]
+//! - Each failure is logged and emitted as `scheduler-task-failed`
+//! - Restarts back off exponentially while the task keeps failing, and the
+//!   backoff starts over once the task ran for a while
+//! - A task that returns (shutdown or closed command channel) ends supervision
+
+use std::any::Any;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep};
+
+use super::event_bus::DomainEvent;
+use super::event_emitter::EventEmitter;
+use super::models::{Command, SchedulerTask, TaskFailure};
+use super::shared_state::SharedState;
+
+/// Size of the command channel of each task
+pub(crate) const TASK_COMMAND_BUFFER: usize = 32;
+
+/// Delay before the first restart of a failing task
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Longest delay between restarts of a task that keeps failing
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+
+/// A task that ran this long before failing is restarted with the initial backoff
+const STABLE_RUN: Duration = Duration::from_secs(60);
+
+/// Run a scheduler task, restarting it whenever it panics
+///
+/// `spawn` starts a fresh instance of the task reading commands from the given
+/// receiver. Commands sent to `cmd_rx` are relayed to the running instance,
+/// and queued for the next one while a restart is pending.
+pub(crate) async fn supervise<E, F>(
+    task: SchedulerTask,
+    mut cmd_rx: mpsc::Receiver<Command>,
+    mut spawn: F,
+    shared_state: SharedState,
+    events: E,
+    mut shutdown_rx: watch::Receiver<()>,
+) where
+    E: EventEmitter,
+    F: FnMut(mpsc::Receiver<Command>) -> JoinHandle<()>,
+{
+    let (tx, rx) = mpsc::channel(TASK_COMMAND_BUFFER);
+    let mut task_tx = Some(tx);
+    let mut handle = spawn(rx);
+    let mut started = Instant::now();
+    let mut failures = 0;
+    let mut undelivered = Vec::new();
+
+    loop {
+        let result = tokio::select! {
+            result = &mut handle => result,
+            cmd = cmd_rx.recv(), if task_tx.is_some() => {
+                let Some(cmd) = cmd else {
+                    // Closing the task's channel lets it shut down
+                    task_tx = None;
+                    continue;
+                };
+                // The task is gone if this fails, its result is picked up next
+                if let Some(tx) = &task_tx
+                    && let Err(mpsc::error::SendError(cmd)) = tx.send(cmd).await
+                {
+                    undelivered.push(cmd);
+                }
+                continue;
+            }
+        };
+
+        let error = match result {
+            Ok(()) => {
+                tracing::info!("{task} stopped");
+                return;
+            }
+            Err(e) if e.is_cancelled() => {
+                tracing::info!("{task} was cancelled");
+                return;
+            }
+            Err(e) => e,
+        };
+
+        if started.elapsed() >= STABLE_RUN {
+            failures = 0;
+        }
+        failures += 1;
+        let retry_in = backoff(failures);
+        let message = panic_message(error.into_panic().as_ref());
+        tracing::error!(
+            "{task} panicked ({failures} time(s) in a row): {message}. Restarting in {retry_in:?}"
+        );
+        let failure = TaskFailure {
+            task,
+            message,
+            failures,
+            retry_in_s: u32::try_from(retry_in.as_secs()).unwrap_or(u32::MAX),
+        };
+        events.emit(DomainEvent::TaskFailed(failure));
+
+        // Commands arriving until the restart are queued for the new instance
+        let (tx, rx) = mpsc::channel(TASK_COMMAND_BUFFER);
+        prepare_restart(task, &shared_state, &tx);
+        for cmd in undelivered.drain(..) {
+            queue_command(task, &tx, cmd);
+        }
+
+        let restart = sleep(retry_in);
+        tokio::pin!(restart);
+        loop {
+            tokio::select! {
+                biased;
+                _ = shutdown_rx.changed() => {
+                    tracing::info!("{task} supervisor shutting down");
+                    return;
+                }
+                () = &mut restart => break,
+                cmd = cmd_rx.recv() => {
+                    let Some(cmd) = cmd else {
+                        tracing::info!("Command channel closed, {task} will not be restarted");
+                        return;
+                    };
+                    queue_command(task, &tx, cmd);
+                }
+            }
+        }
+
+        tracing::info!("Restarting {task}");
+        task_tx = Some(tx);
+        handle = spawn(rx);
+        started = Instant::now();
+    }
+}
+
+/// Bring the shared state and a restarted task in line with each other
+///
+/// A session of the failed task is over, as its window will never be finished
+/// through it. The new instance starts running, so it is paused again if the
+/// scheduler is paused.
+fn prepare_restart(task: SchedulerTask, shared_state: &SharedState, tx: &mpsc::Sender<Command>) {
+    let pause_reason = {
+        let mut state = shared_state.write();
+        match task {
+            SchedulerTask::BreakScheduler => state.end_break_session(),
+            SchedulerTask::AttentionTimer => state.end_attention_session(),
+            // Its state lives in `SharedState` and survives the restart
+            SchedulerTask::CommandBroadcaster => return,
+        }
+        state.pause_reasons().first().copied()
+    };
+    if let Some(reason) = pause_reason {
+        queue_command(task, tx, Command::Pause(reason, None));
+    }
+}
+
+/// Queue a command for a task that is about to be restarted
+fn queue_command(task: SchedulerTask, tx: &mpsc::Sender<Command>, cmd: Command) {
+    if let Err(e) = tx.try_send(cmd) {
+        tracing::warn!("Dropping command for {task} while it restarts: {e}");
+    }
+}
+
+/// Delay before restarting a task that failed `failures` times in a row
+fn backoff(failures: u32) -> Duration {
+    INITIAL_BACKOFF
+        .saturating_mul(2_u32.saturating_pow(failures.saturating_sub(1)))
+        .min(MAX_BACKOFF)
+}
+
+/// Readable message of a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use crate::scheduler::event_emitter::TestEventEmitter;
+    use crate::scheduler::models::PauseReason;
+    use crate::scheduler::shared_state::create_shared_state;
+
+    #[test]
+    fn panic_message_reads_string_payloads() {
+        assert_eq!(panic_message(&"boom"), "boom");
+        assert_eq!(panic_message(&String::from("boom")), "boom");
+        assert_eq!(panic_message(&42), "unknown panic");
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        assert_eq!(backoff(1), Duration::from_secs(1));
+        assert_eq!(backoff(2), Duration::from_secs(2));
+        assert_eq!(backoff(4), Duration::from_secs(8));
+        assert_eq!(backoff(40), MAX_BACKOFF);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn restarts_panicked_task_with_its_commands() {
+        let emitter = TestEventEmitter::new();
+        let shared_state = create_shared_state();
+        shared_state.write().add_pause_reason(PauseReason::Dnd);
+        shared_state.write().start_break_session();
+
+        // The first instance panics on its first command, later ones report them
+        let instances = Arc::new(AtomicU32::new(0));
+        let (seen_tx, mut seen_rx) = mpsc::unbounded_channel();
+        let spawn = {
+            let instances = instances.clone();
+            move |mut rx: mpsc::Receiver<Command>| {
+                let instance = instances.fetch_add(1, Ordering::SeqCst);
+                let seen_tx = seen_tx.clone();
+                tokio::spawn(async move {
+                    while let Some(cmd) = rx.recv().await {
+                        assert!(instance > 0, "task failure");
+                        seen_tx.send(cmd).unwrap();
+                    }
+                })
+            }
+        };
+
+        let (cmd_tx, cmd_rx) = mpsc::channel(TASK_COMMAND_BUFFER);
+        let (_shutdown_tx, shutdown_rx) = watch::channel(());
+        let supervisor = tokio::spawn(supervise(
+            SchedulerTask::BreakScheduler,
+            cmd_rx,
+            spawn,
+            shared_state.clone(),
+            emitter.clone(),
+            shutdown_rx,
+        ));
+
+        cmd_tx.send(Command::SkipBreak(None)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(instances.load(Ordering::SeqCst), 1);
+        let failures = emitter.get_events_by_name("scheduler-task-failed");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0]["retryInS"], 1);
+
+        // Queued until the restart after a second
+        cmd_tx.send(Command::PostponeBreak(None)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1000)).await;
+
+        assert_eq!(instances.load(Ordering::SeqCst), 2);
+        assert!(!shared_state.read().in_break_session());
+        assert!(matches!(
+            seen_rx.recv().await,
+            Some(Command::Pause(PauseReason::Dnd, None))
+        ));
+        assert!(matches!(
+            seen_rx.recv().await,
+            Some(Command::PostponeBreak(None))
+        ));
+
+        // Closing the command channel ends supervision
+        drop(cmd_tx);
+        supervisor.await.unwrap();
+    }
+}
@@ -14,11 +14,13 @@
 use chrono::{DateTime, Datelike, Duration, Local, NaiveTime, TimeZone, Timelike, Utc, Weekday};
 use tauri::Manager;
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, BreakVerification, PausePolicy};
 use crate::core::schedule::{
-    BaseBreakSettings, LongBreakSettings, MiniBreakSettings, ScheduleSettings,
+    BaseBreakSettings, LongBreakCollision, LongBreakSettings, MiniBreakSettings, ScheduleKind,
+    ScheduleSettings, SkipEscalation,
 };
 use crate::core::time::TimeRange;
+use crate::scheduler::models::PauseReason;
 
 // ============================================================================
 // Configuration Builders
@@ -53,10 +55,13 @@ impl TestConfigBuilder {
         let default_schedule = ScheduleSettings {
             name: "Test Schedule".to_string(),
             enabled: true,
-            time_range: TimeRange {
+            kind: ScheduleKind::Interval,
+            focus_s: 1500,
+            count_active_time: false,
+            time_ranges: vec![TimeRange {
                 start: NaiveTime::MIN,
                 end: NaiveTime::MIN,
-            },
+            }],
             days_of_week: all_weekdays(),
             notification_before_s: 0,
             mini_breaks: MiniBreakSettings {
@@ -78,6 +83,8 @@ impl TestConfigBuilder {
                     ..BaseBreakSettings::default()
                 },
                 after_mini_breaks: 4,
+                interval_s: 0,
+                collision: LongBreakCollision::Merge,
             },
         };
 
@@ -114,6 +121,51 @@ impl TestConfigBuilder {
         self
     }
 
+    /// Make long breaks time-based (every N seconds of work)
+    pub fn long_break_interval_s(mut self, seconds: u32, collision: LongBreakCollision) -> Self {
+        self.config.schedules[0].long_breaks.interval_s = seconds;
+        self.config.schedules[0].long_breaks.collision = collision;
+        self
+    }
+
+    /// Set how the interval continues after a pause for `reason`
+    pub fn pause_policy(mut self, reason: PauseReason, policy: PausePolicy) -> Self {
+        let policies = &mut self.config.pause_policies;
+        match reason {
+            PauseReason::UserIdle => policies.user_idle = policy,
+            PauseReason::Dnd => policies.dnd = policy,
+            PauseReason::AppExclusion => policies.app_exclusion = policy,
+            PauseReason::Manual => policies.manual = policy,
+        }
+        self
+    }
+
+    /// Check breaks for input with `mode`
+    pub fn break_verification(mut self, mode: BreakVerification) -> Self {
+        self.config.break_verification = mode;
+        self
+    }
+
+    /// Escalate mini breaks with `policy` after `after_skips` skips in a row
+    pub fn mini_break_skip_escalation(mut self, policy: SkipEscalation, after_skips: u8) -> Self {
+        let base = &mut self.config.schedules[0].mini_breaks.base;
+        base.skip_escalation = policy;
+        base.escalate_after_skips = after_skips;
+        self
+    }
+
+    /// Let due breaks wait up to `seconds` for a pause in typing
+    pub fn break_grace_s(mut self, seconds: u32) -> Self {
+        self.config.break_grace_s = seconds;
+        self
+    }
+
+    /// Count only active time towards break intervals
+    pub fn count_active_time(mut self) -> Self {
+        self.config.schedules[0].count_active_time = true;
+        self
+    }
+
     /// Set notification time before break (seconds)
     pub fn notification_before_s(mut self, seconds: u32) -> Self {
         self.config.schedules[0].notification_before_s = seconds;
@@ -122,7 +174,13 @@ impl TestConfigBuilder {
 
     /// Set time range for the schedule
     pub fn time_range(mut self, range: TimeRange) -> Self {
-        self.config.schedules[0].time_range = range;
+        self.config.schedules[0].time_ranges = vec![range];
+        self
+    }
+
+    /// Set several time ranges for the schedule
+    pub fn time_ranges(mut self, ranges: Vec<TimeRange>) -> Self {
+        self.config.schedules[0].time_ranges = ranges;
         self
     }
 
@@ -362,7 +420,10 @@ pub fn minimal_schedule(
     ScheduleSettings {
         name: "Minimal Schedule".to_string(),
         enabled: true,
-        time_range,
+        kind: ScheduleKind::Interval,
+        focus_s: 1500,
+        count_active_time: false,
+        time_ranges: vec![time_range],
         days_of_week: days,
         notification_before_s: 0,
         mini_breaks: MiniBreakSettings {
@@ -381,10 +442,38 @@ pub fn minimal_schedule(
                 ..BaseBreakSettings::default()
             },
             after_mini_breaks: 0,
+            ..LongBreakSettings::default()
         },
     }
 }
 
+/// Create a Pomodoro schedule with 5min rests and a 15min long rest
+#[allow(unused)]
+pub fn pomodoro_schedule(focus_s: u32, after_mini_breaks: u8) -> ScheduleSettings {
+    ScheduleSettings {
+        name: "Pomodoro".to_string(),
+        kind: ScheduleKind::Pomodoro,
+        focus_s,
+        notification_before_s: 0,
+        mini_breaks: MiniBreakSettings {
+            base: BaseBreakSettings {
+                duration_s: 300,
+                ..BaseBreakSettings::default()
+            },
+            ..MiniBreakSettings::default()
+        },
+        long_breaks: LongBreakSettings {
+            base: BaseBreakSettings {
+                duration_s: 900,
+                ..BaseBreakSettings::default()
+            },
+            after_mini_breaks,
+            ..LongBreakSettings::default()
+        },
+        ..ScheduleSettings::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,24 +550,47 @@ mod tests {
 pub mod state_machine {
     use super::*;
     use crate::config::SharedConfig;
+    use crate::core::history::HistoryStore;
     use crate::core::payload::PromptPayloadStore;
     use crate::core::suggestions::{SharedSuggestions, SuggestionsConfig};
     use crate::scheduler::break_scheduler::BreakScheduler;
+    use crate::scheduler::clock::{Clock, SystemClock};
     use crate::scheduler::event_emitter::TestEventEmitter;
     use crate::scheduler::models::{SchedulerEvent, SchedulerStatus};
+    use crate::scheduler::progress::ProgressStore;
     use crate::scheduler::shared_state::create_shared_state;
 
     use tauri::AppHandle;
     use tauri::test::{MockRuntime, mock_builder, mock_context, noop_assets};
     use tokio::sync::watch;
 
+    /// Break scheduler hosted by a mock app
+    pub type TestBreakScheduler<C = SystemClock> =
+        BreakScheduler<TestEventEmitter, AppHandle<MockRuntime>, C>;
+
     /// Create a test break scheduler with mock dependencies
     ///
     /// Returns (`scheduler`, `event_emitter`, `shutdown_tx`, `app_handle`)
     pub fn create_test_break_scheduler(
         config: AppConfig,
     ) -> (
-        BreakScheduler<TestEventEmitter, MockRuntime>,
+        TestBreakScheduler,
+        TestEventEmitter,
+        watch::Sender<()>,
+        AppHandle<MockRuntime>,
+    ) {
+        create_test_break_scheduler_with_clock(config, SystemClock)
+    }
+
+    /// Create a test break scheduler reading the time from `clock`
+    ///
+    /// Use a [`FakeClock`](crate::scheduler::clock::FakeClock) to control the
+    /// wall clock and time zone the scheduler sees.
+    pub fn create_test_break_scheduler_with_clock<C: Clock>(
+        config: AppConfig,
+        clock: C,
+    ) -> (
+        TestBreakScheduler<C>,
         TestEventEmitter,
         watch::Sender<()>,
         AppHandle<MockRuntime>,
@@ -506,11 +618,19 @@ pub mod state_machine {
         let prompt_payload_store = PromptPayloadStore::new();
         app_handle.manage(prompt_payload_store);
 
+        // Install in-memory break history and scheduler progress
+        app_handle.manage(HistoryStore::in_memory());
+        app_handle.manage(ProgressStore::in_memory());
+
+        // Shared state is reachable from tests, as from commands in the app
+        app_handle.manage(shared_state.clone());
+
         let scheduler = BreakScheduler::new(
             app_handle.clone(),
             event_emitter.clone(),
             shutdown_rx,
             shared_state,
+            clock,
         );
 
         (scheduler, event_emitter, shutdown_tx, app_handle)
@@ -676,10 +796,12 @@ pub mod state_machine {
 pub mod manager {
     use super::*;
     use crate::config::SharedConfig;
+    use crate::core::history::HistoryStore;
     use crate::core::payload::PromptPayloadStore;
     use crate::core::suggestions::{SharedSuggestions, SuggestionsConfig};
     use crate::scheduler::event_emitter::TestEventEmitter;
     use crate::scheduler::models::Command;
+    use crate::scheduler::progress::ProgressStore;
     use crate::scheduler::shared_state::SharedState;
 
     use tauri::AppHandle;
@@ -730,6 +852,10 @@ pub mod manager {
         let prompt_payload_store = PromptPayloadStore::new();
         app_handle.manage(prompt_payload_store);
 
+        // Install in-memory break history and scheduler progress
+        app_handle.manage(HistoryStore::in_memory());
+        app_handle.manage(ProgressStore::in_memory());
+
         // Create channels
         let (cmd_tx, _cmd_rx) = mpsc::channel(32);
         let (shutdown_tx, _shutdown_rx) = watch::channel(());
@@ -756,7 +882,8 @@ pub mod manager {
     pub async fn spawn_test_manager(env: &ManagerTestEnv, cmd_rx: mpsc::Receiver<Command>) {
         use crate::scheduler::attention_timer::AttentionTimer;
         use crate::scheduler::break_scheduler::BreakScheduler;
-        use crate::scheduler::manager::broadcast_commands;
+        use crate::scheduler::clock::SystemClock;
+        use crate::scheduler::manager::{CommandTargets, broadcast_commands};
 
         let (break_cmd_tx, break_cmd_rx) = mpsc::channel::<Command>(32);
         let (attention_cmd_tx, attention_cmd_rx) = mpsc::channel::<Command>(32);
@@ -772,6 +899,7 @@ pub mod manager {
                 break_event_emitter,
                 break_shutdown_rx,
                 break_shared_state,
+                SystemClock,
             );
             scheduler.run(break_cmd_rx).await;
         });
@@ -787,6 +915,7 @@ pub mod manager {
                 attention_event_emitter,
                 attention_shutdown_rx,
                 attention_shared_state,
+                SystemClock,
             );
             timer.run(attention_cmd_rx).await;
         });
@@ -795,14 +924,19 @@ pub mod manager {
         let router_shutdown_rx = env.shutdown_tx.subscribe();
         let router_shared_state = env.shared_state.clone();
         let router_app_handle = env.app_handle.clone();
+        let router_targets = CommandTargets {
+            break_cmd_tx,
+            attention_cmd_tx,
+            events: env.event_emitter.clone(),
+        };
         tokio::spawn(async move {
             broadcast_commands(
                 cmd_rx,
-                break_cmd_tx,
-                attention_cmd_tx,
+                router_targets,
                 router_shutdown_rx,
                 router_shared_state,
                 router_app_handle,
+                SystemClock,
             )
             .await;
         });
@@ -0,0 +1,361 @@
+//! Projection of upcoming scheduler events
+//!
+//! Replays the decisions of the break scheduler and attention timer ahead of
+//! time, without any timers or side effects. The projection assumes every break
+//! is taken in full when it is due and that the scheduler is never paused.
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+
+use crate::config::AppConfig;
+use crate::scheduler::attention_timer::get_next_attention_time;
+use crate::scheduler::break_scheduler::calculate_next_break_pure;
+use crate::scheduler::event::{get_active_schedule, next_schedule_boundary};
+use crate::scheduler::models::SchedulerEvent;
+use crate::scheduler::progress::SchedulerProgress;
+
+/// A projected break or attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpcomingEvent {
+    pub kind: SchedulerEvent,
+    pub time: DateTime<Utc>,
+}
+
+/// Break cycle a projection continues from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BreakCycle {
+    /// Mini breaks taken since the last long break
+    pub mini_break_counter: u8,
+    /// Seconds worked since the last long break
+    pub work_since_long_break_s: u32,
+    /// When the last break ended
+    pub last_break_time: Option<DateTime<Utc>>,
+}
+
+impl From<SchedulerProgress> for BreakCycle {
+    fn from(progress: SchedulerProgress) -> Self {
+        Self {
+            mini_break_counter: progress.mini_break_counter,
+            work_since_long_break_s: progress.work_since_long_break_s,
+            last_break_time: progress.last_break_time,
+        }
+    }
+}
+
+/// Project the next `limit` breaks and attentions after `now`, up to `until`
+///
+/// The break cycle continues from `cycle`, the same progress the break
+/// scheduler keeps. Schedules are evaluated in `timezone`. Events are sorted by time.
+#[must_use]
+pub fn project_timeline<Tz: TimeZone>(
+    config: &AppConfig,
+    now: DateTime<Utc>,
+    timezone: &Tz,
+    cycle: BreakCycle,
+    until: DateTime<Utc>,
+    limit: usize,
+) -> Vec<UpcomingEvent> {
+    let mut events = project_breaks(config, now, timezone, cycle, until, limit);
+    events.extend(project_attentions(config, now, timezone, until, limit));
+    events.sort_by_key(|event| event.time);
+    events.truncate(limit);
+    events
+}
+
+/// Project breaks the way the break scheduler would take them
+fn project_breaks<Tz: TimeZone>(
+    config: &AppConfig,
+    mut now: DateTime<Utc>,
+    timezone: &Tz,
+    mut cycle: BreakCycle,
+    until: DateTime<Utc>,
+    limit: usize,
+) -> Vec<UpcomingEvent> {
+    let mut events = Vec::new();
+    // The first interval starts now unless continued from saved progress
+    cycle.last_break_time.get_or_insert(now);
+
+    while events.len() < limit && now < until {
+        let boundary = next_schedule_boundary(config, &now.with_timezone(timezone));
+
+        let Some(info) = calculate_next_break_pure(
+            config,
+            now,
+            timezone,
+            cycle.mini_break_counter,
+            cycle.work_since_long_break_s,
+            cycle.last_break_time,
+        ) else {
+            // Idle until the next schedule starts, which begins a fresh interval
+            let Some(boundary) = boundary else {
+                break;
+            };
+            now = boundary;
+            cycle.last_break_time = Some(boundary);
+            continue;
+        };
+
+        // A break that is already due is taken immediately
+        let break_time = info.break_time.max(now);
+        if let Some(boundary) = boundary
+            && boundary < break_time
+        {
+            // The active schedule changes first, re-evaluate there
+            now = boundary;
+            continue;
+        }
+        if break_time > until {
+            break;
+        }
+
+        events.push(UpcomingEvent {
+            kind: info.event,
+            time: break_time,
+        });
+
+        // The next interval starts when the break ends
+        now = break_time
+            + Duration::seconds(i64::from(break_duration_s(
+                config,
+                info.event,
+                &break_time.with_timezone(timezone),
+            )));
+
+        // As in the break scheduler, the time since the previous break counts as work
+        let worked_s = cycle.last_break_time.map_or(0, |last| {
+            u32::try_from((now - last).num_seconds().max(0)).unwrap_or(u32::MAX)
+        });
+        cycle.last_break_time = Some(now);
+        if let SchedulerEvent::LongBreak(_) = info.event {
+            cycle.mini_break_counter = 0;
+            cycle.work_since_long_break_s = 0;
+        } else {
+            cycle.mini_break_counter = cycle.mini_break_counter.saturating_add(1);
+            cycle.work_since_long_break_s = cycle.work_since_long_break_s.saturating_add(worked_s);
+        }
+    }
+
+    events
+}
+
+/// Duration of a break of the schedule active at `time`
+fn break_duration_s<Tz: TimeZone>(
+    config: &AppConfig,
+    event: SchedulerEvent,
+    time: &DateTime<Tz>,
+) -> u32 {
+    get_active_schedule(config, time.time(), time.weekday()).map_or(0, |schedule| match event {
+        SchedulerEvent::MiniBreak(_) => schedule.mini_breaks.base.duration_s,
+        SchedulerEvent::LongBreak(_) => schedule.long_breaks.base.duration_s,
+        SchedulerEvent::Attention(_) => 0,
+    })
+}
+
+/// Project up to `limit` occurrences of every attention
+fn project_attentions<Tz: TimeZone>(
+    config: &AppConfig,
+    now: DateTime<Utc>,
+    timezone: &Tz,
+    until: DateTime<Utc>,
+    limit: usize,
+) -> Vec<UpcomingEvent> {
+    let next_after = |attention, time: DateTime<Utc>| {
+        get_next_attention_time(attention, &time.with_timezone(timezone))
+    };
+
+    config
+        .attentions
+        .iter()
+        .flat_map(|attention| {
+            std::iter::successors(next_after(attention, now), move |time| {
+                next_after(attention, *time)
+            })
+            .take_while(move |time| *time <= until)
+            .take(limit)
+            .map(move |time| UpcomingEvent {
+                kind: SchedulerEvent::Attention(attention.id),
+                time,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::schedule::{AttentionSettings, LongBreakCollision};
+    use crate::core::time::ShortTimes;
+    use crate::scheduler::test_helpers::*;
+
+    use chrono::Local;
+
+    /// Weekday schedule 09:00-17:00 with a long break after 3 mini breaks
+    fn workday_config() -> AppConfig {
+        TestConfigBuilder::new()
+            .time_range(time_range(9, 0, 17, 0))
+            .days_of_week(workdays())
+            .long_break_after_mini_breaks(3)
+            .build()
+    }
+
+    #[test]
+    fn projects_mini_and_long_breaks_in_cycle() {
+        let config = workday_config();
+        let now = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+        let until = test_datetime_with_local(2025, 9, 1, 12, 0, 0);
+
+        let events = project_timeline(&config, now, &Local, BreakCycle::default(), until, 10);
+
+        let kinds: Vec<_> = events.iter().map(|e| e.kind.is_long()).collect();
+        assert_eq!(kinds, vec![false, false, false, true, false]);
+
+        // Each interval starts when the previous break ends
+        assert_eq!(
+            events[0].time,
+            test_datetime_with_local(2025, 9, 1, 10, 20, 0)
+        );
+        assert_eq!(
+            events[1].time,
+            test_datetime_with_local(2025, 9, 1, 10, 40, 20)
+        );
+        assert_eq!(
+            events[3].time,
+            test_datetime_with_local(2025, 9, 1, 11, 21, 0)
+        );
+        assert_eq!(
+            events[4].time,
+            test_datetime_with_local(2025, 9, 1, 11, 46, 0)
+        );
+    }
+
+    #[test]
+    fn projects_time_based_long_break() {
+        let config = TestConfigBuilder::new()
+            .time_range(time_range(9, 0, 17, 0))
+            .days_of_week(workdays())
+            .long_break_interval_s(3600, LongBreakCollision::Merge)
+            .build();
+        let now = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+        let until = test_datetime_with_local(2025, 9, 1, 12, 0, 0);
+
+        let events = project_timeline(&config, now, &Local, BreakCycle::default(), until, 3);
+
+        let kinds: Vec<_> = events.iter().map(|e| e.kind.is_long()).collect();
+        assert_eq!(kinds, vec![false, false, true]);
+        // Due at 11:00 after an hour of work, merged with the mini break at 11:00:40
+        assert_eq!(
+            events[2].time,
+            test_datetime_with_local(2025, 9, 1, 11, 0, 40)
+        );
+    }
+
+    #[test]
+    fn continues_from_saved_progress() {
+        let config = workday_config();
+        let now = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+        let cycle = BreakCycle {
+            mini_break_counter: 3,
+            last_break_time: Some(test_datetime_with_local(2025, 9, 1, 9, 50, 0)),
+            ..BreakCycle::default()
+        };
+        let until = test_datetime_with_local(2025, 9, 1, 17, 0, 0);
+
+        let events = project_timeline(&config, now, &Local, cycle, until, 1);
+
+        assert_eq!(events.len(), 1);
+        assert!(events[0].kind.is_long());
+        assert_eq!(
+            events[0].time,
+            test_datetime_with_local(2025, 9, 1, 10, 10, 0)
+        );
+    }
+
+    #[test]
+    fn overdue_break_is_projected_now() {
+        let config = workday_config();
+        let now = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+        let cycle = BreakCycle {
+            last_break_time: Some(test_datetime_with_local(2025, 9, 1, 9, 0, 0)),
+            ..BreakCycle::default()
+        };
+        let until = test_datetime_with_local(2025, 9, 1, 17, 0, 0);
+
+        let events = project_timeline(&config, now, &Local, cycle, until, 1);
+
+        assert_eq!(events[0].time, now);
+    }
+
+    #[test]
+    fn skips_time_outside_schedules() {
+        let config = workday_config();
+        // Friday evening, the next schedule starts on Monday morning
+        let now = test_datetime_with_local(2025, 9, 5, 18, 0, 0);
+        let until = test_datetime_with_local(2025, 9, 8, 12, 0, 0);
+
+        let events = project_timeline(&config, now, &Local, BreakCycle::default(), until, 1);
+
+        assert_eq!(
+            events[0].time,
+            test_datetime_with_local(2025, 9, 8, 9, 20, 0)
+        );
+    }
+
+    #[test]
+    fn stops_at_schedule_end() {
+        let config = workday_config();
+        let now = test_datetime_with_local(2025, 9, 1, 16, 30, 0);
+        let until = test_datetime_with_local(2025, 9, 1, 23, 59, 59);
+
+        let events = project_timeline(&config, now, &Local, BreakCycle::default(), until, 10);
+
+        // 16:50 fits, 17:10:20 is after the range ends at 17:00
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].time,
+            test_datetime_with_local(2025, 9, 1, 16, 50, 0)
+        );
+    }
+
+    #[test]
+    fn merges_attentions_by_time() {
+        let mut config = workday_config();
+        config.attentions = vec![AttentionSettings {
+            enabled: true,
+            times: ShortTimes::new(vec![naive_time(10, 30, 0), naive_time(15, 0, 0)]),
+            days_of_week: all_weekdays(),
+            ..Default::default()
+        }];
+        let now = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+        let until = test_datetime_with_local(2025, 9, 1, 11, 0, 0);
+
+        let events = project_timeline(&config, now, &Local, BreakCycle::default(), until, 10);
+
+        let attention_times: Vec<_> = events
+            .iter()
+            .filter(|e| e.kind.is_attention())
+            .map(|e| e.time)
+            .collect();
+        assert_eq!(
+            attention_times,
+            vec![test_datetime_with_local(2025, 9, 1, 10, 30, 0)]
+        );
+        assert!(events.is_sorted_by_key(|e| e.time));
+        assert_eq!(events.len(), 3);
+    }
+
+    #[test]
+    fn respects_limit_and_horizon() {
+        let config = workday_config();
+        let now = test_datetime_with_local(2025, 9, 1, 10, 0, 0);
+
+        let until = test_datetime_with_local(2025, 9, 1, 17, 0, 0);
+        assert_eq!(
+            project_timeline(&config, now, &Local, BreakCycle::default(), until, 2).len(),
+            2
+        );
+
+        let until = test_datetime_with_local(2025, 9, 1, 10, 10, 0);
+        assert!(
+            project_timeline(&config, now, &Local, BreakCycle::default(), until, 10).is_empty()
+        );
+    }
+}
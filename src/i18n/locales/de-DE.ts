@@ -121,9 +121,17 @@ const deDE: LocaleStrings = {
     volume: "Lautstärke",
   },
   break: {
+    activityDetected:
+      "Eingabe erkannt – entfernen Sie sich vom Computer, um Ihre Pause zu machen",
     attention: "Achtung",
     attentionMessage:
       "Konzentrieren Sie sich auf die Gegenwart, eliminieren Sie Ablenkungen.",
+    escalatedLongBreak:
+      "Sie haben {count} Pausen in Folge übersprungen, daher ist dies eine lange Pause",
+    escalatedShortenInterval:
+      "Sie haben {count} Pausen in Folge übersprungen, daher kam diese früher",
+    escalatedStrict:
+      "Sie haben {count} Pausen in Folge übersprungen, diese kann nicht übersprungen werden",
     gotIt: "Verstanden",
     longBreakMessage:
       "Machen Sie eine längere Pause und tanken Sie neue Energie.",
@@ -140,6 +148,7 @@ const deDE: LocaleStrings = {
     skip: "Überspringen",
     strict: "Strikter Modus - Kann nicht übersprungen werden",
     suggestion: "Vorschlag",
+    task: "Aufgabe: {task}",
     timerLabel: "Verbleibende Zeit",
   },
   days: {
@@ -152,6 +161,7 @@ const deDE: LocaleStrings = {
     Wed: "Mi",
   },
   general: {
+    activeTime: "{active}/{target} Min. aktiv",
     allScreens: "Pausen auf allen Monitoren anzeigen",
     allScreensHint:
       "Pausenfenster auf jedem Bildschirm in Multi-Monitor-Konfiguration anzeigen",
@@ -160,6 +170,16 @@ const deDE: LocaleStrings = {
     autostartEnabled: "Autostart erfolgreich aktiviert",
     autostartHint: "Focust automatisch beim Anmelden starten",
     behaviorSettings: "Anwendungsverhalten",
+    breakGraceHint:
+      "Eine fällige Pause beginnt, sobald Sie aufhören zu tippen; 0 beginnt pünktlich",
+    breakGraceLabel: "Auf eine Tipppause warten, höchstens",
+    breakVerification: "Wenn Sie während einer Pause weiterarbeiten",
+    breakVerificationExtend: "Pause verlängern",
+    breakVerificationHint:
+      "Tastatur- und Mauseingaben während einer Pause werden über die Leerlaufzeit des Systems erkannt",
+    breakVerificationOff: "Nicht prüfen",
+    breakVerificationRecord: "Pause als unterbrochen erfassen",
+    breakVerificationRestart: "Pause neu starten",
     fullscreen: "Vollbild",
     hoursMinutes: "{hours}h {minutes}m",
     imminent: "beginnt bald",
@@ -173,11 +193,25 @@ const deDE: LocaleStrings = {
     minutesRemaining: "{minutes} Min",
     monitorDnd: "Pausieren, wenn Nicht stören aktiviert ist",
     monitorDndHint:
-      "Alle Erinnerungen automatisch pausieren, wenn der Nicht-stören-Modus erkannt wird. Erfordert Neustart.",
+      "Alle Erinnerungen automatisch pausieren, wenn der Nicht-stören-Modus erkannt wird.",
     nextBreak: "{kind} in {time}",
     nextBreakNotification: "Benachrichtigung für {kind} in {time}",
     notification: "Benachrichtigung",
     paused: "Planer pausiert",
+    pausedRemaining: "Planer pausiert, fortgesetzt in {time}",
+    pausePolicies: "Nach einer Pause",
+    pausePoliciesHint:
+      "Wie der Countdown zur nächsten Pause nach jeder Art von Unterbrechung weiterläuft",
+    pausePolicyCreditBreak: "Als Pause zählen, wenn lang genug",
+    pausePolicyFreeze: "Dort fortsetzen, wo er anhielt",
+    pausePolicyReset: "Intervall neu starten",
+    pauseReasonAppExclusion: "Ausgeschlossene Anwendung",
+    pauseReasonDnd: "Nicht stören",
+    pauseReasonManual: "Von Ihnen pausiert",
+    pauseReasonUserIdle: "Inaktivität",
+    pomodoroShortcut: "Pomodoro-Tastenkürzel",
+    pomodoroShortcutHint:
+      "Klicken, um Tasten aufzunehmen, z. B. Strg+Umschalt+F",
     postponeHint:
       "Verschieben schiebt die nächste Pause um die konfigurierte Dauer hinaus",
     postponeShortcut: "Verschieben-Tastenkombination",
@@ -206,8 +240,16 @@ const deDE: LocaleStrings = {
     suggestions: "Pausenvorschläge",
   },
   schedule: {
+    addTimeRange: "Zeitbereich hinzufügen",
     afterMiniBreaks: "Nach Mini-Pausen",
     audio: "Sound",
+    collision: "Bei Mini-Pausen",
+    collisionDefer: "Auf die nächste Mini-Pause warten",
+    collisionMerge: "Mit der nächstgelegenen Mini-Pause zusammenlegen",
+    collisionReplace: "Pünktlich, statt der Mini-Pause",
+    countActiveTime: "Nur aktive Zeit zählen",
+    countActiveTimeHint:
+      "Pausen werden nach so viel Zeit an der Tastatur fällig. Eingabepausen, z. B. beim Lesen, werden nicht gezählt.",
     create: "Plan erstellen",
     days: "Wochentage",
     description: "Pausenpläne und -zeiten konfigurieren",
@@ -218,21 +260,38 @@ const deDE: LocaleStrings = {
     enabled: "Plan aktivieren",
     enabledStatus: "Aktiviert",
     end: "Ende",
+    escalateAfterSkips: "Nach übersprungenen Pausen in Folge",
+    focusMinutes: "Fokus",
     hint: "Erstellen Sie mehrere Pläne, z.B. unterschiedliche Zeiten für Wochentage und Wochenenden",
     intervalMinutes: "Intervall",
+    kind: "Typ",
+    kindInterval: "Intervall",
+    kindPomodoro: "Pomodoro",
     longBreak: "Lange Pause",
+    longIntervalHint:
+      "Legen Sie eine Arbeitszeit fest, um lange Pausen nach Zeit zu machen, z. B. 5 Minuten pro Stunde. Bei 0 werden Mini-Pausen gezählt.",
+    longIntervalMinutes: "Alle (Arbeitszeit)",
     maxPostponeCount: "Maximale Verschiebungsanzahl",
     miniBreak: "Mini-Pause",
     minutesUnit: "Min",
     name: "Planname",
     notifyBefore: "Vorher benachrichtigen",
+    pomodoroHint:
+      "Pomodoro-Sitzungen werden über das Tray-Menü oder das Pomodoro-Tastenkürzel gestartet. Die Pausen verwenden die Einstellungen für kurze und lange Pausen unten.",
     postponeMinutes: "Verschieben",
     secondsUnit: "Sek",
+    skipEscalation: "Bei wiederholtem Überspringen",
+    skipEscalationLongBreak: "Nächste Pause ist eine lange Pause",
+    skipEscalationOff: "Nichts passiert",
+    skipEscalationShortenInterval: "Nächste Pause kommt früher",
+    skipEscalationStrict: "Nächste Pause ist strikt",
     start: "Start",
     strictMode: "Strikter Modus",
     suggestions: "Vorschläge",
     theme: "Design",
     timeRange: "Aktiver Zeitbereich",
+    timeRangeHint:
+      "Beide auf 00:00 setzen für den ganzen Tag. Ein Ende vor dem Beginn reicht über Mitternacht hinaus.",
     title: "Pausenpläne",
     totalCount: "Pläne",
   },
@@ -290,6 +349,7 @@ const deDE: LocaleStrings = {
   toast: {
     attentionTriggered: "Aufmerksamkeit ausgelöst",
     attentionTriggerFailed: "Aufmerksamkeit auslösen fehlgeschlagen",
+    breakCredited: "Willkommen zurück! Ihre Abwesenheit zählt als {kind}",
     breakSkipFailed: "Aktuelle Pause überspringen fehlgeschlagen",
     breakSkipped: "Die aktuelle Pause wurde übersprungen",
     directoryOpened: "Konfigurationsverzeichnis geöffnet",
@@ -305,6 +365,8 @@ const deDE: LocaleStrings = {
     openUrlFailed: "{url} öffnen fehlgeschlagen",
     saved: "Konfiguration gespeichert",
     saveFailed: "Konfiguration speichern fehlgeschlagen",
+    schedulerRestarting:
+      "Pausenerinnerungen wurden unerwartet beendet und werden neu gestartet",
     updateCheckFailed: "Nach Updates suchen fehlgeschlagen",
   },
 };
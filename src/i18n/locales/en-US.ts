@@ -116,8 +116,16 @@ const enUS = {
     volume: "Volume",
   },
   break: {
+    activityDetected:
+      "Input detected, step away from the computer to take your break",
     attention: "Attention",
     attentionMessage: "Focus on the present, eliminate distractions.",
+    escalatedLongBreak:
+      "You skipped {count} breaks in a row, so this one is a long break",
+    escalatedShortenInterval:
+      "You skipped {count} breaks in a row, so this one came early",
+    escalatedStrict:
+      "You skipped {count} breaks in a row, so this one cannot be skipped",
     gotIt: "Got it",
     longBreakMessage: "Take a longer rest and recharge.",
     miniBreakMessage: "Time for a quick pause—stretch, hydrate, breathe.",
@@ -130,6 +138,7 @@ const enUS = {
     skip: "Skip",
     strict: "Strict mode - Cannot skip",
     suggestion: "Suggestion",
+    task: "Task: {task}",
     timerLabel: "Time remaining",
   },
   days: {
@@ -142,6 +151,7 @@ const enUS = {
     Wed: "Wed",
   },
   general: {
+    activeTime: "{active}/{target} min active",
     allScreens: "Show breaks on all monitors",
     allScreensHint:
       "Display break window on every screen in multi-monitor setup",
@@ -150,6 +160,16 @@ const enUS = {
     autostartEnabled: "Autostart enabled successfully",
     autostartHint: "Automatically start Focust when you log in",
     behaviorSettings: "Application Behavior",
+    breakGraceHint:
+      "A due break opens once you stop typing, 0 opens it on time",
+    breakGraceLabel: "Wait for a pause in typing for up to",
+    breakVerification: "When you keep working during a break",
+    breakVerificationExtend: "Extend the break",
+    breakVerificationHint:
+      "Keyboard and mouse input during a break is detected from the system idle time",
+    breakVerificationOff: "Do not check",
+    breakVerificationRecord: "Record the break as interrupted",
+    breakVerificationRestart: "Restart the break",
     fullscreen: "Fullscreen",
     hoursMinutes: "{hours}h {minutes}m",
     imminent: "starting soon",
@@ -163,11 +183,24 @@ const enUS = {
     minutesRemaining: "{minutes} min",
     monitorDnd: "Pause when Do Not Disturb is enabled",
     monitorDndHint:
-      "Automatically pause all reminders when DND mode is detected. Requires restart.",
+      "Automatically pause all reminders when DND mode is detected.",
     nextBreak: "{kind} in {time}",
     nextBreakNotification: "Notification for {kind} in {time}",
     notification: "Notification",
     paused: "Scheduler paused",
+    pausedRemaining: "Scheduler paused, resumes in {time}",
+    pausePolicies: "After a pause",
+    pausePoliciesHint:
+      "How the countdown to the next break continues after each kind of pause",
+    pausePolicyCreditBreak: "Count as a break if long enough",
+    pausePolicyFreeze: "Continue where it stopped",
+    pausePolicyReset: "Restart the interval",
+    pauseReasonAppExclusion: "Excluded application",
+    pauseReasonDnd: "Do Not Disturb",
+    pauseReasonManual: "Paused by you",
+    pauseReasonUserIdle: "Inactivity",
+    pomodoroShortcut: "Pomodoro shortcut",
+    pomodoroShortcutHint: "Click to capture keys, e.g., Ctrl+Shift+F",
     postponeHint: "Postpone pushes the next break by the configured duration",
     postponeShortcut: "Postpone shortcut",
     postponeShortcutHint: "Click to capture keys, e.g., Ctrl+Shift+P",
@@ -194,8 +227,16 @@ const enUS = {
     suggestions: "Break Suggestions",
   },
   schedule: {
+    addTimeRange: "Add time range",
     afterMiniBreaks: "After mini breaks",
     audio: "Sound",
+    collision: "Around mini breaks",
+    collisionDefer: "Wait for the next mini break",
+    collisionMerge: "Merge with the closest mini break",
+    collisionReplace: "On time, replacing the mini break",
+    countActiveTime: "Count only active time",
+    countActiveTimeHint:
+      "Breaks are due after this much time at the keyboard. Pauses in input, e.g. while reading, are not counted.",
     create: "Create schedule",
     days: "Days of week",
     description: "Configure break schedules and timing",
@@ -205,21 +246,38 @@ const enUS = {
     enabled: "Enable schedule",
     enabledStatus: "Enabled",
     end: "End",
+    escalateAfterSkips: "After skips in a row",
+    focusMinutes: "Focus",
     hint: "Create multiple schedules, e.g., different timing for weekdays and weekends",
     intervalMinutes: "Interval",
+    kind: "Type",
+    kindInterval: "Interval",
+    kindPomodoro: "Pomodoro",
     longBreak: "Long break",
+    longIntervalHint:
+      "Set a work time to take long breaks by time, e.g. 5 minutes every hour. Leave at 0 to count mini breaks.",
+    longIntervalMinutes: "Every (work time)",
     maxPostponeCount: "Max postpone count",
     miniBreak: "Mini break",
     minutesUnit: "min",
     name: "Schedule name",
     notifyBefore: "Notify before",
+    pomodoroHint:
+      "Pomodoro sessions are started from the tray menu or the Pomodoro shortcut. Rests use the mini and long break settings below.",
     postponeMinutes: "Postpone",
     secondsUnit: "sec",
+    skipEscalation: "When skipped repeatedly",
+    skipEscalationLongBreak: "Next break is a long break",
+    skipEscalationOff: "Nothing happens",
+    skipEscalationShortenInterval: "Next break comes sooner",
+    skipEscalationStrict: "Next break is strict",
     start: "Start",
     strictMode: "Strict mode",
     suggestions: "Suggestions",
     theme: "Theme",
     timeRange: "Active time range",
+    timeRangeHint:
+      "Set both to 00:00 for all day. An end earlier than the start continues past midnight.",
     title: "Break Schedules",
     totalCount: "schedules",
   },
@@ -274,6 +332,7 @@ const enUS = {
   toast: {
     attentionTriggered: "Attention triggered",
     attentionTriggerFailed: "Attention triggered failed",
+    breakCredited: "Welcome back! Your time away counted as a {kind}",
     breakSkipFailed: "Skip current break failed",
     breakSkipped: "The current break has been skipped",
     directoryOpened: "Configuration directory opened",
@@ -289,6 +348,8 @@ const enUS = {
     openUrlFailed: "Failed to open {url}",
     saved: "Configuration saved",
     saveFailed: "Failed to save configuration",
+    schedulerRestarting:
+      "Break reminders stopped unexpectedly and are being restarted",
     updateCheckFailed: "Failed to check for updates",
   },
 };
@@ -120,8 +120,16 @@ const esES: LocaleStrings = {
     volume: "Volumen",
   },
   break: {
+    activityDetected:
+      "Se detectó actividad, aléjate del ordenador para tomar tu descanso",
     attention: "Atención",
     attentionMessage: "Concéntrate en el presente, elimina distracciones.",
+    escalatedLongBreak:
+      "Te saltaste {count} descansos seguidos, así que este es un descanso largo",
+    escalatedShortenInterval:
+      "Te saltaste {count} descansos seguidos, así que este llegó antes",
+    escalatedStrict:
+      "Te saltaste {count} descansos seguidos, este no se puede saltar",
     gotIt: "Entendido",
     longBreakMessage: "Toma un descanso más largo y recarga energías.",
     miniBreakMessage:
@@ -136,6 +144,7 @@ const esES: LocaleStrings = {
     skip: "Omitir",
     strict: "Modo estricto - No se puede omitir",
     suggestion: "Sugerencia",
+    task: "Tarea: {task}",
     timerLabel: "Tiempo restante",
   },
   days: {
@@ -148,6 +157,7 @@ const esES: LocaleStrings = {
     Wed: "Mié",
   },
   general: {
+    activeTime: "{active}/{target} min activos",
     allScreens: "Mostrar descansos en todos los monitores",
     allScreensHint:
       "Muestra la ventana de descanso en cada pantalla en configuración multi-monitor",
@@ -156,6 +166,16 @@ const esES: LocaleStrings = {
     autostartEnabled: "Inicio automático habilitado exitosamente",
     autostartHint: "Inicia Focust automáticamente cuando inicies sesión",
     behaviorSettings: "Comportamiento de la aplicación",
+    breakGraceHint:
+      "Un descanso pendiente se abre cuando dejas de escribir; 0 lo abre a su hora",
+    breakGraceLabel: "Esperar una pausa al escribir hasta",
+    breakVerification: "Si sigues trabajando durante un descanso",
+    breakVerificationExtend: "Alargar el descanso",
+    breakVerificationHint:
+      "La actividad de teclado y ratón durante un descanso se detecta con el tiempo de inactividad del sistema",
+    breakVerificationOff: "No comprobar",
+    breakVerificationRecord: "Registrar el descanso como interrumpido",
+    breakVerificationRestart: "Reiniciar el descanso",
     fullscreen: "Pantalla completa",
     hoursMinutes: "{hours}h {minutes}m",
     imminent: "comenzando pronto",
@@ -169,11 +189,24 @@ const esES: LocaleStrings = {
     minutesRemaining: "{minutes} min",
     monitorDnd: "Pausar cuando No molestar esté habilitado",
     monitorDndHint:
-      "Pausa automáticamente todos los recordatorios cuando se detecta el modo No molestar. Requiere reinicio.",
+      "Pausa automáticamente todos los recordatorios cuando se detecta el modo No molestar.",
     nextBreak: "{kind} en {time}",
     nextBreakNotification: "Notificación de {kind} en {time}",
     notification: "Notificación",
     paused: "Programador pausado",
+    pausedRemaining: "Programador pausado, se reanuda en {time}",
+    pausePolicies: "Después de una pausa",
+    pausePoliciesHint:
+      "Cómo continúa la cuenta atrás hasta el próximo descanso tras cada tipo de pausa",
+    pausePolicyCreditBreak: "Contar como descanso si es suficiente",
+    pausePolicyFreeze: "Continuar donde se detuvo",
+    pausePolicyReset: "Reiniciar el intervalo",
+    pauseReasonAppExclusion: "Aplicación excluida",
+    pauseReasonDnd: "No molestar",
+    pauseReasonManual: "Pausado por ti",
+    pauseReasonUserIdle: "Inactividad",
+    pomodoroShortcut: "Atajo de Pomodoro",
+    pomodoroShortcutHint: "Haz clic para capturar teclas, p. ej., Ctrl+Shift+F",
     postponeHint:
       "Posponer retrasa el siguiente descanso por la duración configurada",
     postponeShortcut: "Atajo de posponer",
@@ -201,8 +234,16 @@ const esES: LocaleStrings = {
     suggestions: "Sugerencias de descanso",
   },
   schedule: {
+    addTimeRange: "Añadir rango de tiempo",
     afterMiniBreaks: "Después de descansos mini",
     audio: "Sonido",
+    collision: "Junto a descansos mini",
+    collisionDefer: "Esperar al siguiente descanso mini",
+    collisionMerge: "Unir al descanso mini más cercano",
+    collisionReplace: "A su hora, en lugar del descanso mini",
+    countActiveTime: "Contar solo el tiempo activo",
+    countActiveTimeHint:
+      "Los descansos llegan tras ese tiempo frente al teclado. Las pausas sin actividad, p. ej., al leer, no cuentan.",
     create: "Crear horario",
     days: "Días de la semana",
     description: "Configura horarios y tiempos de descanso",
@@ -213,21 +254,38 @@ const esES: LocaleStrings = {
     enabled: "Habilitar horario",
     enabledStatus: "Habilitado",
     end: "Fin",
+    escalateAfterSkips: "Tras saltos seguidos",
+    focusMinutes: "Concentración",
     hint: "Crea múltiples horarios, p. ej., diferentes tiempos para días laborables y fines de semana",
     intervalMinutes: "Intervalo",
+    kind: "Tipo",
+    kindInterval: "Intervalo",
+    kindPomodoro: "Pomodoro",
     longBreak: "Descanso largo",
+    longIntervalHint:
+      "Indica un tiempo de trabajo para tomar descansos largos por tiempo, p. ej., 5 minutos cada hora. Déjalo en 0 para contar descansos mini.",
+    longIntervalMinutes: "Cada (tiempo de trabajo)",
     maxPostponeCount: "Máximo número de aplazamientos",
     miniBreak: "Descanso mini",
     minutesUnit: "min",
     name: "Nombre del horario",
     notifyBefore: "Notificar antes",
+    pomodoroHint:
+      "Las sesiones Pomodoro se inician desde el menú de la bandeja o con el atajo de Pomodoro. Los descansos usan los ajustes de pausa corta y larga de abajo.",
     postponeMinutes: "Posponer",
     secondsUnit: "seg",
+    skipEscalation: "Al saltarse repetidamente",
+    skipEscalationLongBreak: "El próximo es un descanso largo",
+    skipEscalationOff: "No pasa nada",
+    skipEscalationShortenInterval: "El próximo descanso llega antes",
+    skipEscalationStrict: "El próximo descanso es estricto",
     start: "Inicio",
     strictMode: "Modo estricto",
     suggestions: "Sugerencias",
     theme: "Tema",
     timeRange: "Rango de tiempo activo",
+    timeRangeHint:
+      "Ponga ambos en 00:00 para todo el día. Un fin anterior al inicio continúa pasada la medianoche.",
     title: "Horarios de descanso",
     totalCount: "horarios",
   },
@@ -284,6 +342,7 @@ const esES: LocaleStrings = {
   toast: {
     attentionTriggered: "Atención activada",
     attentionTriggerFailed: "Falló activación de atención",
+    breakCredited: "¡Bienvenido de nuevo! Tu ausencia cuenta como {kind}",
     breakSkipFailed: "Falló omitir descanso actual",
     breakSkipped: "El descanso actual ha sido omitido",
     directoryOpened: "Directorio de configuración abierto",
@@ -299,6 +358,8 @@ const esES: LocaleStrings = {
     openUrlFailed: "Falló abrir {url}",
     saved: "Configuración guardada",
     saveFailed: "Falló guardar configuración",
+    schedulerRestarting:
+      "Los recordatorios de descanso se detuvieron inesperadamente y se están reiniciando",
     updateCheckFailed: "Falló buscar actualizaciones",
   },
 };
@@ -122,9 +122,17 @@ const frFR: LocaleStrings = {
     volume: "Volume",
   },
   break: {
+    activityDetected:
+      "Activité détectée, éloignez-vous de l'ordinateur pour faire votre pause",
     attention: "Attention",
     attentionMessage:
       "Concentrez-vous sur le présent, éliminez les distractions.",
+    escalatedLongBreak:
+      "Vous avez sauté {count} pauses d'affilée, celle-ci est donc une pause longue",
+    escalatedShortenInterval:
+      "Vous avez sauté {count} pauses d'affilée, celle-ci arrive donc plus tôt",
+    escalatedStrict:
+      "Vous avez sauté {count} pauses d'affilée, celle-ci ne peut pas être sautée",
     gotIt: "Compris",
     longBreakMessage: "Prenez un repos plus long et rechargez vos batteries.",
     miniBreakMessage:
@@ -140,6 +148,7 @@ const frFR: LocaleStrings = {
     skip: "Ignorer",
     strict: "Mode strict - Impossible d'ignorer",
     suggestion: "Suggestion",
+    task: "Tâche : {task}",
     timerLabel: "Temps restant",
   },
   days: {
@@ -152,6 +161,7 @@ const frFR: LocaleStrings = {
     Wed: "Mer",
   },
   general: {
+    activeTime: "{active}/{target} min d'activité",
     allScreens: "Afficher les pauses sur tous les moniteurs",
     allScreensHint:
       "Afficher la fenêtre de pause sur chaque écran dans une configuration multi-moniteurs",
@@ -161,6 +171,16 @@ const frFR: LocaleStrings = {
     autostartHint:
       "Démarrer automatiquement Focust lorsque vous vous connectez",
     behaviorSettings: "Comportement de l'application",
+    breakGraceHint:
+      "Une pause due s'ouvre dès que vous arrêtez de taper ; 0 l'ouvre à l'heure",
+    breakGraceLabel: "Attendre une pause de frappe jusqu'à",
+    breakVerification: "Si vous continuez à travailler pendant une pause",
+    breakVerificationExtend: "Prolonger la pause",
+    breakVerificationHint:
+      "La saisie clavier et souris pendant une pause est détectée via le temps d'inactivité du système",
+    breakVerificationOff: "Ne pas vérifier",
+    breakVerificationRecord: "Enregistrer la pause comme interrompue",
+    breakVerificationRestart: "Recommencer la pause",
     fullscreen: "Plein écran",
     hoursMinutes: "{hours}h {minutes}m",
     imminent: "commence bientôt",
@@ -174,11 +194,24 @@ const frFR: LocaleStrings = {
     minutesRemaining: "{minutes} min",
     monitorDnd: "Suspendre lorsque Ne pas déranger est activé",
     monitorDndHint:
-      "Suspendre automatiquement tous les rappels lorsque le mode Ne pas déranger est détecté. Nécessite un redémarrage.",
+      "Suspendre automatiquement tous les rappels lorsque le mode Ne pas déranger est détecté.",
     nextBreak: "{kind} dans {time}",
     nextBreakNotification: "Notification de {kind} dans {time}",
     notification: "Notification",
     paused: "Planificateur suspendu",
+    pausedRemaining: "Planificateur suspendu, reprise dans {time}",
+    pausePolicies: "Après une pause",
+    pausePoliciesHint:
+      "Comment le compte à rebours jusqu'à la prochaine pause reprend après chaque type d'interruption",
+    pausePolicyCreditBreak: "Compter comme pause si assez longue",
+    pausePolicyFreeze: "Reprendre là où il s'est arrêté",
+    pausePolicyReset: "Redémarrer l'intervalle",
+    pauseReasonAppExclusion: "Application exclue",
+    pauseReasonDnd: "Ne pas déranger",
+    pauseReasonManual: "Mis en pause par vous",
+    pauseReasonUserIdle: "Inactivité",
+    pomodoroShortcut: "Raccourci Pomodoro",
+    pomodoroShortcutHint: "Cliquez pour capturer les touches, ex. Ctrl+Shift+F",
     postponeHint: "Reporter repousse la prochaine pause de la durée configurée",
     postponeShortcut: "Raccourci de report",
     postponeShortcutHint:
@@ -207,8 +240,16 @@ const frFR: LocaleStrings = {
     suggestions: "Suggestions de pause",
   },
   schedule: {
+    addTimeRange: "Ajouter une plage horaire",
     afterMiniBreaks: "Après les mini-pauses",
     audio: "Son",
+    collision: "Avec les mini-pauses",
+    collisionDefer: "Attendre la mini-pause suivante",
+    collisionMerge: "Fusionner avec la mini-pause la plus proche",
+    collisionReplace: "À l'heure, à la place de la mini-pause",
+    countActiveTime: "Ne compter que le temps actif",
+    countActiveTimeHint:
+      "Les pauses arrivent après ce temps passé au clavier. Les moments sans saisie, ex. en lisant, ne comptent pas.",
     create: "Créer un horaire",
     days: "Jours de la semaine",
     description: "Configurer les horaires et les temps de pause",
@@ -219,21 +260,38 @@ const frFR: LocaleStrings = {
     enabled: "Activer l'horaire",
     enabledStatus: "Activé",
     end: "Fin",
+    escalateAfterSkips: "Après des pauses sautées d'affilée",
+    focusMinutes: "Concentration",
     hint: "Créez plusieurs horaires, par ex., différents temps pour les jours de semaine et les week-ends",
     intervalMinutes: "Intervalle",
+    kind: "Type",
+    kindInterval: "Intervalle",
+    kindPomodoro: "Pomodoro",
     longBreak: "Pause longue",
+    longIntervalHint:
+      "Indiquez un temps de travail pour prendre les longues pauses selon le temps, ex. 5 minutes par heure. Laissez 0 pour compter les mini-pauses.",
+    longIntervalMinutes: "Toutes les (temps de travail)",
     maxPostponeCount: "Nombre maximum de reports",
     miniBreak: "Mini-pause",
     minutesUnit: "min",
     name: "Nom de l'horaire",
     notifyBefore: "Notifier avant",
+    pomodoroHint:
+      "Les sessions Pomodoro se lancent depuis le menu de la barre des tâches ou avec le raccourci Pomodoro. Les repos utilisent les réglages des mini-pauses et des longues pauses ci-dessous.",
     postponeMinutes: "Reporter",
     secondsUnit: "sec",
+    skipEscalation: "En cas de sauts répétés",
+    skipEscalationLongBreak: "La prochaine pause est longue",
+    skipEscalationOff: "Rien ne se passe",
+    skipEscalationShortenInterval: "La prochaine pause arrive plus tôt",
+    skipEscalationStrict: "La prochaine pause est stricte",
     start: "Début",
     strictMode: "Mode strict",
     suggestions: "Suggestions",
     theme: "Thème",
     timeRange: "Plage horaire active",
+    timeRangeHint:
+      "Mettez les deux à 00:00 pour toute la journée. Une fin antérieure au début se poursuit après minuit.",
     title: "Horaires de pause",
     totalCount: "horaires",
   },
@@ -290,6 +348,7 @@ const frFR: LocaleStrings = {
   toast: {
     attentionTriggered: "Attention déclenchée",
     attentionTriggerFailed: "Échec du déclenchement de l'attention",
+    breakCredited: "Bon retour ! Votre absence compte comme {kind}",
     breakSkipFailed: "Échec de l'ignorance de la pause actuelle",
     breakSkipped: "La pause actuelle a été ignorée",
     directoryOpened: "Répertoire de configuration ouvert",
@@ -305,6 +364,8 @@ const frFR: LocaleStrings = {
     openUrlFailed: "Échec de l'ouverture de {url}",
     saved: "Configuration enregistrée",
     saveFailed: "Échec de l'enregistrement de la configuration",
+    schedulerRestarting:
+      "Les rappels de pause se sont arrêtés de manière inattendue et redémarrent",
     updateCheckFailed: "Échec de la vérification des mises à jour",
   },
 };
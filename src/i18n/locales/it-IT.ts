@@ -122,8 +122,16 @@ const itIT: LocaleStrings = {
     volume: "Volume",
   },
   break: {
+    activityDetected:
+      "Attività rilevata, allontanati dal computer per fare la pausa",
     attention: "Attenzione",
     attentionMessage: "Concentrati sul presente, elimina le distrazioni.",
+    escalatedLongBreak:
+      "Hai saltato {count} pause di fila, quindi questa è una pausa lunga",
+    escalatedShortenInterval:
+      "Hai saltato {count} pause di fila, quindi questa è arrivata prima",
+    escalatedStrict:
+      "Hai saltato {count} pause di fila, questa non può essere saltata",
     gotIt: "Capito",
     longBreakMessage: "Fai una pausa più lunga e ricaricati.",
     miniBreakMessage:
@@ -139,6 +147,7 @@ const itIT: LocaleStrings = {
     skip: "Salta",
     strict: "Modalità rigorosa - Non può saltare",
     suggestion: "Suggerimento",
+    task: "Attività: {task}",
     timerLabel: "Tempo rimanente",
   },
   days: {
@@ -151,6 +160,7 @@ const itIT: LocaleStrings = {
     Wed: "Mer",
   },
   general: {
+    activeTime: "{active}/{target} min attivi",
     allScreens: "Mostra pause su tutti i monitor",
     allScreensHint:
       "Visualizza la finestra di pausa su ogni schermo nella configurazione multi-monitor",
@@ -159,6 +169,16 @@ const itIT: LocaleStrings = {
     autostartEnabled: "Avvio automatico abilitato con successo",
     autostartHint: "Avvia automaticamente Focust quando accedi",
     behaviorSettings: "Comportamento dell'applicazione",
+    breakGraceHint:
+      "Una pausa dovuta si apre quando smetti di scrivere; 0 la apre in orario",
+    breakGraceLabel: "Attendere una pausa nella digitazione fino a",
+    breakVerification: "Se continui a lavorare durante una pausa",
+    breakVerificationExtend: "Prolungare la pausa",
+    breakVerificationHint:
+      "L'uso di tastiera e mouse durante una pausa viene rilevato dal tempo di inattività del sistema",
+    breakVerificationOff: "Non controllare",
+    breakVerificationRecord: "Registrare la pausa come interrotta",
+    breakVerificationRestart: "Ricominciare la pausa",
     fullscreen: "Schermo intero",
     hoursMinutes: "{hours}h {minutes}m",
     imminent: "inizierà presto",
@@ -172,11 +192,24 @@ const itIT: LocaleStrings = {
     minutesRemaining: "{minutes} min",
     monitorDnd: "Pausa quando Non disturbare è abilitato",
     monitorDndHint:
-      "Sospendi automaticamente tutti i promemoria quando viene rilevata la modalità Non disturbare. Richiede riavvio.",
+      "Sospendi automaticamente tutti i promemoria quando viene rilevata la modalità Non disturbare.",
     nextBreak: "{kind} tra {time}",
     nextBreakNotification: "Notifica per {kind} tra {time}",
     notification: "Notifica",
     paused: "Scheduler in pausa",
+    pausedRemaining: "Scheduler in pausa, riprende tra {time}",
+    pausePolicies: "Dopo una sospensione",
+    pausePoliciesHint:
+      "Come prosegue il conto alla rovescia fino alla prossima pausa dopo ogni tipo di sospensione",
+    pausePolicyCreditBreak: "Contare come pausa se abbastanza lunga",
+    pausePolicyFreeze: "Riprendere da dove si era fermato",
+    pausePolicyReset: "Ricominciare l'intervallo",
+    pauseReasonAppExclusion: "Applicazione esclusa",
+    pauseReasonDnd: "Non disturbare",
+    pauseReasonManual: "Messo in pausa da te",
+    pauseReasonUserIdle: "Inattività",
+    pomodoroShortcut: "Scorciatoia Pomodoro",
+    pomodoroShortcutHint: "Clicca per catturare i tasti, es. Ctrl+Shift+F",
     postponeHint:
       "Posticipare sposta la prossima pausa della durata configurata",
     postponeShortcut: "Scorciatoia posticipa",
@@ -205,8 +238,16 @@ const itIT: LocaleStrings = {
     suggestions: "Suggerimenti di pause",
   },
   schedule: {
+    addTimeRange: "Aggiungi intervallo",
     afterMiniBreaks: "Dopo mini pause",
     audio: "Suono",
+    collision: "Con le mini pause",
+    collisionDefer: "Attendere la mini pausa successiva",
+    collisionMerge: "Unire alla mini pausa più vicina",
+    collisionReplace: "In orario, al posto della mini pausa",
+    countActiveTime: "Conta solo il tempo attivo",
+    countActiveTimeHint:
+      "Le pause arrivano dopo questo tempo alla tastiera. Le pause senza input, es. durante la lettura, non contano.",
     create: "Crea programma",
     days: "Giorni della settimana",
     description: "Configura programmi e tempistiche delle pause",
@@ -217,21 +258,38 @@ const itIT: LocaleStrings = {
     enabled: "Abilita programma",
     enabledStatus: "Abilitato",
     end: "Fine",
+    escalateAfterSkips: "Dopo salti consecutivi",
+    focusMinutes: "Concentrazione",
     hint: "Crea più programmi, ad es. orari diversi per giorni feriali e fine settimana",
     intervalMinutes: "Intervallo",
+    kind: "Tipo",
+    kindInterval: "Intervallo",
+    kindPomodoro: "Pomodoro",
     longBreak: "Pausa lunga",
+    longIntervalHint:
+      "Imposta un tempo di lavoro per fare pause lunghe a tempo, es. 5 minuti ogni ora. Lascia 0 per contare le mini pause.",
+    longIntervalMinutes: "Ogni (tempo di lavoro)",
     maxPostponeCount: "Numero massimo di rinvii",
     miniBreak: "Mini pausa",
     minutesUnit: "min",
     name: "Nome programma",
     notifyBefore: "Notifica prima",
+    pomodoroHint:
+      "Le sessioni Pomodoro si avviano dal menu della barra di sistema o con la scorciatoia Pomodoro. I riposi usano le impostazioni delle mini pause e delle pause lunghe qui sotto.",
     postponeMinutes: "Posticipa",
     secondsUnit: "sec",
+    skipEscalation: "Se saltata ripetutamente",
+    skipEscalationLongBreak: "La prossima è una pausa lunga",
+    skipEscalationOff: "Non succede nulla",
+    skipEscalationShortenInterval: "La prossima pausa arriva prima",
+    skipEscalationStrict: "La prossima pausa è rigorosa",
     start: "Inizio",
     strictMode: "Modalità rigorosa",
     suggestions: "Suggerimenti",
     theme: "Tema",
     timeRange: "Intervallo temporale attivo",
+    timeRangeHint:
+      "Imposta entrambi a 00:00 per tutto il giorno. Una fine precedente all'inizio prosegue oltre la mezzanotte.",
     title: "Programmi di pausa",
     totalCount: "programmi",
   },
@@ -288,6 +346,7 @@ const itIT: LocaleStrings = {
   toast: {
     attentionTriggered: "Attenzione attivata",
     attentionTriggerFailed: "Attivazione attenzione fallita",
+    breakCredited: "Bentornato! Il tempo lontano conta come {kind}",
     breakSkipFailed: "Salto pausa corrente fallito",
     breakSkipped: "La pausa corrente è stata saltata",
     directoryOpened: "Directory di configurazione aperta",
@@ -303,6 +362,8 @@ const itIT: LocaleStrings = {
     openUrlFailed: "Apertura di {url} fallita",
     saved: "Configurazione salvata",
     saveFailed: "Salvataggio configurazione fallito",
+    schedulerRestarting:
+      "I promemoria delle pause si sono interrotti inaspettatamente e vengono riavviati",
     updateCheckFailed: "Controllo aggiornamenti fallito",
   },
 };
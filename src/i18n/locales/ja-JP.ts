@@ -118,8 +118,12 @@ const jaJP: LocaleStrings = {
     volume: "音量",
   },
   break: {
+    activityDetected: "入力を検出しました。休憩を取るためにコンピューターから離れてください",
     attention: "注意",
     attentionMessage: "今に集中し、気を散らすものを排除しましょう。",
+    escalatedLongBreak: "{count} 回続けて休憩をスキップしたため、今回は長い休憩です",
+    escalatedShortenInterval: "{count} 回続けて休憩をスキップしたため、今回は早めの休憩です",
+    escalatedStrict: "{count} 回続けて休憩をスキップしたため、今回はスキップできません",
     gotIt: "了解",
     longBreakMessage: "長めの休憩を取り、リフレッシュしましょう。",
     miniBreakMessage: "短い休憩の時間です—ストレッチ、水分補給、深呼吸。",
@@ -132,6 +136,7 @@ const jaJP: LocaleStrings = {
     skip: "スキップ",
     strict: "厳格モード - スキップ不可",
     suggestion: "提案",
+    task: "タスク: {task}",
     timerLabel: "残り時間",
   },
   days: {
@@ -144,6 +149,7 @@ const jaJP: LocaleStrings = {
     Wed: "水",
   },
   general: {
+    activeTime: "アクティブ {active}/{target} 分",
     allScreens: "すべてのモニターに休憩を表示",
     allScreensHint: "マルチモニター設定で各画面に休憩ウィンドウを表示",
     autostart: "システム起動時に起動",
@@ -151,6 +157,14 @@ const jaJP: LocaleStrings = {
     autostartEnabled: "自動起動が正常に有効化されました",
     autostartHint: "ログイン時にFocustを自動的に起動",
     behaviorSettings: "アプリケーションの動作",
+    breakGraceHint: "時間になった休憩は入力が止まると始まります。0 で時間どおりに開始",
+    breakGraceLabel: "入力の区切りを待つ最大時間",
+    breakVerification: "休憩中に作業を続けたとき",
+    breakVerificationExtend: "休憩を延長する",
+    breakVerificationHint: "休憩中のキーボードやマウスの入力はシステムのアイドル時間から検出されます",
+    breakVerificationOff: "確認しない",
+    breakVerificationRecord: "休憩を中断として記録する",
+    breakVerificationRestart: "休憩をやり直す",
     fullscreen: "フルスクリーン",
     hoursMinutes: "{hours}時間{minutes}分",
     imminent: "まもなく開始",
@@ -163,12 +177,23 @@ const jaJP: LocaleStrings = {
     lessThanMinute: "1分未満",
     minutesRemaining: "{minutes}分",
     monitorDnd: "おやすみモード有効時に一時停止",
-    monitorDndHint:
-      "おやすみモードが検出されたときに、すべてのリマインダーを自動的に一時停止します。再起動が必要です。",
+    monitorDndHint: "おやすみモードが検出されたときに、すべてのリマインダーを自動的に一時停止します。",
     nextBreak: "{time}後に{kind}",
     nextBreakNotification: "{time}後に{kind}の通知",
     notification: "通知",
     paused: "スケジューラー一時停止中",
+    pausedRemaining: "スケジューラー一時停止中、{time}後に再開",
+    pausePolicies: "一時停止の後",
+    pausePoliciesHint: "一時停止の種類ごとに、次の休憩までのカウントダウンをどう再開するか",
+    pausePolicyCreditBreak: "十分長ければ休憩として数える",
+    pausePolicyFreeze: "止まったところから再開",
+    pausePolicyReset: "間隔をやり直す",
+    pauseReasonAppExclusion: "除外アプリ",
+    pauseReasonDnd: "おやすみモード",
+    pauseReasonManual: "手動で一時停止",
+    pauseReasonUserIdle: "無操作",
+    pomodoroShortcut: "ポモドーロのショートカット",
+    pomodoroShortcutHint: "クリックしてキーを入力（例: Ctrl+Shift+F）",
     postponeHint: "延期は次の休憩を設定された期間だけ後ろにずらします",
     postponeShortcut: "延期ショートカット",
     postponeShortcutHint: "クリックしてキーをキャプチャ、例：Ctrl+Shift+P",
@@ -195,8 +220,15 @@ const jaJP: LocaleStrings = {
     suggestions: "休憩の提案",
   },
   schedule: {
+    addTimeRange: "時間範囲を追加",
     afterMiniBreaks: "ミニ休憩の後",
     audio: "サウンド",
+    collision: "ミニ休憩との重なり",
+    collisionDefer: "次のミニ休憩まで待つ",
+    collisionMerge: "最も近いミニ休憩とまとめる",
+    collisionReplace: "時間どおり（ミニ休憩の代わり）",
+    countActiveTime: "アクティブな時間のみ数える",
+    countActiveTimeHint: "キーボード操作の時間がこの長さに達すると休憩になります。読書中など入力のない時間は数えません。",
     create: "スケジュールを作成",
     days: "曜日",
     description: "休憩スケジュールとタイミングを設定",
@@ -207,21 +239,38 @@ const jaJP: LocaleStrings = {
     enabled: "スケジュールを有効にする",
     enabledStatus: "有効",
     end: "終了",
+    escalateAfterSkips: "連続スキップ回数",
+    focusMinutes: "集中時間",
     hint: "複数のスケジュールを作成できます。例：平日と週末で異なるタイミング",
     intervalMinutes: "間隔",
+    kind: "種類",
+    kindInterval: "間隔",
+    kindPomodoro: "ポモドーロ",
     longBreak: "長い休憩",
+    longIntervalHint:
+      "作業時間を設定すると、時間で長い休憩を取ります（例: 1時間ごとに5分）。0 のままにするとミニ休憩の回数で数えます。",
+    longIntervalMinutes: "間隔（作業時間）",
     maxPostponeCount: "最大延期回数",
     miniBreak: "短い休憩",
     minutesUnit: "分",
     name: "スケジュール名",
     notifyBefore: "事前通知",
+    pomodoroHint:
+      "ポモドーロはトレイメニューまたはポモドーロのショートカットから開始します。休憩には下のミニ休憩と長い休憩の設定が使われます。",
     postponeMinutes: "延期",
     secondsUnit: "秒",
+    skipEscalation: "繰り返しスキップしたとき",
+    skipEscalationLongBreak: "次は長い休憩",
+    skipEscalationOff: "何もしない",
+    skipEscalationShortenInterval: "次の休憩を早める",
+    skipEscalationStrict: "次の休憩は厳格モード",
     start: "開始",
     strictMode: "厳格モード",
     suggestions: "提案",
     theme: "テーマ",
     timeRange: "有効時間範囲",
+    timeRangeHint:
+      "両方を 00:00 にすると終日になります。終了が開始より早い場合は日付をまたぎます。",
     title: "休憩スケジュール",
     totalCount: "件のスケジュール",
   },
@@ -275,6 +324,7 @@ const jaJP: LocaleStrings = {
   toast: {
     attentionTriggered: "注意がトリガーされました",
     attentionTriggerFailed: "注意のトリガーに失敗しました",
+    breakCredited: "おかえりなさい！離席時間は{kind}として数えられました",
     breakSkipFailed: "現在の休憩のスキップに失敗しました",
     breakSkipped: "現在の休憩がスキップされました",
     directoryOpened: "設定ディレクトリを開きました",
@@ -290,6 +340,7 @@ const jaJP: LocaleStrings = {
     openUrlFailed: "{url}を開けませんでした",
     saved: "設定が保存されました",
     saveFailed: "設定の保存に失敗しました",
+    schedulerRestarting: "休憩リマインダーが予期せず停止したため、再起動しています",
     updateCheckFailed: "アップデートの確認に失敗しました",
   },
 };
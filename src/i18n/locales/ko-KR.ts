@@ -116,8 +116,12 @@ const koKR: LocaleStrings = {
     volume: "볼륨",
   },
   break: {
+    activityDetected: "입력이 감지되었습니다. 휴식을 위해 컴퓨터에서 잠시 떨어지세요",
     attention: "주의",
     attentionMessage: "현재에 집중하고 방해 요소를 제거하세요.",
+    escalatedLongBreak: "휴식을 {count}번 연속 건너뛰어 이번에는 긴 휴식입니다",
+    escalatedShortenInterval: "휴식을 {count}번 연속 건너뛰어 이번 휴식이 앞당겨졌습니다",
+    escalatedStrict: "휴식을 {count}번 연속 건너뛰어 이번 휴식은 건너뛸 수 없습니다",
     gotIt: "알겠습니다",
     longBreakMessage: "더 긴 휴식을 취하고 재충전하세요.",
     miniBreakMessage: "빠른 휴식 시간입니다—스트레칭, 수분 섭취, 호흡.",
@@ -130,6 +134,7 @@ const koKR: LocaleStrings = {
     skip: "건너뛰기",
     strict: "엄격 모드 - 건너뛸 수 없음",
     suggestion: "제안",
+    task: "작업: {task}",
     timerLabel: "남은 시간",
   },
   days: {
@@ -142,6 +147,7 @@ const koKR: LocaleStrings = {
     Wed: "수",
   },
   general: {
+    activeTime: "활동 {active}/{target}분",
     allScreens: "모든 모니터에 휴식 표시",
     allScreensHint: "멀티 모니터 설정에서 각 화면에 휴식 창 표시",
     autostart: "시스템 시작 시 실행",
@@ -149,6 +155,14 @@ const koKR: LocaleStrings = {
     autostartEnabled: "자동 시작이 성공적으로 활성화되었습니다",
     autostartHint: "로그인 시 Focust를 자동으로 시작",
     behaviorSettings: "애플리케이션 동작",
+    breakGraceHint: "예정된 휴식은 입력을 멈추면 시작됩니다. 0이면 제시간에 시작",
+    breakGraceLabel: "입력이 멈출 때까지 최대 대기",
+    breakVerification: "휴식 중에 계속 작업하면",
+    breakVerificationExtend: "휴식 연장",
+    breakVerificationHint: "휴식 중 키보드와 마우스 입력은 시스템 유휴 시간으로 감지됩니다",
+    breakVerificationOff: "확인 안 함",
+    breakVerificationRecord: "휴식을 중단됨으로 기록",
+    breakVerificationRestart: "휴식 다시 시작",
     fullscreen: "전체 화면",
     hoursMinutes: "{hours}시간 {minutes}분",
     imminent: "곧 시작",
@@ -161,12 +175,23 @@ const koKR: LocaleStrings = {
     lessThanMinute: "1분 미만",
     minutesRemaining: "{minutes}분",
     monitorDnd: "방해 금지 모드 활성화 시 일시 정지",
-    monitorDndHint:
-      "방해 금지 모드가 감지되면 모든 알림을 자동으로 일시 정지합니다. 재시작이 필요합니다.",
+    monitorDndHint: "방해 금지 모드가 감지되면 모든 알림을 자동으로 일시 정지합니다.",
     nextBreak: "{time} 후 {kind}",
     nextBreakNotification: "{time} 후 {kind} 알림",
     notification: "알림",
     paused: "스케줄러 일시 정지됨",
+    pausedRemaining: "스케줄러 일시 정지됨, {time} 후 재개",
+    pausePolicies: "일시 정지 후",
+    pausePoliciesHint: "일시 정지 종류별로 다음 휴식까지의 카운트다운을 어떻게 이어갈지",
+    pausePolicyCreditBreak: "충분히 길면 휴식으로 계산",
+    pausePolicyFreeze: "멈춘 곳부터 계속",
+    pausePolicyReset: "간격 다시 시작",
+    pauseReasonAppExclusion: "제외된 앱",
+    pauseReasonDnd: "방해 금지",
+    pauseReasonManual: "직접 일시 정지",
+    pauseReasonUserIdle: "비활성",
+    pomodoroShortcut: "뽀모도로 단축키",
+    pomodoroShortcutHint: "클릭하여 키 입력, 예: Ctrl+Shift+F",
     postponeHint: "연기는 구성된 기간만큼 다음 휴식을 뒤로 미룹니다",
     postponeShortcut: "연기 단축키",
     postponeShortcutHint: "클릭하여 키 캡처, 예: Ctrl+Shift+P",
@@ -193,8 +218,15 @@ const koKR: LocaleStrings = {
     suggestions: "휴식 제안",
   },
   schedule: {
+    addTimeRange: "시간 범위 추가",
     afterMiniBreaks: "미니 휴식 이후",
     audio: "사운드",
+    collision: "미니 휴식과 겹칠 때",
+    collisionDefer: "다음 미니 휴식까지 대기",
+    collisionMerge: "가장 가까운 미니 휴식과 합치기",
+    collisionReplace: "제시간에 (미니 휴식 대신)",
+    countActiveTime: "활동 시간만 계산",
+    countActiveTimeHint: "키보드 사용 시간이 이만큼 쌓이면 휴식합니다. 읽는 중처럼 입력이 없는 시간은 세지 않습니다.",
     create: "일정 만들기",
     days: "요일",
     description: "휴식 일정 및 타이밍 구성",
@@ -205,21 +237,38 @@ const koKR: LocaleStrings = {
     enabled: "일정 활성화",
     enabledStatus: "활성화됨",
     end: "종료",
+    escalateAfterSkips: "연속 건너뛰기 횟수",
+    focusMinutes: "집중 시간",
     hint: "여러 일정을 만드세요. 예: 평일과 주말에 다른 타이밍",
     intervalMinutes: "간격",
+    kind: "유형",
+    kindInterval: "간격",
+    kindPomodoro: "뽀모도로",
     longBreak: "긴 휴식",
+    longIntervalHint:
+      "작업 시간을 설정하면 시간 기준으로 긴 휴식을 합니다 (예: 매시간 5분). 0이면 미니 휴식 횟수로 셉니다.",
+    longIntervalMinutes: "간격 (작업 시간)",
     maxPostponeCount: "최대 연기 횟수",
     miniBreak: "짧은 휴식",
     minutesUnit: "분",
     name: "일정 이름",
     notifyBefore: "사전 알림",
+    pomodoroHint:
+      "뽀모도로 세션은 트레이 메뉴나 뽀모도로 단축키로 시작합니다. 휴식에는 아래의 짧은 휴식과 긴 휴식 설정이 사용됩니다.",
     postponeMinutes: "연기",
     secondsUnit: "초",
+    skipEscalation: "반복해서 건너뛸 때",
+    skipEscalationLongBreak: "다음은 긴 휴식",
+    skipEscalationOff: "아무 일도 없음",
+    skipEscalationShortenInterval: "다음 휴식을 앞당김",
+    skipEscalationStrict: "다음 휴식은 엄격 모드",
     start: "시작",
     strictMode: "엄격 모드",
     suggestions: "제안",
     theme: "테마",
     timeRange: "활성 시간 범위",
+    timeRangeHint:
+      "둘 다 00:00으로 설정하면 하루 종일입니다. 종료가 시작보다 이르면 자정을 넘어 계속됩니다.",
     title: "휴식 일정",
     totalCount: "개 일정",
   },
@@ -273,6 +322,7 @@ const koKR: LocaleStrings = {
   toast: {
     attentionTriggered: "주의가 트리거되었습니다",
     attentionTriggerFailed: "주의 트리거 실패",
+    breakCredited: "다시 오신 것을 환영합니다! 자리를 비운 시간이 {kind}(으)로 계산되었습니다",
     breakSkipFailed: "현재 휴식 건너뛰기 실패",
     breakSkipped: "현재 휴식을 건너뛰었습니다",
     directoryOpened: "구성 디렉토리가 열렸습니다",
@@ -288,6 +338,7 @@ const koKR: LocaleStrings = {
     openUrlFailed: "{url} 열기 실패",
     saved: "구성이 저장되었습니다",
     saveFailed: "구성 저장 실패",
+    schedulerRestarting: "휴식 알림이 예기치 않게 중지되어 다시 시작하는 중입니다",
     updateCheckFailed: "업데이트 확인 실패",
   },
 };
@@ -119,8 +119,16 @@ const ptBR: LocaleStrings = {
     volume: "Volume",
   },
   break: {
+    activityDetected:
+      "Atividade detectada, afaste-se do computador para fazer sua pausa",
     attention: "Atenção",
     attentionMessage: "Concentre-se no presente, elimine distrações.",
+    escalatedLongBreak:
+      "Você pulou {count} pausas seguidas, então esta é uma pausa longa",
+    escalatedShortenInterval:
+      "Você pulou {count} pausas seguidas, então esta veio mais cedo",
+    escalatedStrict:
+      "Você pulou {count} pausas seguidas, esta não pode ser pulada",
     gotIt: "Entendi",
     longBreakMessage: "Faça uma pausa mais longa e recarregue as energias.",
     miniBreakMessage:
@@ -135,6 +143,7 @@ const ptBR: LocaleStrings = {
     skip: "Pular",
     strict: "Modo estrito - Não pode pular",
     suggestion: "Sugestão",
+    task: "Tarefa: {task}",
     timerLabel: "Tempo restante",
   },
   days: {
@@ -147,6 +156,7 @@ const ptBR: LocaleStrings = {
     Wed: "Qua",
   },
   general: {
+    activeTime: "{active}/{target} min ativos",
     allScreens: "Mostrar pausas em todos os monitores",
     allScreensHint:
       "Exibir janela de pausa em cada tela na configuração multi-monitor",
@@ -155,6 +165,16 @@ const ptBR: LocaleStrings = {
     autostartEnabled: "Inicialização automática habilitada com sucesso",
     autostartHint: "Iniciar o Focust automaticamente quando você fizer login",
     behaviorSettings: "Comportamento do aplicativo",
+    breakGraceHint:
+      "Uma pausa devida abre quando você para de digitar; 0 abre no horário",
+    breakGraceLabel: "Esperar uma pausa na digitação por até",
+    breakVerification: "Se você continuar trabalhando durante uma pausa",
+    breakVerificationExtend: "Prolongar a pausa",
+    breakVerificationHint:
+      "O uso de teclado e mouse durante uma pausa é detectado pelo tempo ocioso do sistema",
+    breakVerificationOff: "Não verificar",
+    breakVerificationRecord: "Registrar a pausa como interrompida",
+    breakVerificationRestart: "Reiniciar a pausa",
     fullscreen: "Tela cheia",
     hoursMinutes: "{hours}h {minutes}m",
     imminent: "começando em breve",
@@ -168,11 +188,24 @@ const ptBR: LocaleStrings = {
     minutesRemaining: "{minutes} min",
     monitorDnd: "Pausar quando Não Perturbe está habilitado",
     monitorDndHint:
-      "Pausar automaticamente todos os lembretes quando o modo Não Perturbe é detectado. Requer reinicialização.",
+      "Pausar automaticamente todos os lembretes quando o modo Não Perturbe é detectado.",
     nextBreak: "{kind} em {time}",
     nextBreakNotification: "Notificação de {kind} em {time}",
     notification: "Notificação",
     paused: "Agendador pausado",
+    pausedRemaining: "Agendador pausado, retoma em {time}",
+    pausePolicies: "Depois de uma pausa",
+    pausePoliciesHint:
+      "Como a contagem até a próxima pausa continua após cada tipo de interrupção",
+    pausePolicyCreditBreak: "Contar como pausa se for longa o bastante",
+    pausePolicyFreeze: "Continuar de onde parou",
+    pausePolicyReset: "Reiniciar o intervalo",
+    pauseReasonAppExclusion: "Aplicativo excluído",
+    pauseReasonDnd: "Não perturbe",
+    pauseReasonManual: "Pausado por você",
+    pauseReasonUserIdle: "Inatividade",
+    pomodoroShortcut: "Atalho do Pomodoro",
+    pomodoroShortcutHint: "Clique para capturar teclas, ex.: Ctrl+Shift+F",
     postponeHint: "Adiar empurra a próxima pausa pela duração configurada",
     postponeShortcut: "Atalho de adiamento",
     postponeShortcutHint:
@@ -200,8 +233,16 @@ const ptBR: LocaleStrings = {
     suggestions: "Sugestões de pausas",
   },
   schedule: {
+    addTimeRange: "Adicionar intervalo",
     afterMiniBreaks: "Após mini pausas",
     audio: "Som",
+    collision: "Junto às mini pausas",
+    collisionDefer: "Esperar a próxima mini pausa",
+    collisionMerge: "Juntar à mini pausa mais próxima",
+    collisionReplace: "No horário, no lugar da mini pausa",
+    countActiveTime: "Contar apenas o tempo ativo",
+    countActiveTimeHint:
+      "As pausas vêm após esse tempo no teclado. Momentos sem entrada, ex.: lendo, não contam.",
     create: "Criar agendamento",
     days: "Dias da semana",
     description: "Configurar agendamentos e horários de pausas",
@@ -212,21 +253,38 @@ const ptBR: LocaleStrings = {
     enabled: "Habilitar agendamento",
     enabledStatus: "Habilitado",
     end: "Fim",
+    escalateAfterSkips: "Após pausas puladas seguidas",
+    focusMinutes: "Foco",
     hint: "Crie vários agendamentos, por exemplo, horários diferentes para dias úteis e fins de semana",
     intervalMinutes: "Intervalo",
+    kind: "Tipo",
+    kindInterval: "Intervalo",
+    kindPomodoro: "Pomodoro",
     longBreak: "Pausa longa",
+    longIntervalHint:
+      "Defina um tempo de trabalho para fazer pausas longas por tempo, ex.: 5 minutos a cada hora. Deixe 0 para contar mini pausas.",
+    longIntervalMinutes: "A cada (tempo de trabalho)",
     maxPostponeCount: "Número máximo de adiamentos",
     miniBreak: "Mini pausa",
     minutesUnit: "min",
     name: "Nome do agendamento",
     notifyBefore: "Notificar antes",
+    pomodoroHint:
+      "As sessões Pomodoro são iniciadas pelo menu da bandeja ou pelo atalho do Pomodoro. Os descansos usam as configurações de pausa curta e longa abaixo.",
     postponeMinutes: "Adiar",
     secondsUnit: "seg",
+    skipEscalation: "Ao pular repetidamente",
+    skipEscalationLongBreak: "A próxima é uma pausa longa",
+    skipEscalationOff: "Nada acontece",
+    skipEscalationShortenInterval: "A próxima pausa vem antes",
+    skipEscalationStrict: "A próxima pausa é rigorosa",
     start: "Início",
     strictMode: "Modo estrito",
     suggestions: "Sugestões",
     theme: "Tema",
     timeRange: "Intervalo de tempo ativo",
+    timeRangeHint:
+      "Defina ambos como 00:00 para o dia todo. Um fim anterior ao início continua após a meia-noite.",
     title: "Agendamentos de pausas",
     totalCount: "agendamentos",
   },
@@ -283,6 +341,7 @@ const ptBR: LocaleStrings = {
   toast: {
     attentionTriggered: "Atenção acionada",
     attentionTriggerFailed: "Falha ao acionar atenção",
+    breakCredited: "Bem-vindo de volta! Seu tempo ausente contou como {kind}",
     breakSkipFailed: "Falha ao pular pausa atual",
     breakSkipped: "A pausa atual foi pulada",
     directoryOpened: "Diretório de configuração aberto",
@@ -298,6 +357,8 @@ const ptBR: LocaleStrings = {
     openUrlFailed: "Falha ao abrir {url}",
     saved: "Configuração salva",
     saveFailed: "Falha ao salvar configuração",
+    schedulerRestarting:
+      "Os lembretes de pausa pararam inesperadamente e estão sendo reiniciados",
     updateCheckFailed: "Falha ao verificar atualizações",
   },
 };
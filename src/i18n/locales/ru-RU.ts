@@ -118,8 +118,16 @@ const ruRU: LocaleStrings = {
     volume: "Громкость",
   },
   break: {
+    activityDetected:
+      "Обнаружен ввод — отойдите от компьютера, чтобы отдохнуть",
     attention: "Внимание",
     attentionMessage: "Сосредоточьтесь на настоящем, устраните отвлечения.",
+    escalatedLongBreak:
+      "Вы пропустили {count} перерыва подряд, поэтому этот перерыв длинный",
+    escalatedShortenInterval:
+      "Вы пропустили {count} перерыва подряд, поэтому этот наступил раньше",
+    escalatedStrict:
+      "Вы пропустили {count} перерыва подряд, этот пропустить нельзя",
     gotIt: "Понятно",
     longBreakMessage: "Сделайте более длительный перерыв и перезарядитесь.",
     miniBreakMessage:
@@ -135,6 +143,7 @@ const ruRU: LocaleStrings = {
     skip: "Пропустить",
     strict: "Строгий режим - Нельзя пропустить",
     suggestion: "Предложение",
+    task: "Задача: {task}",
     timerLabel: "Оставшееся время",
   },
   days: {
@@ -147,6 +156,7 @@ const ruRU: LocaleStrings = {
     Wed: "Ср",
   },
   general: {
+    activeTime: "{active}/{target} мин активности",
     allScreens: "Показывать перерывы на всех мониторах",
     allScreensHint:
       "Отображать окно перерыва на каждом экране в многомониторной конфигурации",
@@ -155,6 +165,16 @@ const ruRU: LocaleStrings = {
     autostartEnabled: "Автозапуск успешно включен",
     autostartHint: "Автоматически запускать Focust при входе в систему",
     behaviorSettings: "Поведение приложения",
+    breakGraceHint:
+      "Перерыв начнётся, как только вы перестанете печатать; 0 — точно по времени",
+    breakGraceLabel: "Ждать паузы в наборе не дольше",
+    breakVerification: "Если вы продолжаете работать во время перерыва",
+    breakVerificationExtend: "Продлить перерыв",
+    breakVerificationHint:
+      "Ввод с клавиатуры и мыши во время перерыва определяется по времени простоя системы",
+    breakVerificationOff: "Не проверять",
+    breakVerificationRecord: "Записать перерыв как прерванный",
+    breakVerificationRestart: "Начать перерыв заново",
     fullscreen: "Полноэкранный режим",
     hoursMinutes: "{hours}ч {minutes}м",
     imminent: "скоро начнется",
@@ -168,11 +188,25 @@ const ruRU: LocaleStrings = {
     minutesRemaining: "{minutes} мин",
     monitorDnd: "Приостановить при включении режима Не беспокоить",
     monitorDndHint:
-      "Автоматически приостанавливать все напоминания при обнаружении режима Не беспокоить. Требуется перезапуск.",
+      "Автоматически приостанавливать все напоминания при обнаружении режима Не беспокоить.",
     nextBreak: "{kind} через {time}",
     nextBreakNotification: "Уведомление о {kind} через {time}",
     notification: "Уведомление",
     paused: "Планировщик приостановлен",
+    pausedRemaining: "Планировщик приостановлен, возобновится через {time}",
+    pausePolicies: "После приостановки",
+    pausePoliciesHint:
+      "Как продолжается отсчёт до следующего перерыва после каждого вида приостановки",
+    pausePolicyCreditBreak: "Засчитать как перерыв, если достаточно долго",
+    pausePolicyFreeze: "Продолжить с места остановки",
+    pausePolicyReset: "Начать интервал заново",
+    pauseReasonAppExclusion: "Исключённое приложение",
+    pauseReasonDnd: "Не беспокоить",
+    pauseReasonManual: "Приостановлено вами",
+    pauseReasonUserIdle: "Бездействие",
+    pomodoroShortcut: "Сочетание клавиш для помодоро",
+    pomodoroShortcutHint:
+      "Нажмите, чтобы записать клавиши, например Ctrl+Shift+F",
     postponeHint:
       "Отсрочка сдвигает следующий перерыв на настроенную продолжительность",
     postponeShortcut: "Горячая клавиша отсрочки",
@@ -200,8 +234,16 @@ const ruRU: LocaleStrings = {
     suggestions: "Предложения для перерывов",
   },
   schedule: {
+    addTimeRange: "Добавить диапазон",
     afterMiniBreaks: "После мини-перерывов",
     audio: "Звук",
+    collision: "Рядом с мини-перерывом",
+    collisionDefer: "Дождаться следующего мини-перерыва",
+    collisionMerge: "Объединить с ближайшим мини-перерывом",
+    collisionReplace: "Вовремя, вместо мини-перерыва",
+    countActiveTime: "Учитывать только активное время",
+    countActiveTimeHint:
+      "Перерыв наступает после этого времени за клавиатурой. Паузы без ввода, например при чтении, не учитываются.",
     create: "Создать расписание",
     days: "Дни недели",
     description: "Настройка расписаний и времени перерывов",
@@ -212,21 +254,38 @@ const ruRU: LocaleStrings = {
     enabled: "Включить расписание",
     enabledStatus: "Включено",
     end: "Конец",
+    escalateAfterSkips: "После пропусков подряд",
+    focusMinutes: "Фокус",
     hint: "Создайте несколько расписаний, например, разные времена для будних дней и выходных",
     intervalMinutes: "Интервал",
+    kind: "Тип",
+    kindInterval: "Интервал",
+    kindPomodoro: "Помодоро",
     longBreak: "Длительный перерыв",
+    longIntervalHint:
+      "Укажите время работы, чтобы делать длинные перерывы по времени, например 5 минут каждый час. Оставьте 0, чтобы считать мини-перерывы.",
+    longIntervalMinutes: "Каждые (время работы)",
     maxPostponeCount: "Максимальное количество отсрочек",
     miniBreak: "Короткий перерыв",
     minutesUnit: "мин",
     name: "Название расписания",
     notifyBefore: "Уведомить за",
+    pomodoroHint:
+      "Сессии помодоро запускаются из меню в трее или сочетанием клавиш. Для отдыха используются настройки коротких и длинных перерывов ниже.",
     postponeMinutes: "Отсрочка",
     secondsUnit: "сек",
+    skipEscalation: "При повторных пропусках",
+    skipEscalationLongBreak: "Следующий перерыв длинный",
+    skipEscalationOff: "Ничего не происходит",
+    skipEscalationShortenInterval: "Следующий перерыв наступит раньше",
+    skipEscalationStrict: "Следующий перерыв строгий",
     start: "Начало",
     strictMode: "Строгий режим",
     suggestions: "Предложения",
     theme: "Тема",
     timeRange: "Активный диапазон времени",
+    timeRangeHint:
+      "Укажите 00:00 для обоих, чтобы охватить весь день. Конец раньше начала продолжается после полуночи.",
     title: "Расписание перерывов",
     totalCount: "расписаний",
   },
@@ -283,6 +342,7 @@ const ruRU: LocaleStrings = {
   toast: {
     attentionTriggered: "Внимание активировано",
     attentionTriggerFailed: "Не удалось активировать внимание",
+    breakCredited: "С возвращением! Время отсутствия засчитано как {kind}",
     breakSkipFailed: "Не удалось пропустить текущий перерыв",
     breakSkipped: "Текущий перерыв пропущен",
     directoryOpened: "Каталог конфигурации открыт",
@@ -298,6 +358,8 @@ const ruRU: LocaleStrings = {
     openUrlFailed: "Не удалось открыть {url}",
     saved: "Конфигурация сохранена",
     saveFailed: "Не удалось сохранить конфигурацию",
+    schedulerRestarting:
+      "Напоминания о перерывах неожиданно остановились и перезапускаются",
     updateCheckFailed: "Не удалось проверить обновления",
   },
 };
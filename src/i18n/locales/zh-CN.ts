@@ -111,8 +111,12 @@ const zhCN: LocaleStrings = {
     volume: "音量",
   },
   break: {
+    activityDetected: "检测到输入，请离开电脑好好休息",
     attention: "注意",
     attentionMessage: "专注当下，排除干扰。",
+    escalatedLongBreak: "你已连续跳过 {count} 次休息，因此这次是长休息",
+    escalatedShortenInterval: "你已连续跳过 {count} 次休息，因此这次休息提前了",
+    escalatedStrict: "你已连续跳过 {count} 次休息，这次无法跳过",
     gotIt: "知道了",
     longBreakMessage: "休息一会儿，给自己充充电。",
     miniBreakMessage: "是时候短暂休息了——伸展、喝水、深呼吸。",
@@ -125,6 +129,7 @@ const zhCN: LocaleStrings = {
     skip: "跳过",
     strict: "严格模式 - 无法跳过",
     suggestion: "休息建议",
+    task: "任务：{task}",
     timerLabel: "剩余时间",
   },
   days: {
@@ -137,6 +142,7 @@ const zhCN: LocaleStrings = {
     Wed: "三",
   },
   general: {
+    activeTime: "已活跃 {active}/{target} 分钟",
     allScreens: "在所有显示器上显示休息界面",
     allScreensHint: "在多显示器环境下，每个屏幕都显示休息窗口",
     autostart: "开机自动启动",
@@ -144,6 +150,14 @@ const zhCN: LocaleStrings = {
     autostartEnabled: "已启用开机自启动",
     autostartHint: "登录系统时自动启动 Focust",
     behaviorSettings: "应用行为",
+    breakGraceHint: "到点的休息会在你停止输入时开始，0 表示准时开始",
+    breakGraceLabel: "等待输入停顿，最多",
+    breakVerification: "休息期间继续工作时",
+    breakVerificationExtend: "延长休息",
+    breakVerificationHint: "通过系统空闲时间检测休息期间的键盘和鼠标输入",
+    breakVerificationOff: "不检查",
+    breakVerificationRecord: "记录为中断的休息",
+    breakVerificationRestart: "重新开始休息",
     fullscreen: "全屏",
     hoursMinutes: "{hours} 小时 {minutes} 分钟",
     imminent: "即将开始",
@@ -156,11 +170,23 @@ const zhCN: LocaleStrings = {
     lessThanMinute: "不到 1 分钟",
     minutesRemaining: "{minutes} 分钟",
     monitorDnd: "勿扰模式时暂停提醒",
-    monitorDndHint: "检测到勿扰模式时自动暂停所有提醒，需重启",
+    monitorDndHint: "检测到勿扰模式时自动暂停所有提醒",
     nextBreak: "{time}后开始{kind}",
     nextBreakNotification: "{time}后提醒即将{kind}",
     notification: "提醒",
     paused: "调度器已暂停",
+    pausedRemaining: "调度器已暂停，{time}后恢复",
+    pausePolicies: "暂停之后",
+    pausePoliciesHint: "各类暂停结束后，距下次休息的倒计时如何继续",
+    pausePolicyCreditBreak: "足够长时计为一次休息",
+    pausePolicyFreeze: "从暂停处继续",
+    pausePolicyReset: "重新开始计时",
+    pauseReasonAppExclusion: "排除的应用",
+    pauseReasonDnd: "免打扰",
+    pauseReasonManual: "手动暂停",
+    pauseReasonUserIdle: "无操作",
+    pomodoroShortcut: "番茄钟快捷键",
+    pomodoroShortcutHint: "点击以录入按键，例如 Ctrl+Shift+F",
     postponeHint: "延后将根据配置推迟下一次休息",
     postponeShortcut: "延后快捷键",
     postponeShortcutHint: "点击输入框并按下组合键，例如：Ctrl+Shift+P",
@@ -187,8 +213,15 @@ const zhCN: LocaleStrings = {
     suggestions: "休息建议",
   },
   schedule: {
+    addTimeRange: "添加时间段",
     afterMiniBreaks: "累计短休次数",
     audio: "提示音",
+    collision: "与短休冲突时",
+    collisionDefer: "等到下一次短休",
+    collisionMerge: "与最近的短休合并",
+    collisionReplace: "准时，取代短休",
+    countActiveTime: "仅计算活跃时间",
+    countActiveTimeHint: "在键盘前累计达到该时长后休息。无输入的时间（例如阅读时）不计入。",
     create: "新建计划",
     days: "适用星期",
     description: "配置休息计划和时间安排",
@@ -198,21 +231,36 @@ const zhCN: LocaleStrings = {
     enabled: "启用计划",
     enabledStatus: "已启用",
     end: "结束",
+    escalateAfterSkips: "连续跳过次数",
+    focusMinutes: "专注时长",
     hint: "可创建多个计划，例如工作日和周末使用不同时间安排",
     intervalMinutes: "间隔",
+    kind: "类型",
+    kindInterval: "间隔",
+    kindPomodoro: "番茄钟",
     longBreak: "长休息",
+    longIntervalHint: "设置工作时长后按时间安排长休息，例如每小时休息 5 分钟。保持 0 则按短休次数计算。",
+    longIntervalMinutes: "间隔（工作时长）",
     maxPostponeCount: "最大延后次数",
     miniBreak: "短休息",
     minutesUnit: "分钟",
     name: "计划名称",
     notifyBefore: "提前提醒",
+    pomodoroHint: "番茄钟从托盘菜单或番茄钟快捷键开始。休息使用下方的小休息和长休息设置。",
     postponeMinutes: "延后",
     secondsUnit: "秒",
+    skipEscalation: "多次跳过时",
+    skipEscalationLongBreak: "下次改为长休息",
+    skipEscalationOff: "无操作",
+    skipEscalationShortenInterval: "下次休息提前",
+    skipEscalationStrict: "下次休息为严格模式",
     start: "开始",
     strictMode: "严格模式",
     suggestions: "休息建议",
     theme: "主题",
     timeRange: "生效时间段",
+    timeRangeHint:
+      "开始和结束均设为 00:00 表示全天。结束早于开始时将跨越午夜。",
     title: "休息计划",
     totalCount: "个计划",
   },
@@ -265,6 +313,7 @@ const zhCN: LocaleStrings = {
   toast: {
     attentionTriggered: "已触发注意提醒",
     attentionTriggerFailed: "触发注意提醒失败",
+    breakCredited: "欢迎回来！离开的时间已计为{kind}",
     breakSkipFailed: "跳过休息失败",
     breakSkipped: "已跳过当前休息",
     directoryOpened: "配置目录已打开",
@@ -280,6 +329,7 @@ const zhCN: LocaleStrings = {
     openUrlFailed: "打开 {url} 失败",
     saved: "配置已保存",
     saveFailed: "保存失败",
+    schedulerRestarting: "休息提醒意外停止，正在重新启动",
     updateCheckFailed: "检查更新失败",
   },
 };
@@ -34,22 +34,38 @@ const mockConfig: RawAppConfig = {
     },
   ],
   autostart: false,
+  breakGraceS: 0,
+  breakVerification: "off",
   inactiveS: 300,
   language: "en-US",
   monitorDnd: false,
+  pausePolicies: {
+    appExclusion: "reset",
+    dnd: "reset",
+    manual: "reset",
+    userIdle: "creditBreak",
+  },
+  pomodoroShortcut: "",
   postponeShortcut: "Ctrl+X",
   schedules: [
     {
+      countActiveTime: false,
       daysOfWeek: ["Mon", "Tue", "Wed", "Thu", "Fri"],
       enabled: true,
+      focusS: 1500,
+      kind: "interval",
       longBreaks: {
         afterMiniBreaks: 4,
         audio: createNoAudio(),
+        collision: "merge",
         durationS: 300,
         enabled: true,
+        escalateAfterSkips: 3,
         id: 2,
+        intervalS: 0,
         maxPostponeCount: 2,
         postponedS: 600,
+        skipEscalation: "off",
         strictMode: false,
         suggestions: createSuggestionsSettings(),
         theme: {
@@ -65,10 +81,12 @@ const mockConfig: RawAppConfig = {
         audio: createNoAudio(),
         durationS: 20,
         enabled: true,
+        escalateAfterSkips: 3,
         id: 1,
         intervalS: 1200,
         maxPostponeCount: 2,
         postponedS: 300,
+        skipEscalation: "off",
         strictMode: false,
         suggestions: createSuggestionsSettings(),
         theme: {
@@ -82,7 +100,7 @@ const mockConfig: RawAppConfig = {
       },
       name: "Work hours",
       notificationBeforeS: 30,
-      timeRange: createTimeRange("09:00", "18:00"),
+      timeRanges: [createTimeRange("09:00", "18:00")],
     },
   ],
   themeMode: "system",
@@ -202,16 +202,23 @@ export const useConfigStore = defineStore("config", () => {
     const miniId = nextId(cfg.schedules.map((s) => s.miniBreaks.id));
     const longId = nextId(cfg.schedules.map((s) => s.longBreaks.id));
     cfg.schedules.push({
+      countActiveTime: false,
       daysOfWeek: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
       enabled: true,
+      focusS: 1500,
+      kind: "interval",
       longBreaks: {
         afterMiniBreaks: 4,
         audio: createNoAudio(),
+        collision: "merge",
         durationS: 300,
         enabled: true,
+        escalateAfterSkips: 3,
         id: longId,
+        intervalS: 0,
         maxPostponeCount: 2,
         postponedS: 300,
+        skipEscalation: "off",
         strictMode: false,
         suggestions: createSuggestionsSettings(),
         theme: createDefaultTheme(),
@@ -220,17 +227,19 @@ export const useConfigStore = defineStore("config", () => {
         audio: createNoAudio(),
         durationS: 20,
         enabled: true,
+        escalateAfterSkips: 3,
         id: miniId,
         intervalS: 1200,
         maxPostponeCount: 2,
         postponedS: 300,
+        skipEscalation: "off",
         strictMode: false,
         suggestions: createSuggestionsSettings(),
         theme: createDefaultTheme(),
       },
       name: `New Schedule (${miniId})`, // Use miniId to differentiate
       notificationBeforeS: 10,
-      timeRange: createAllDayTimeRange(),
+      timeRanges: [createAllDayTimeRange()],
     });
   }
 
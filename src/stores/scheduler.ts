@@ -2,7 +2,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { defineStore } from "pinia";
 import { ref } from "vue";
-import type { SchedulerStatus } from "@/types";
+import type { CommandReply, SchedulerStatus } from "@/types";
 
 /** Scheduler store to handle status updates */
 export const useSchedulerStore = defineStore("scheduler", () => {
@@ -26,8 +26,12 @@ export const useSchedulerStore = defineStore("scheduler", () => {
 
     // Request initial status after listeners are set up
     try {
-      await invoke("request_break_status");
+      const reply = await invoke<CommandReply>("request_break_status");
       console.log("[Scheduler] Requested initial status");
+      if (reply.type === "accepted" && reply.data) {
+        schedulerStatus.value = reply.data;
+        schedulerPaused.value = reply.data.paused;
+      }
     } catch (err) {
       console.error("[Scheduler] Failed to request initial status:", err);
     }
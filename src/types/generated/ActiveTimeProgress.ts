@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Active time worked towards the next break
+ */
+export type ActiveTimeProgress = { 
+/**
+ * Active seconds since the last break
+ */
+activeS: number, 
+/**
+ * Active seconds after which the next break is due
+ */
+targetS: number, };
@@ -1,6 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AppExclusion } from "./AppExclusion";
 import type { AttentionSettings } from "./AttentionSettings";
+import type { BreakVerification } from "./BreakVerification";
+import type { PausePolicies } from "./PausePolicies";
 import type { ScheduleSettings } from "./ScheduleSettings";
 
 /**
@@ -19,6 +21,18 @@ monitorDnd: boolean,
  * Inactive time in seconds before pausing breaks
  */
 inactiveS: number, 
+/**
+ * How the break interval continues after each kind of pause
+ */
+pausePolicies: PausePolicies, 
+/**
+ * Seconds a due break may wait for a pause in typing, 0 opens it on time
+ */
+breakGraceS: number, 
+/**
+ * Whether input during a break is detected, and what happens then
+ */
+breakVerification: BreakVerification, 
 /**
  * If breaks should be shown on all screens
  */
@@ -35,6 +49,10 @@ themeMode: string,
  * Shortcut to postpone breaks, e.g., "Ctrl+Shift+X"
  */
 postponeShortcut: string, 
+/**
+ * Shortcut to start or stop a Pomodoro session, e.g., "Ctrl+Shift+F"
+ */
+pomodoroShortcut: string, 
 /**
  * Prompt window size percentage (0.1 to 1.0, where 1.0 is fullscreen)
  */
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchedulerEvent } from "./SchedulerEvent";
+
+/**
+ * Input detected during a break that is being verified
+ *
+ * Emitted as `break-activity` so the prompt can update its countdown.
+ */
+export type BreakActivity = { 
+/**
+ * The break in progress
+ */
+event: SchedulerEvent, 
+/**
+ * Seconds left in the break after the activity was accounted for
+ */
+remainingS: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchedulerEvent } from "./SchedulerEvent";
+
+/**
+ * A break counted from time the user spent away from the computer
+ *
+ * Emitted as `break-credited` when the scheduler resumes after a pause whose
+ * policy is to credit breaks (idle pauses by default).
+ */
+export type BreakCredit = { 
+/**
+ * The break that was counted
+ */
+event: SchedulerEvent, 
+/**
+ * Seconds the user was away, including the idle time before an idle pause
+ */
+pausedS: number, };
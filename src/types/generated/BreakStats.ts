@@ -0,0 +1,59 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Aggregated break statistics over a set of history entries
+ *
+ * A break is *due* once it reaches a final outcome, counted in one of:
+ *
+ * - `taken`: completed, or credited from idle time
+ * - `skipped`: skipped by the user
+ * - `cancelled`: closed by a pause
+ * - `interrupted`: completed while the user kept working
+ *
+ * Postponing is not final, since the break is rescheduled.
+ */
+export type BreakStats = { 
+/**
+ * Breaks that reached a final outcome
+ */
+due: number, 
+/**
+ * Breaks that ran until the end or were taken while idle
+ */
+taken: number, 
+/**
+ * Breaks skipped by the user
+ */
+skipped: number, 
+/**
+ * Breaks closed because the scheduler got paused
+ */
+cancelled: number, 
+/**
+ * Breaks during which the user kept working
+ */
+interrupted: number, 
+/**
+ * Number of postpone actions
+ */
+postpones: number, 
+/**
+ * Due breaks that were postponed at least once
+ */
+postponedBreaks: number, 
+/**
+ * `taken / due`, 0 when nothing was due
+ */
+complianceRate: number, 
+/**
+ * `skipped / due`, 0 when nothing was due
+ */
+skipRate: number, 
+/**
+ * `postponed_breaks / due`, 0 when nothing was due
+ */
+postponeRate: number, 
+/**
+ * Longest time in seconds without a completed break within a single day
+ */
+longestStretchS: number, };
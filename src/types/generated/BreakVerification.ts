@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How breaks are checked for the user actually stepping away
+ */
+export type BreakVerification = "off" | "record" | "extend" | "restart";
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Rejection } from "./Rejection";
+import type { SchedulerStatus } from "./SchedulerStatus";
+
+/**
+ * Reply of the scheduler to a command
+ */
+export type CommandReply = { "type": "accepted", "data": SchedulerStatus | null } | { "type": "rejected", "data": Rejection };
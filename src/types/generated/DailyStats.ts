@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BreakStats } from "./BreakStats";
+import type { ScheduleStats } from "./ScheduleStats";
+
+/**
+ * Break statistics of a single (local) day
+ */
+export type DailyStats = { date: string, overall: BreakStats, schedules: Array<ScheduleStats>, 
+/**
+ * Whether enough due breaks were taken (see [`COMPLIANT_DAY_RATIO`])
+ */
+compliant: boolean, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptOutcome } from "./PromptOutcome";
+import type { SchedulerEvent } from "./SchedulerEvent";
+
+/**
+ * A single record in the break history
+ */
+export type HistoryEntry = { 
+/**
+ * When the transition happened
+ */
+timestamp: string, event: SchedulerEvent, outcome: PromptOutcome, 
+/**
+ * Name of the schedule (breaks) or attention the prompt belongs to
+ */
+sourceName: string | null, 
+/**
+ * Seconds the prompt had been shown when the transition happened
+ */
+durationS: number, 
+/**
+ * Number of times the break had been postponed before this transition
+ */
+postponeCount: number, };
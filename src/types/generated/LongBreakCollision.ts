@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens when a time-based long break falls due around a mini break
+ */
+export type LongBreakCollision = "merge" | "replace" | "defer";
@@ -1,6 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AudioSettings } from "./AudioSettings";
 import type { BreakId } from "./BreakId";
+import type { LongBreakCollision } from "./LongBreakCollision";
+import type { SkipEscalation } from "./SkipEscalation";
 import type { SuggestionsSettings } from "./SuggestionsSettings";
 import type { ThemeSettings } from "./ThemeSettings";
 
@@ -12,6 +14,14 @@ export type LongBreakSettings = {
  * Number of mini breaks after which to trigger a long break
  */
 afterMiniBreaks: number, 
+/**
+ * Work time between long breaks in seconds, 0 counts mini breaks instead
+ */
+intervalS: number, 
+/**
+ * How a long break due by `interval_s` is fitted in with mini breaks
+ */
+collision: LongBreakCollision, 
 /**
  * Unique identifier for the break settings
  */
@@ -47,4 +57,12 @@ maxPostponeCount: number,
 /**
  * If the break should be strictly followed
  */
-strictMode: boolean, };
+strictMode: boolean, 
+/**
+ * What happens once `escalate_after_skips` of these breaks were skipped in a row
+ */
+skipEscalation: SkipEscalation, 
+/**
+ * Number of breaks skipped in a row before `skip_escalation` applies
+ */
+escalateAfterSkips: number, };
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AudioSettings } from "./AudioSettings";
 import type { BreakId } from "./BreakId";
+import type { SkipEscalation } from "./SkipEscalation";
 import type { SuggestionsSettings } from "./SuggestionsSettings";
 import type { ThemeSettings } from "./ThemeSettings";
 
@@ -47,4 +48,12 @@ maxPostponeCount: number,
 /**
  * If the break should be strictly followed
  */
-strictMode: boolean, };
+strictMode: boolean, 
+/**
+ * What happens once `escalate_after_skips` of these breaks were skipped in a row
+ */
+skipEscalation: SkipEscalation, 
+/**
+ * Number of breaks skipped in a row before `skip_escalation` applies
+ */
+escalateAfterSkips: number, };
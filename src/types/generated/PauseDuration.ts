@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How long a manual pause lasts
+ */
+export type PauseDuration = { "type": "indefinite" } | { "type": "seconds", "data": number } | { "type": "untilScheduleEnd" } | { "type": "untilTomorrow" };
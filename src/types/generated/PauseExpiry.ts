@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Information about the end of a timed pause
+ */
+export type PauseExpiry = { 
+/**
+ * When the scheduler resumes (ISO 8601 timestamp)
+ */
+time: string, 
+/**
+ * Seconds until the scheduler resumes
+ */
+secondsUntil: number, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PausePolicy } from "./PausePolicy";
+
+/**
+ * Pause policy for each pause reason
+ */
+export type PausePolicies = { 
+/**
+ * Paused because the user was inactive
+ */
+userIdle: PausePolicy, 
+/**
+ * Paused because Do Not Disturb was on
+ */
+dnd: PausePolicy, 
+/**
+ * Paused because an excluded application was running
+ */
+appExclusion: PausePolicy, 
+/**
+ * Paused by the user
+ */
+manual: PausePolicy, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens to the break interval when the scheduler resumes after a pause
+ */
+export type PausePolicy = "reset" | "freeze" | "creditBreak";
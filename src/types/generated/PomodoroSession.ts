@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A Pomodoro session started by the user
+ */
+export type PomodoroSession = { 
+/**
+ * Name of the Pomodoro schedule the session follows
+ */
+scheduleName: string, 
+/**
+ * What the user is working on, shown in break prompts
+ */
+task: string | null, };
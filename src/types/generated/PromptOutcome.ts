@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Lifecycle transition of a prompt (break or attention)
+ */
+export type PromptOutcome = "started" | "completed" | "skipped" | "postponed" | "cancelled" | "credited" | "interrupted";
@@ -2,12 +2,17 @@
 import type { AudioSettings } from "./AudioSettings";
 import type { EventKind } from "./EventKind";
 import type { ResolvedBackground } from "./ResolvedBackground";
+import type { SkipEscalation } from "./SkipEscalation";
 import type { ThemeSettings } from "./ThemeSettings";
 
 /**
  * Prompt payload stored in backend
  */
-export type PromptPayload = { id: number, kind: EventKind, title: string, messageKey: string, message: string | null, scheduleName: string | null, duration: number, strictMode: boolean, theme: ThemeSettings, background: ResolvedBackground, suggestion: string | null, audio: AudioSettings | null, postponeShortcut: string, allScreens: boolean, language: import('@/i18n').LocaleKey, 
+export type PromptPayload = { id: number, kind: EventKind, title: string, messageKey: string, message: string | null, scheduleName: string | null, 
+/**
+ * Task of the Pomodoro session the break belongs to
+ */
+task: string | null, duration: number, strictMode: boolean, theme: ThemeSettings, background: ResolvedBackground, suggestion: string | null, audio: AudioSettings | null, postponeShortcut: string, allScreens: boolean, language: import('@/i18n').LocaleKey, 
 /**
  * Number of times this break has been postponed
  */
@@ -15,4 +20,12 @@ postponeCount: number,
 /**
  * Maximum number of times this break can be postponed
  */
-maxPostponeCount: number, };
+maxPostponeCount: number, 
+/**
+ * Number of breaks skipped in a row before this one, if it was escalated
+ */
+skipCount: number, 
+/**
+ * How this break was escalated after the skips
+ */
+escalation: SkipEscalation | null, };
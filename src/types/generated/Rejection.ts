@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Why the scheduler refused a command
+ */
+export type Rejection = "paused" | "noBreak" | "postponeLimitReached" | "noPomodoroSchedule" | "noPomodoroSession" | "unsupported";
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { LongBreakSettings } from "./LongBreakSettings";
 import type { MiniBreakSettings } from "./MiniBreakSettings";
+import type { ScheduleKind } from "./ScheduleKind";
 import type { TimeRange } from "./TimeRange";
 
 /**
@@ -16,9 +17,24 @@ name: string,
  */
 enabled: boolean, 
 /**
- * Time range during which the schedule is active
+ * How breaks are scheduled
  */
-timeRange: TimeRange, 
+kind: ScheduleKind, 
+/**
+ * Length of a focus block in seconds (Pomodoro schedules)
+ */
+focusS: number, 
+/**
+ * Count only active time towards break intervals (interval schedules)
+ *
+ * Idle gaps shorter than the inactivity threshold do not count as work.
+ * Falls back to wall-clock time if idle detection is unavailable.
+ */
+countActiveTime: boolean, 
+/**
+ * Time ranges during which the schedule is active
+ */
+timeRanges: Array<TimeRange>, 
 /**
  * Days of the week when the schedule is active
  */
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BreakStats } from "./BreakStats";
+
+/**
+ * Break statistics of a single schedule
+ */
+export type ScheduleStats = { scheduleName: string, stats: BreakStats, };
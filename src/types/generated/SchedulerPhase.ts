@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Phase of the break scheduler's state machine
+ */
+export type SchedulerPhase = { "type": "idle" } | { "type": "paused" } | { "type": "waitingForNotification" } | { "type": "waitingForBreak" } | { "type": "inBreak", "data": number };
@@ -1,5 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ActiveTimeProgress } from "./ActiveTimeProgress";
+import type { PauseExpiry } from "./PauseExpiry";
+import type { PauseReason } from "./PauseReason";
+import type { PomodoroSession } from "./PomodoroSession";
 import type { SchedulerEventInfo } from "./SchedulerEventInfo";
+import type { SchedulerPhase } from "./SchedulerPhase";
 
 /**
  * Scheduler status information for UI display
@@ -9,6 +14,18 @@ export type SchedulerStatus = {
  * Whether the break scheduler is currently paused
  */
 paused: boolean, 
+/**
+ * Why the scheduler is paused, empty while it runs
+ */
+pauseReasons: Array<PauseReason>, 
+/**
+ * What the break scheduler is currently doing
+ */
+phase: SchedulerPhase, 
+/**
+ * Name of the active schedule (if any)
+ */
+scheduleName: string | null, 
 /**
  * The next scheduled break event (if any)
  */
@@ -16,4 +33,24 @@ nextEvent: SchedulerEventInfo | null,
 /**
  * The current mini break counter (for tracking long break triggers)
  */
-miniBreakCounter: number, };
+miniBreakCounter: number, 
+/**
+ * Number of times the next or current break has been postponed
+ */
+postponeCount: number, 
+/**
+ * The next attention reminder (if any)
+ */
+nextAttention: SchedulerEventInfo | null, 
+/**
+ * When a timed manual pause ends (if paused for a limited time)
+ */
+pauseExpiry: PauseExpiry | null, 
+/**
+ * The running Pomodoro session (if any)
+ */
+pomodoro: PomodoroSession | null, 
+/**
+ * Progress towards the next break of a schedule counting active time
+ */
+activeTime: ActiveTimeProgress | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Long-running task of the scheduler system, watched by the supervisor
+ */
+export type SchedulerTask = "breakScheduler" | "attentionTimer" | "commandBroadcaster";
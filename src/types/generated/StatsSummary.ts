@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BreakStats } from "./BreakStats";
+import type { DailyStats } from "./DailyStats";
+import type { ScheduleStats } from "./ScheduleStats";
+
+/**
+ * Break statistics over a range of days
+ */
+export type StatsSummary = { 
+/**
+ * First day of the range (inclusive)
+ */
+from: string, 
+/**
+ * Last day of the range (inclusive)
+ */
+to: string, overall: BreakStats, schedules: Array<ScheduleStats>, days: Array<DailyStats>, 
+/**
+ * Compliant days in a row ending at `to`; days without due breaks are ignored
+ */
+currentStreak: number, 
+/**
+ * Longest run of compliant days up to `to`
+ */
+longestStreak: number, };
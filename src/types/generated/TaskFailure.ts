@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchedulerTask } from "./SchedulerTask";
+
+/**
+ * A scheduler task that panicked and is being restarted
+ *
+ * Emitted as `scheduler-task-failed`.
+ */
+export type TaskFailure = { 
+/**
+ * The task that failed
+ */
+task: SchedulerTask, 
+/**
+ * Panic message
+ */
+message: string, 
+/**
+ * Failures in a row, including this one
+ */
+failures: number, 
+/**
+ * Seconds until the task is restarted
+ */
+retryInS: number, };
@@ -10,25 +10,48 @@
 // Factory functions
 export * from "./factories";
 // Commonly used generated types
+export type { ActiveTimeProgress } from "./generated/ActiveTimeProgress";
 export type { AppConfig } from "./generated/AppConfig";
 export type { AppExclusion } from "./generated/AppExclusion";
 export type { AttentionSettings } from "./generated/AttentionSettings";
 export type { AudioSettings } from "./generated/AudioSettings";
 export type { BackgroundSource } from "./generated/BackgroundSource";
+export type { BreakActivity } from "./generated/BreakActivity";
+export type { BreakCredit } from "./generated/BreakCredit";
+export type { BreakStats } from "./generated/BreakStats";
+export type { CommandReply } from "./generated/CommandReply";
+export type { DailyStats } from "./generated/DailyStats";
 export type { EventKind } from "./generated/EventKind";
 export type { ExclusionRule } from "./generated/ExclusionRule";
 export type { FontFamily } from "./generated/FontFamily";
 export type { HexColor } from "./generated/HexColor";
+export type { HistoryEntry } from "./generated/HistoryEntry";
+export type { LongBreakCollision } from "./generated/LongBreakCollision";
 export type { LongBreakSettings } from "./generated/LongBreakSettings";
 export type { MiniBreakSettings } from "./generated/MiniBreakSettings";
+export type { PauseDuration } from "./generated/PauseDuration";
+export type { PauseExpiry } from "./generated/PauseExpiry";
+export type { PausePolicies } from "./generated/PausePolicies";
+export type { PausePolicy } from "./generated/PausePolicy";
+export type { PauseReason } from "./generated/PauseReason";
+export type { PomodoroSession } from "./generated/PomodoroSession";
+export type { PromptOutcome } from "./generated/PromptOutcome";
 export type { PromptPayload } from "./generated/PromptPayload";
+export type { Rejection } from "./generated/Rejection";
 export type { ResolvedBackground } from "./generated/ResolvedBackground";
+export type { ScheduleKind } from "./generated/ScheduleKind";
 export type { SchedulerEvent } from "./generated/SchedulerEvent";
 export type { SchedulerEventInfo } from "./generated/SchedulerEventInfo";
+export type { SchedulerPhase } from "./generated/SchedulerPhase";
 export type { SchedulerStatus } from "./generated/SchedulerStatus";
+export type { SchedulerTask } from "./generated/SchedulerTask";
 export type { ScheduleSettings } from "./generated/ScheduleSettings";
+export type { ScheduleStats } from "./generated/ScheduleStats";
+export type { SkipEscalation } from "./generated/SkipEscalation";
+export type { StatsSummary } from "./generated/StatsSummary";
 export type { SuggestionsConfig } from "./generated/SuggestionsConfig";
 export type { SuggestionsSettings } from "./generated/SuggestionsSettings";
+export type { TaskFailure } from "./generated/TaskFailure";
 export type { ThemeSettings } from "./generated/ThemeSettings";
 // Type guards
 export * from "./guards";